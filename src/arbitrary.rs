@@ -0,0 +1,322 @@
+//! [proptest](https://docs.rs/proptest) strategies, with the `proptest`
+//! feature.
+//!
+//! [`Predicate`], [`FirstOrder`], [`SecondOrder`], [`JSONPath`] and the
+//! `Regex` of the "matches" predicate implement [`Arbitrary`], and
+//! [`document`] generates the documents to evaluate them against.
+//!
+//! Paths are made of a few short tokens, some of them numeric or wildcards, so
+//! that they often reference something in the generated documents. No
+//! [`Extension`](crate::Extension) predicate is generated.
+
+use proptest::collection::{btree_map, vec};
+use proptest::option;
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use serde_json::{Number, Value};
+
+use crate::comparable::{Comparable, Temporal};
+use crate::json_path::JSONPath;
+use crate::predicate::first_order::{
+    contained::Contained, contains::Contains, defined::Defined, end::End, intersects::Intersects,
+    less::Less, matches::Matches, more::More, r#in::In, r#type::Type, start::Start, test::Test,
+    undefined::Undefined, FirstOrder,
+};
+use crate::predicate::operand::Operand;
+use crate::predicate::quantifier::Quantifier;
+use crate::predicate::second_order::{and::And, not::Not, or::Or, SecondOrder};
+use crate::predicate::Predicate;
+use crate::regex::Regex;
+
+/// The "value" members of the "type" predicate.
+const TYPES: &[&str] = &[
+    "number",
+    "string",
+    "boolean",
+    "object",
+    "array",
+    "null",
+    "undefined",
+    "date",
+    "date-time",
+    "time",
+    "lang",
+    "lang-range",
+    "iri",
+    "absolute-iri",
+];
+
+/// A reference token.
+fn token() -> impl Strategy<Value = String> {
+    prop_oneof![
+        4 => "[a-c]{1,2}",
+        2 => "[0-2]",
+        1 => Just("*".to_string()),
+        1 => Just("**".to_string()),
+        1 => "[~/ a%#]{0,3}",
+    ]
+}
+
+/// A date, time or date-time string as described by RFC3339.
+fn temporal() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "20[0-2][0-9]-0[1-9]-[0-2][1-9]",
+        "[01][0-9]:[0-5][0-9]:[0-5][0-9](Z|[+-]0[0-9]:[03]0)",
+        "20[0-2][0-9]-0[1-9]-[0-2][1-9]T[01][0-9]:[0-5][0-9]:[0-5][0-9](Z|[+-]0[0-9]:[03]0)",
+    ]
+}
+
+/// A number with two decimals, which survives a round trip through its
+/// textual representation.
+fn decimal() -> impl Strategy<Value = Number> {
+    any::<i32>().prop_filter_map("not a finite number", |cents| {
+        Number::from_f64(f64::from(cents) / 100.0)
+    })
+}
+
+/// A JSON document, of at most a few levels.
+pub fn document() -> BoxedStrategy<Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        (-3i64..10).prop_map(Value::from),
+        any::<i64>().prop_map(Value::from),
+        decimal().prop_map(Value::Number),
+        "[a-cA-C0-9 ]{0,4}".prop_map(Value::String),
+        temporal().prop_map(Value::String),
+    ];
+
+    leaf.prop_recursive(3, 16, 3, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..3).prop_map(Value::Array),
+            btree_map("[a-c]{1,2}|[0-2]", inner, 0..3)
+                .prop_map(|members| Value::Object(members.into_iter().collect())),
+        ]
+    })
+    .boxed()
+}
+
+fn comparable() -> impl Strategy<Value = Comparable> {
+    prop_oneof![
+        (-3i64..10).prop_map(Comparable::from),
+        decimal().prop_map(Comparable::Number),
+        temporal().prop_filter_map("not a temporal value", |value| {
+            Temporal::new(value).ok().map(Comparable::Temporal)
+        }),
+    ]
+}
+
+fn operand<T: std::fmt::Debug>(
+    value: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Operand<T>> {
+    prop_oneof![
+        3 => value.prop_map(Operand::Value),
+        1 => any::<JSONPath>().prop_map(Operand::Path),
+        1 => "[a-c]{1,2}".prop_map(Operand::Var),
+    ]
+}
+
+fn quantifier() -> impl Strategy<Value = Option<Quantifier>> {
+    option::of(prop_oneof![
+        Just(Quantifier::Any),
+        Just(Quantifier::All),
+        Just(Quantifier::None),
+        (0usize..3, option::of(0usize..3)).prop_map(|(min, extra)| Quantifier::Count {
+            min,
+            max: extra.map(|extra| min + extra),
+        }),
+    ])
+}
+
+fn path() -> impl Strategy<Value = Option<JSONPath>> {
+    option::of(any::<JSONPath>())
+}
+
+fn second_order(predicate: impl Strategy<Value = Predicate>) -> impl Strategy<Value = SecondOrder> {
+    (0..3u8, path(), vec(predicate, 0..4)).prop_map(|(op, path, apply)| match op {
+        0 => SecondOrder::And(And { path, apply }),
+        1 => SecondOrder::Or(Or { path, apply }),
+        _ => SecondOrder::Not(Not { path, apply }),
+    })
+}
+
+impl Arbitrary for JSONPath {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        vec(token(), 0..4).prop_map(JSONPath::from_tokens).boxed()
+    }
+}
+
+impl Arbitrary for Regex {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        "\\^?([a-cA-C.]{1,3}[*+?]?){0,2}\\$?"
+            .prop_filter_map("not a regex", |pattern| {
+                regex::Regex::new(&pattern).ok().map(Regex::from)
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for FirstOrder {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let ty = prop_oneof![
+            3 => proptest::sample::select(TYPES).prop_map(str::to_string),
+            1 => "[a-z]{1,6}",
+        ];
+
+        Union::new([
+            (path(), quantifier(), any::<bool>(), operand(document()))
+                .prop_map(|(path, quantifier, ignore_case, value)| {
+                    FirstOrder::Contains(Contains {
+                        path,
+                        quantifier,
+                        ignore_case,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier())
+                .prop_map(|(path, quantifier)| FirstOrder::Defined(Defined { path, quantifier }))
+                .boxed(),
+            (path(), quantifier())
+                .prop_map(|(path, quantifier)| {
+                    FirstOrder::Undefined(Undefined { path, quantifier })
+                })
+                .boxed(),
+            (path(), quantifier(), any::<bool>(), operand(document()))
+                .prop_map(|(path, quantifier, ignore_case, value)| {
+                    FirstOrder::Start(Start {
+                        path,
+                        quantifier,
+                        ignore_case,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier(), any::<bool>(), operand(document()))
+                .prop_map(|(path, quantifier, ignore_case, value)| {
+                    FirstOrder::End(End {
+                        path,
+                        quantifier,
+                        ignore_case,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier(), ty)
+                .prop_map(|(path, quantifier, value)| {
+                    FirstOrder::Type(Type {
+                        path,
+                        quantifier,
+                        value,
+                    })
+                })
+                .boxed(),
+            (
+                path(),
+                quantifier(),
+                any::<bool>(),
+                operand(vec(document(), 0..3)),
+            )
+                .prop_map(|(path, quantifier, ignore_case, value)| {
+                    FirstOrder::In(In {
+                        path,
+                        quantifier,
+                        ignore_case,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier(), any::<bool>(), operand(document()))
+                .prop_map(|(path, quantifier, ignore_case, value)| {
+                    FirstOrder::Test(Test {
+                        path,
+                        quantifier,
+                        ignore_case,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier(), any::<bool>(), any::<Regex>())
+                .prop_map(|(path, quantifier, ignore_case, value)| {
+                    FirstOrder::Matches(Matches {
+                        path,
+                        quantifier,
+                        ignore_case,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier(), operand(comparable()))
+                .prop_map(|(path, quantifier, value)| {
+                    FirstOrder::Less(Less {
+                        path,
+                        quantifier,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier(), operand(comparable()))
+                .prop_map(|(path, quantifier, value)| {
+                    FirstOrder::More(More {
+                        path,
+                        quantifier,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier(), any::<bool>(), vec(document(), 0..3))
+                .prop_map(|(path, quantifier, ignore_case, value)| {
+                    FirstOrder::Contained(Contained {
+                        path,
+                        quantifier,
+                        ignore_case,
+                        value,
+                    })
+                })
+                .boxed(),
+            (path(), quantifier(), any::<bool>(), vec(document(), 0..3))
+                .prop_map(|(path, quantifier, ignore_case, value)| {
+                    FirstOrder::Intersects(Intersects {
+                        path,
+                        quantifier,
+                        ignore_case,
+                        value,
+                    })
+                })
+                .boxed(),
+        ])
+        .boxed()
+    }
+}
+
+impl Arbitrary for SecondOrder {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        second_order(any::<Predicate>()).boxed()
+    }
+}
+
+impl Arbitrary for Predicate {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<FirstOrder>()
+            .prop_map(Predicate::FirstOrder)
+            .prop_recursive(3, 16, 4, |inner| {
+                second_order(inner).prop_map(Predicate::SecondOrder)
+            })
+            .boxed()
+    }
+}
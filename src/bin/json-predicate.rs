@@ -0,0 +1,157 @@
+//! Evaluate a JSON Predicate against JSON documents.
+//!
+//! Every file, or the standard input when no file is given, can contain one or
+//! more JSON documents (e.g. NDJSON), each of them being evaluated as a
+//! record.
+//!
+//! The exit status is `0` when a record matched the predicate (every record
+//! with `--all`), `1` when none did and `2` when an error occurred.
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Parser;
+use json_predicate::context::PredicateContext;
+use json_predicate::{Predicate, PredicateImpl};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Evaluate a JSON Predicate against JSON documents")]
+struct Args {
+    /// The file containing the predicate.
+    predicate: PathBuf,
+    /// The files containing the JSON documents, the standard input is read
+    /// when none is given or for `-`.
+    files: Vec<PathBuf>,
+    /// The JSON Pointer the predicate is evaluated from.
+    #[arg(short = 'C', long, value_name = "POINTER")]
+    context: Option<String>,
+    /// Bind a variable the predicate can reference as `{"$var": "NAME"}`, the
+    /// value being JSON.
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    vars: Vec<String>,
+    /// Print the records matching the predicate, one per line.
+    #[arg(short, long)]
+    print: bool,
+    /// Print the explanation of every evaluation to the standard error.
+    #[arg(short, long)]
+    explain: bool,
+    /// Only succeed if every record matches the predicate.
+    #[arg(short, long)]
+    all: bool,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(err) => {
+            eprintln!("json-predicate: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<bool, String> {
+    let predicate = File::open(&args.predicate)
+        .map_err(|err| format!("{}: {err}", args.predicate.display()))?;
+    let predicate: Value = serde_json::from_reader(BufReader::new(predicate))
+        .map_err(|err| format!("{}: {err}", args.predicate.display()))?;
+    let predicate = Predicate::deserialize(predicate)
+        .map_err(|err| format!("{}: invalid predicate: {err}", args.predicate.display()))?;
+
+    let ctx = match &args.context {
+        Some(context) => PredicateContext::new(context.clone())
+            .map_err(|err| format!("invalid context: {err}"))?,
+        None => PredicateContext::default(),
+    };
+    let ctx = args.vars.iter().try_fold(ctx, |ctx, var| {
+        let (name, value) = var
+            .split_once('=')
+            .ok_or_else(|| format!("invalid variable {var:?}: expected NAME=VALUE"))?;
+        let value: Value = serde_json::from_str(value)
+            .map_err(|err| format!("invalid variable {name:?}: {err}"))?;
+        Ok::<_, String>(ctx.with_variable(name, value))
+    })?;
+
+    let mut evaluation = Evaluation {
+        args,
+        predicate: &predicate,
+        ctx: &ctx,
+        out: BufWriter::new(io::stdout().lock()),
+        matched: 0,
+        evaluated: 0,
+    };
+
+    let stdin = Path::new("-");
+    let files: Vec<&Path> = if args.files.is_empty() {
+        vec![stdin]
+    } else {
+        args.files.iter().map(PathBuf::as_path).collect()
+    };
+
+    for file in files {
+        if file == stdin {
+            evaluation.records("<stdin>", io::stdin().lock())?;
+        } else {
+            let name = file.display().to_string();
+            let reader = File::open(file).map_err(|err| format!("{name}: {err}"))?;
+            evaluation.records(&name, BufReader::new(reader))?;
+        }
+    }
+
+    evaluation
+        .out
+        .flush()
+        .map_err(|err| format!("<stdout>: {err}"))?;
+
+    Ok(if args.all {
+        evaluation.evaluated > 0 && evaluation.matched == evaluation.evaluated
+    } else {
+        evaluation.matched > 0
+    })
+}
+
+struct Evaluation<'a, W: Write> {
+    args: &'a Args,
+    predicate: &'a Predicate,
+    ctx: &'a PredicateContext,
+    out: W,
+    matched: usize,
+    evaluated: usize,
+}
+
+impl<W: Write> Evaluation<'_, W> {
+    fn records<R: BufRead>(&mut self, name: &str, reader: R) -> Result<(), String> {
+        let records = serde_json::Deserializer::from_reader(reader).into_iter::<Value>();
+
+        for (index, record) in records.enumerate() {
+            let record = record.map_err(|err| format!("{name}: {err}"))?;
+            self.evaluated += 1;
+
+            let outcome = if self.args.explain {
+                let explanation = self.predicate.explain(&record, self.ctx.clone());
+                eprint!("{name}: record {}\n{explanation}", index + 1);
+                explanation.outcome
+            } else {
+                self.predicate.evaluate(&record, self.ctx.clone())
+            };
+
+            // A record which couldn't be evaluated doesn't match.
+            if outcome.is_true() {
+                self.matched += 1;
+                if self.args.print {
+                    serde_json::to_writer(&mut self.out, &record)
+                        .map_err(|err| format!("<stdout>: {err}"))?;
+                    writeln!(self.out).map_err(|err| format!("<stdout>: {err}"))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveTime, Timelike};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+/// The "value" member of the "less" and "more" predicates: either a number or
+/// a date, time or date-time string as described by
+/// [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Comparable {
+    Number(Number),
+    Temporal(Temporal),
+}
+
+/// A "full-date", "full-time" or "date-time" string as described by
+/// [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6).
+///
+/// Times and date-times are compared as instants, their offsets being
+/// normalized, and only values of the same kind can be compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Temporal {
+    raw: String,
+    value: TemporalValue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TemporalValue {
+    Date(NaiveDate),
+    /// A time, as an instant of 1970-01-01.
+    Time(DateTime<FixedOffset>),
+    DateTime(DateTime<FixedOffset>),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("The value is not a date, a time or a date-time as described by RFC3339.")]
+pub struct TemporalError;
+
+impl TemporalValue {
+    fn parse(value: &str) -> Option<Self> {
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+            return Some(Self::DateTime(date_time));
+        }
+        if let Ok(date) = NaiveDate::from_str(value) {
+            return Some(Self::Date(date));
+        }
+        parse_time(value).map(Self::Time)
+    }
+
+    /// Parse `value` as a temporal value of the same kind.
+    fn parse_same_kind(&self, value: &str) -> Option<Self> {
+        match self {
+            Self::Date(_) => NaiveDate::from_str(value).ok().map(Self::Date),
+            Self::Time(_) => parse_time(value).map(Self::Time),
+            Self::DateTime(_) => DateTime::parse_from_rfc3339(value).ok().map(Self::DateTime),
+        }
+    }
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Date(left), Self::Date(right)) => Some(left.cmp(right)),
+            (Self::Time(left), Self::Time(right)) => Some(left.cmp(right)),
+            (Self::DateTime(left), Self::DateTime(right)) => Some(left.cmp(right)),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a "full-time", e.g. "10:10:10Z" or "10:10:10.5+05:30", as an
+/// instant of 1970-01-01 so that offsets are normalized.
+fn parse_time(value: &str) -> Option<DateTime<FixedOffset>> {
+    let (time, offset) = match value.strip_suffix(['Z', 'z']) {
+        Some(time) => (time, FixedOffset::east_opt(0)?),
+        None => {
+            let idx = value.rfind(['+', '-'])?;
+            let (time, offset) = value.split_at(idx);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let offset = NaiveTime::parse_from_str(&offset[1..], "%H:%M").ok()?;
+            let seconds = offset.num_seconds_from_midnight() as i32;
+            (time, FixedOffset::east_opt(sign * seconds)?)
+        }
+    };
+    let time = NaiveTime::parse_from_str(time, "%H:%M:%S%.f").ok()?;
+
+    NaiveDate::from_ymd_opt(1970, 1, 1)?
+        .and_time(time)
+        .and_local_timezone(offset)
+        .single()
+}
+
+impl Temporal {
+    pub fn new<S: Into<String>>(value: S) -> Result<Temporal, TemporalError> {
+        let raw = value.into();
+        let value = TemporalValue::parse(&raw).ok_or(TemporalError)?;
+        Ok(Temporal { raw, value })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Whether the value is a "full-time", which has no date.
+    pub(crate) fn is_time(&self) -> bool {
+        matches!(self.value, TemporalValue::Time(_))
+    }
+
+    /// A value of the same kind, one day or one second before or after this
+    /// one.
+    pub(crate) fn shifted(&self, later: bool) -> Option<Temporal> {
+        let sign = if later { 1 } else { -1 };
+        let raw = match self.value {
+            TemporalValue::Date(date) => date.checked_add_signed(Duration::days(sign))?.to_string(),
+            TemporalValue::Time(time) => time
+                .checked_add_signed(Duration::seconds(sign))?
+                .format("%H:%M:%S%.f%:z")
+                .to_string(),
+            TemporalValue::DateTime(date_time) => date_time
+                .checked_add_signed(Duration::seconds(sign))?
+                .to_rfc3339(),
+        };
+        Temporal::new(raw).ok()
+    }
+
+    /// The ordering of `value` compared to this one, `None` if `value` isn't
+    /// of the same kind.
+    fn cmp_str(&self, value: &str) -> Option<Ordering> {
+        self.value.parse_same_kind(value)?.partial_cmp(&self.value)
+    }
+}
+
+impl Display for Temporal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl Comparable {
+    /// The ordering of the referenced element compared to this value, `None`
+    /// if they can't be compared.
+    pub(crate) fn cmp_value(&self, context: &Value) -> Option<Ordering> {
+        match (self, context) {
+            (Comparable::Number(value), Value::Number(context)) => {
+                context.as_f64()?.partial_cmp(&value.as_f64()?)
+            }
+            (Comparable::Temporal(value), Value::String(context)) => value.cmp_str(context),
+            _ => None,
+        }
+    }
+}
+
+impl From<Number> for Comparable {
+    fn from(value: Number) -> Self {
+        Comparable::Number(value)
+    }
+}
+
+impl From<Temporal> for Comparable {
+    fn from(value: Temporal) -> Self {
+        Comparable::Temporal(value)
+    }
+}
+
+macro_rules! from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Comparable {
+                fn from(value: $ty) -> Self {
+                    Comparable::Number(Number::from(value))
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl Serialize for Comparable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Comparable::Number(value) => value.serialize(serializer),
+            Comparable::Temporal(value) => serializer.serialize_str(&value.raw),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Comparable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct VisitorComparable<'de> {
+            marker: PhantomData<Comparable>,
+            lifetime: PhantomData<&'de ()>,
+        }
+
+        impl<'de> Visitor<'de> for VisitorComparable<'de> {
+            type Value = Comparable;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a number or a date, time or date-time string")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Comparable::from(v))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Comparable::from(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Number::from_f64(v)
+                    .map(Comparable::Number)
+                    .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Float(v), &self))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Temporal::new(v)
+                    .map(Comparable::Temporal)
+                    .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        Deserializer::deserialize_any(
+            deserializer,
+            VisitorComparable {
+                marker: PhantomData::<Comparable>,
+                lifetime: PhantomData,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use serde::Deserialize;
+
+    use crate::comparable::{Comparable, Temporal};
+
+    #[test]
+    fn deser_test() {
+        let number = Comparable::deserialize(serde_json::json!(12)).unwrap();
+        assert_eq!(number, Comparable::from(12));
+
+        let date_time = Comparable::deserialize(serde_json::json!("2010-10-10T10:10:10Z")).unwrap();
+        assert_eq!(
+            date_time,
+            Comparable::from(Temporal::new("2010-10-10T10:10:10Z").unwrap())
+        );
+        assert_eq!(
+            serde_json::to_value(date_time).unwrap(),
+            serde_json::json!("2010-10-10T10:10:10Z")
+        );
+
+        assert!(Comparable::deserialize(serde_json::json!("XYZ")).is_err());
+        assert!(Comparable::deserialize(serde_json::json!(["a"])).is_err());
+    }
+
+    #[test]
+    fn cmp_test() {
+        let cmp = |value: &str, context: &str| {
+            Comparable::from(Temporal::new(value).unwrap()).cmp_value(&serde_json::json!(context))
+        };
+
+        assert_eq!(
+            cmp("2010-10-10T10:10:10Z", "2010-10-10T10:10:10+05:30"),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            cmp("2010-10-10T04:40:10Z", "2010-10-10T10:10:10+05:30"),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(cmp("2010-10-10", "2010-10-11"), Some(Ordering::Greater));
+        assert_eq!(cmp("10:10:10Z", "10:10:10+05:30"), Some(Ordering::Less));
+        assert_eq!(
+            cmp("04:40:10Z", "10:10:10.000+05:30"),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(cmp("10:10:10-01:00", "10:10:10Z"), Some(Ordering::Less));
+        assert_eq!(cmp("2010-10-10", "2010-10-10T10:10:10Z"), None);
+        assert_eq!(cmp("2010-10-10", "XYZ"), None);
+    }
+}
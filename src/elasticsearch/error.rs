@@ -0,0 +1,37 @@
+use crate::json_path::JSONPath;
+
+/// Error raised while converting a [`Predicate`](crate::Predicate) into an
+/// Elasticsearch query.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ElasticsearchError {
+    /// The predicate at `location` in the predicate document, e.g.
+    /// `/apply/1`, has no Elasticsearch equivalent.
+    #[error(
+        "`{op}`{} can't be converted into an Elasticsearch query: {reason}",
+        at(location)
+    )]
+    Unsupported {
+        op: String,
+        location: JSONPath,
+        reason: &'static str,
+    },
+}
+
+impl ElasticsearchError {
+    /// The location of the predicate which couldn't be converted, in the
+    /// predicate document.
+    pub fn location(&self) -> &JSONPath {
+        match self {
+            Self::Unsupported { location, .. } => location,
+        }
+    }
+}
+
+/// The location of a predicate, omitted for the root one.
+fn at(location: &JSONPath) -> String {
+    if location.tokens().is_empty() {
+        String::new()
+    } else {
+        format!(" at {location}")
+    }
+}
@@ -0,0 +1,353 @@
+//! Conversion of predicates into [Elasticsearch query
+//! DSL](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl.html)
+//! `bool` queries, JSON Pointers being written as dotted field names. The
+//! queries only use what Elasticsearch and OpenSearch have in common.
+//!
+//! ```rust
+//! use json_predicate::context::PredicateContext;
+//! use json_predicate::Predicate;
+//! use serde_json::json;
+//!
+//! let predicate: Predicate = r#"/user/age > 17 and /user/role in ["admin", "owner"]"#.parse()?;
+//! assert_eq!(
+//!     predicate.to_elasticsearch(PredicateContext::default())?,
+//!     json!({"bool": {"must": [
+//!         {"range": {"user.age": {"gt": 17}}},
+//!         {"terms": {"user.role": ["admin", "owner"]}},
+//!     ]}})
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! A query matches the documents a predicate holds for, provided strings are
+//! indexed as `keyword` fields, and dates and date-times as `date` ones. The
+//! query of a negated predicate also requires its field to exist, as the
+//! predicate can't be evaluated without it.
+//!
+//! "test" becomes a `term` query, "in" and "intersects" `terms`, "less" and
+//! "more" `range`, "starts" `prefix`, "ends" and "contains" `wildcard`,
+//! "matches" `regexp`, "defined" and "undefined" `exists`, and the Second
+//! Order predicates `bool` queries with `must`, `should` and `must_not`
+//! clauses. The case-insensitive variants rely on the `case_insensitive`
+//! parameter, and variables are resolved from the [`PredicateContext`]. A
+//! quantifier, a "value" member referencing another location of the
+//! document, a time, a comparison with `null`, an array or an object, the
+//! "type" and "contained" predicates, a regex using what Lucene regular
+//! expressions lack, like flags or escape sequences other than escaped
+//! punctuation, the operations registered by the application and a path
+//! which can't be written as a dotted field name are reported as
+//! [`ElasticsearchError::Unsupported`].
+//!
+//! A few differences remain:
+//!
+//! - Elasticsearch flattens arrays: a query matches a field holding an array
+//!   when one of its items matches, so "intersects" also matches a field
+//!   equal to one of the values, and an index in a path references an object
+//!   member;
+//! - a field holding `null` or an empty array doesn't exist for `exists`;
+//! - Elasticsearch compares numbers by value, so `1` and `1.0` are equal, and
+//!   dates with date-times, as instants.
+//!
+//! [`PredicateContext`]: crate::context::PredicateContext
+use serde_json::{json, Value};
+
+use crate::comparable::Comparable;
+use crate::json_path::JSONPath;
+use crate::predicate::context::PredicateContext;
+use crate::predicate::convert::{self, bound, Target};
+use crate::predicate::first_order::FirstOrder;
+use crate::predicate::Predicate;
+
+mod error;
+
+pub use error::ElasticsearchError;
+
+impl Predicate {
+    /// Convert the predicate into an Elasticsearch query matching the
+    /// documents it holds for, paths being resolved from `ctx`.
+    ///
+    /// See the [`elasticsearch`](crate::elasticsearch) module for what can be
+    /// converted.
+    pub fn to_elasticsearch(&self, ctx: PredicateContext) -> Result<Value, ElasticsearchError> {
+        convert::to_query::<Elasticsearch>(self, &ctx, false, &mut Vec::new())
+    }
+}
+
+/// The Elasticsearch queries predicates are converted into.
+struct Elasticsearch;
+
+impl Target for Elasticsearch {
+    type Query = Value;
+    type Error = ElasticsearchError;
+
+    fn constant(value: bool) -> Value {
+        constant(value)
+    }
+
+    fn combine(queries: Vec<Value>, all: bool) -> Value {
+        combine(queries, if all { "must" } else { "should" })
+    }
+
+    fn first_order(
+        first_order: &FirstOrder,
+        path: &JSONPath,
+        ctx: &PredicateContext,
+        negated: bool,
+    ) -> Result<Value, &'static str> {
+        let field = field(path)?;
+
+        let check = match first_order {
+            FirstOrder::Defined(_) => return Ok(exists(&field, !negated)),
+            FirstOrder::Undefined(_) => return Ok(exists(&field, negated)),
+            FirstOrder::Test(test) => {
+                let value = bound!(ctx, &test.value);
+                term(&field, &value, test.ignore_case)?
+            }
+            FirstOrder::In(r#in) => {
+                let values = bound!(ctx, &r#in.value);
+                terms(&field, &values, r#in.ignore_case)?
+            }
+            FirstOrder::Intersects(intersects) => {
+                terms(&field, &intersects.value, intersects.ignore_case)?
+            }
+            FirstOrder::Less(less) => range(&field, "lt", &*bound!(ctx, &less.value))?,
+            FirstOrder::More(more) => range(&field, "gt", &*bound!(ctx, &more.value))?,
+            FirstOrder::Start(start) => match &*bound!(ctx, &start.value) {
+                Value::String(value) => leaf("prefix", &field, value.as_str(), start.ignore_case),
+                // A value which isn't a string never matches.
+                _ => constant(false),
+            },
+            FirstOrder::End(end) => match &*bound!(ctx, &end.value) {
+                Value::String(value) => {
+                    let pattern = format!("*{}", wildcard_escape(value));
+                    leaf("wildcard", &field, pattern.as_str(), end.ignore_case)
+                }
+                _ => constant(false),
+            },
+            FirstOrder::Contains(contains) => match &*bound!(ctx, &contains.value) {
+                Value::String(value) => {
+                    let pattern = format!("*{}*", wildcard_escape(value));
+                    leaf("wildcard", &field, pattern.as_str(), contains.ignore_case)
+                }
+                _ => constant(false),
+            },
+            FirstOrder::Matches(matches) => {
+                let pattern = regexp(matches.value.as_ref().as_str())?;
+                let mut query = json!({ "value": pattern, "flags": "NONE" });
+                if matches.ignore_case {
+                    query["case_insensitive"] = true.into();
+                }
+                json!({ "regexp": { field.as_str(): query } })
+            }
+            FirstOrder::Type(_) => return Err("Elasticsearch can't check the type of a value"),
+            FirstOrder::Contained(_) => return Err("the operation isn't supported"),
+        };
+
+        Ok(match negated {
+            false => check,
+            // The predicate can't be evaluated when the element doesn't exist.
+            true => json!({ "bool": { "must": [exists(&field, true)], "must_not": [check] } }),
+        })
+    }
+
+    fn unsupported(op: &str, location: JSONPath, reason: &'static str) -> ElasticsearchError {
+        ElasticsearchError::Unsupported {
+            op: op.to_string(),
+            location,
+            reason,
+        }
+    }
+}
+
+/// The dotted field name of `path`.
+fn field(path: &JSONPath) -> Result<String, &'static str> {
+    let tokens = path.tokens();
+    if tokens.is_empty() {
+        return Err("a query can't reference the whole document");
+    }
+    if tokens
+        .iter()
+        .any(|token| token.is_empty() || token.contains('.'))
+    {
+        return Err("a field whose name is empty or has a dot can't be referenced");
+    }
+    Ok(tokens.join("."))
+}
+
+/// A query matching every document when `value` is true, and none otherwise.
+fn constant(value: bool) -> Value {
+    match value {
+        true => json!({ "match_all": {} }),
+        false => json!({ "match_none": {} }),
+    }
+}
+
+/// Combine the queries as the `occur` clauses of a `bool` query, `must` or
+/// `should`.
+fn combine(mut queries: Vec<Value>, occur: &str) -> Value {
+    match queries.len() {
+        1 => queries.remove(0),
+        _ => json!({ "bool": { occur: queries } }),
+    }
+}
+
+fn exists(field: &str, exists: bool) -> Value {
+    let query = json!({ "exists": { "field": field } });
+    match exists {
+        true => query,
+        false => json!({ "bool": { "must_not": [query] } }),
+    }
+}
+
+/// A term-level query `name` matching the field with `value`.
+fn leaf(name: &str, field: &str, value: impl Into<Value>, ignore_case: bool) -> Value {
+    let mut query = json!({ "value": value.into() });
+    if ignore_case {
+        query["case_insensitive"] = true.into();
+    }
+    json!({ name: { field: query } })
+}
+
+/// Check that `value` can be compared with a field.
+fn scalar(value: &Value) -> Result<(), &'static str> {
+    match value {
+        Value::Null => Err("Elasticsearch doesn't index null values"),
+        Value::Array(_) | Value::Object(_) => {
+            Err("Elasticsearch can't compare an array or an object as a whole")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The query matching the field when it's equal to `value`.
+fn term(field: &str, value: &Value, ignore_case: bool) -> Result<Value, &'static str> {
+    scalar(value)?;
+    Ok(leaf(
+        "term",
+        field,
+        value.clone(),
+        ignore_case && value.is_string(),
+    ))
+}
+
+/// The query matching the field when it's equal to one of `values`.
+fn terms(field: &str, values: &[Value], ignore_case: bool) -> Result<Value, &'static str> {
+    for value in values {
+        scalar(value)?;
+    }
+
+    // `terms` has no `case_insensitive` parameter.
+    if ignore_case && values.iter().any(Value::is_string) && values.len() > 1 {
+        let queries = values
+            .iter()
+            .map(|value| term(field, value, true))
+            .collect::<Result<_, _>>()?;
+        return Ok(combine(queries, "should"));
+    }
+    Ok(match values {
+        [value] => term(field, value, ignore_case)?,
+        values => json!({ "terms": { field: values } }),
+    })
+}
+
+/// The query matching the field when it's lower (`lt`) or greater (`gt`) than
+/// `value`.
+fn range(field: &str, op: &str, value: &Comparable) -> Result<Value, &'static str> {
+    let value = match value {
+        Comparable::Number(number) => Value::Number(number.clone()),
+        Comparable::Temporal(temporal) if temporal.is_time() => {
+            return Err("Elasticsearch has no type for times");
+        }
+        Comparable::Temporal(temporal) => temporal.as_str().into(),
+    };
+    Ok(json!({ "range": { field: { op: value } } }))
+}
+
+/// Escape the wildcards of a `wildcard` query.
+fn wildcard_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '*' | '?' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The Lucene regular expression, which is anchored and read with the `NONE`
+/// flags, matching the strings in which `pattern` finds a match.
+fn regexp(pattern: &str) -> Result<String, &'static str> {
+    let (body, start) = match pattern.strip_prefix('^') {
+        Some(body) => (body, ""),
+        None => (pattern, ".*"),
+    };
+    let (body, end) = match body.strip_suffix('$') {
+        // An escaped `$` is a literal one.
+        Some(rest) if rest.chars().rev().take_while(|&c| c == '\\').count() % 2 == 0 => (rest, ""),
+        _ => (body, ".*"),
+    };
+
+    let mut translated = String::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+    let (mut depth, mut class, mut alternation, mut quantified) = (0, false, false, false);
+    while let Some(c) = chars.next() {
+        let quantifier = !class && matches!(c, '*' | '+' | '?' | '}');
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) if escaped.is_ascii_punctuation() => {
+                    translated.extend(['\\', escaped]);
+                }
+                _ => return Err("only escaped punctuation is supported in a regex"),
+            },
+            '?' if quantified => return Err("lazy quantifiers aren't supported"),
+            '[' if class => return Err("nested character classes aren't supported"),
+            '[' => {
+                class = true;
+                translated.push('[');
+                if let Some(negation) = chars.next_if_eq(&'^') {
+                    translated.push(negation);
+                }
+            }
+            ']' if class => {
+                class = false;
+                translated.push(']');
+            }
+            '&' | '-' | '~' if class && chars.peek() == Some(&c) => {
+                return Err("character class set operations aren't supported");
+            }
+            '"' => translated.push_str("\\\""),
+            // A Rust regex dot doesn't match a line feed, a Lucene one does.
+            '.' if !class => translated.push_str("[^\n]"),
+            '^' | '$' if !class => {
+                return Err("anchors are only supported at the start and at the end of a regex");
+            }
+            '(' if !class && chars.peek() == Some(&'?') => {
+                return Err("flags and non-capturing groups aren't supported");
+            }
+            '(' if !class => {
+                depth += 1;
+                translated.push('(');
+            }
+            ')' if !class => {
+                depth -= 1;
+                translated.push(')');
+            }
+            '|' if !class && depth == 0 => {
+                alternation = true;
+                translated.push('|');
+            }
+            c => translated.push(c),
+        }
+        quantified = quantifier;
+    }
+
+    if !alternation {
+        return Ok(format!("{start}{translated}{end}"));
+    }
+    // An anchor only applies to the alternative it's written in.
+    if start.is_empty() || end.is_empty() {
+        return Err("anchors are only supported at the start and at the end of a regex");
+    }
+    Ok(format!("{start}({translated}){end}"))
+}
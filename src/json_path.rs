@@ -1,6 +1,8 @@
-use json_pointer::{JsonPointer, ParseError};
+use json_pointer::{IndexError, JsonPointer, ParseError};
 use serde::{Deserialize, Deserializer, Serialize};
-use std::ops::Add;
+use serde_json::Value;
+use std::fmt::Display;
+use std::ops::{Add, ControlFlow};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct JSONPath(JsonPointer<String, Vec<String>>);
@@ -42,6 +44,92 @@ impl JSONPath {
     pub fn take(self) -> JsonPointer<String, Vec<String>> {
         self.0
     }
+
+    /// The pointer made of the reference tokens `tokens`.
+    pub(crate) fn from_tokens(tokens: Vec<String>) -> Self {
+        Self(JsonPointer::new(tokens))
+    }
+
+    /// Resolve the pointer against the provided JSON.
+    pub fn get<'json>(&self, data: &'json Value) -> Result<&'json Value, IndexError> {
+        self.0.get(data)
+    }
+
+    /// The reference tokens of the pointer.
+    pub(crate) fn tokens(&self) -> Vec<String> {
+        let mut remaining = self.0.clone();
+        let mut tokens = Vec::new();
+        while let Some(token) = remaining.pop() {
+            tokens.push(token);
+        }
+        tokens.reverse();
+        tokens
+    }
+}
+
+/// Resolve a single reference token against the provided JSON.
+pub(crate) fn index<'json>(value: &'json Value, token: &str) -> Option<&'json Value> {
+    match value {
+        Value::Object(map) => map.get(token),
+        Value::Array(array) => {
+            if token == "-" {
+                None
+            } else {
+                array.get(token.parse::<usize>().ok()?)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Visit every value referenced by the reference tokens, `*` matching every
+/// element of an array or member of an object and `**` any number of
+/// levels, including none, until `f` breaks.
+pub(crate) fn visit_matches<'json, S, B>(
+    tokens: &[S],
+    value: &'json Value,
+    f: &mut impl FnMut(&'json Value) -> ControlFlow<B>,
+) -> ControlFlow<B>
+where
+    S: AsRef<str>,
+{
+    let Some((token, rest)) = tokens.split_first() else {
+        return f(value);
+    };
+
+    match token.as_ref() {
+        "*" => match value {
+            Value::Object(map) => map
+                .values()
+                .try_for_each(|child| visit_matches(rest, child, f)),
+            Value::Array(array) => array
+                .iter()
+                .try_for_each(|child| visit_matches(rest, child, f)),
+            _ => ControlFlow::Continue(()),
+        },
+        "**" => {
+            visit_matches(rest, value, f)?;
+            match value {
+                Value::Object(map) => map
+                    .values()
+                    .try_for_each(|child| visit_matches(tokens, child, f)),
+                Value::Array(array) => array
+                    .iter()
+                    .try_for_each(|child| visit_matches(tokens, child, f)),
+                _ => ControlFlow::Continue(()),
+            }
+        }
+        token => match index(value, token) {
+            Some(child) => visit_matches(rest, child, f),
+            None => ControlFlow::Continue(()),
+        },
+    }
+}
+
+impl Display for JSONPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
 }
 
 impl Serialize for JSONPath {
@@ -57,10 +145,11 @@ impl Serialize for JSONPath {
 impl Add for JSONPath {
     type Output = Self;
 
-    fn add(self, other: Self) -> Self::Output {
-        // TODO: Fix it because it's an unwrap that shouldn't be here as we should be sure that it
-        // works.
-        Self::new(format!("{}{}", self.0, other.0)).unwrap()
+    fn add(mut self, other: Self) -> Self::Output {
+        for token in other.tokens() {
+            self.0.push(token);
+        }
+        self
     }
 }
 
@@ -70,9 +159,6 @@ impl<'de> Deserialize<'de> for JSONPath {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        let ptr = s
-            .parse::<JsonPointer<_, _>>()
-            .map_err(|e| serde::de::Error::custom(format!("{:?}", e)))?;
-        Ok(JSONPath(ptr))
+        JSONPath::new(s).map_err(serde::de::Error::custom)
     }
 }
@@ -0,0 +1,44 @@
+use crate::json_path::JSONPath;
+
+/// Error raised while converting a [`Predicate`](crate::Predicate) into a
+/// JsonLogic rule, or a JsonLogic rule into a predicate.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum JsonLogicError {
+    /// The predicate at `location` in the predicate document, e.g.
+    /// `/apply/1`, has no JsonLogic equivalent.
+    #[error(
+        "`{op}`{} can't be converted into a JsonLogic rule: {reason}",
+        at(location)
+    )]
+    Unsupported {
+        op: String,
+        location: JSONPath,
+        reason: &'static str,
+    },
+    /// The member at `location` in the rule, e.g. `/and/1/</0`, isn't valid
+    /// or has no predicate equivalent.
+    #[error(
+        "The JsonLogic rule{} can't be converted into a predicate: {reason}",
+        at(location)
+    )]
+    Rule { location: JSONPath, reason: String },
+}
+
+impl JsonLogicError {
+    /// The location of the predicate or of the rule member which couldn't be
+    /// converted.
+    pub fn location(&self) -> &JSONPath {
+        match self {
+            Self::Unsupported { location, .. } | Self::Rule { location, .. } => location,
+        }
+    }
+}
+
+/// The location of a predicate or a rule member, omitted for the root one.
+fn at(location: &JSONPath) -> String {
+    if location.tokens().is_empty() {
+        String::new()
+    } else {
+        format!(" at {location}")
+    }
+}
@@ -0,0 +1,523 @@
+//! Conversion between predicates and [JsonLogic](https://jsonlogic.com)
+//! rules, JSON Pointers being written as the dotted paths of `var`
+//! operations.
+//!
+//! ```rust
+//! use json_predicate::context::PredicateContext;
+//! use json_predicate::{Predicate, PredicateImpl};
+//! use serde_json::json;
+//!
+//! let predicate: Predicate = r#"/user/age > 17 and /user/role in ["admin", "owner"]"#.parse()?;
+//! assert_eq!(
+//!     predicate.to_jsonlogic(PredicateContext::default())?,
+//!     json!({"and": [
+//!         {"!==": [{"var": "user.age"}, null]},
+//!         {">": [{"var": "user.age"}, 17]},
+//!         {"in": [{"var": "user.role"}, ["admin", "owner"]]},
+//!     ]})
+//! );
+//!
+//! let rule = json!({"and": [{"<": [17, {"var": "age"}]}, {"in": ["beta", {"var": "flags"}]}]});
+//! let predicate = Predicate::from_jsonlogic(&rule)?;
+//! assert!(predicate.test(&json!({"age": 30, "flags": ["beta"]}), PredicateContext::default()));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! A rule is truthy for the documents a predicate holds for. JsonLogic reads
+//! a missing value as `null`, so the rules of the negated predicates and of
+//! "less" and "more" check that their value isn't `null`, and the predicates
+//! converted from a negation are guarded with "defined".
+//!
+//! [`Predicate::to_jsonlogic`] converts the "test", "in", "less", "more",
+//! "contains" and "intersects" predicates, their case-insensitive variants
+//! for values which aren't strings, "defined", "undefined" and the Second
+//! Order predicates, variables being resolved from the
+//! [`PredicateContext`]. Anything else, a comparison with `null`, an array or
+//! an object, and a path which can't be written as a dotted path are
+//! reported as [`JsonLogicError::Unsupported`].
+//!
+//! [`Predicate::from_jsonlogic`] converts the boolean subset of JsonLogic:
+//! the `==`, `===`, `!=`, `!==`, `in`, `<` and `>` operations comparing a
+//! `var` with a literal value, `and`, `or`, `!` and the `true` and `false`
+//! literals. Any other operation, a `var` with a default value, a comparison
+//! with an array or an object and an ordering of something else than numbers
+//! are reported as [`JsonLogicError::Rule`].
+//!
+//! A few differences remain:
+//!
+//! - JsonLogic reads a missing value as `null`, so "defined" and "undefined"
+//!   become comparisons with `null`;
+//! - JsonLogic coerces values like JavaScript, e.g. `"1" == 1` or
+//!   `null < 1`, and its `in` operation looks into both strings and arrays,
+//!   whereas a predicate only compares values of the expected types;
+//! - JsonLogic compares numbers by value, so `1` and `1.0` are equal.
+//!
+//! [`PredicateContext`]: crate::context::PredicateContext
+use serde_json::{json, Value};
+
+use crate::comparable::Comparable;
+use crate::json_path::JSONPath;
+use crate::predicate::context::PredicateContext;
+use crate::predicate::convert::{self, all, any, bound, constant, guarded, or_undefined, Target};
+use crate::predicate::first_order::{
+    contains::Contains, intersects::Intersects, less::Less, more::More, r#in::In, test::Test,
+    FirstOrder,
+};
+use crate::predicate::operand::Operand;
+use crate::predicate::second_order::{and::And, not::Not, SecondOrder};
+use crate::predicate::Predicate;
+
+mod error;
+
+pub use error::JsonLogicError;
+
+impl Predicate {
+    /// Convert the predicate into a JsonLogic rule which is truthy for the
+    /// documents it holds for, paths being resolved from `ctx`.
+    ///
+    /// See the [`jsonlogic`](crate::jsonlogic) module for what can be
+    /// converted.
+    pub fn to_jsonlogic(&self, ctx: PredicateContext) -> Result<Value, JsonLogicError> {
+        convert::to_query::<JsonLogic>(self, &ctx, false, &mut Vec::new())
+    }
+
+    /// Convert a JsonLogic rule into a predicate holding for the documents
+    /// it's truthy for.
+    ///
+    /// See the [`jsonlogic`](crate::jsonlogic) module for what can be
+    /// converted.
+    pub fn from_jsonlogic(rule: &Value) -> Result<Predicate, JsonLogicError> {
+        from_rule(rule, false, &mut Vec::new())
+    }
+}
+
+/// The JsonLogic rules predicates are converted into.
+struct JsonLogic;
+
+impl Target for JsonLogic {
+    type Query = Value;
+    type Error = JsonLogicError;
+
+    fn constant(value: bool) -> Value {
+        Value::Bool(value)
+    }
+
+    fn combine(rules: Vec<Value>, all: bool) -> Value {
+        combine(rules, if all { "and" } else { "or" })
+    }
+
+    fn first_order(
+        first_order: &FirstOrder,
+        path: &JSONPath,
+        ctx: &PredicateContext,
+        negated: bool,
+    ) -> Result<Value, &'static str> {
+        let var = json!({ "var": var_name(path)? });
+
+        let check = match first_order {
+            FirstOrder::Defined(_) => return Ok(null_check(&var, !negated)),
+            FirstOrder::Undefined(_) => return Ok(null_check(&var, negated)),
+            FirstOrder::Test(test) => {
+                let value = bound!(ctx, &test.value);
+                literal(&value, test.ignore_case)?;
+                json!({ "===": [var, value] })
+            }
+            FirstOrder::In(r#in) => {
+                let values = bound!(ctx, &r#in.value);
+                for value in values.iter() {
+                    literal(value, r#in.ignore_case)?;
+                }
+                json!({ "in": [var, values] })
+            }
+            FirstOrder::Less(less) => {
+                let Comparable::Number(number) = &*bound!(ctx, &less.value) else {
+                    return Err("JsonLogic compares dates and times as strings");
+                };
+                json!({ "<": [var, number] })
+            }
+            FirstOrder::More(more) => {
+                let Comparable::Number(number) = &*bound!(ctx, &more.value) else {
+                    return Err("JsonLogic compares dates and times as strings");
+                };
+                json!({ ">": [var, number] })
+            }
+            FirstOrder::Contains(contains) => match &*bound!(ctx, &contains.value) {
+                Value::String(_) if contains.ignore_case => {
+                    return Err("JsonLogic can't compare strings case-insensitively");
+                }
+                Value::String(value) => json!({ "in": [value, var] }),
+                // A value which isn't a string is never contained.
+                _ => Value::Bool(false),
+            },
+            FirstOrder::Intersects(intersects) => {
+                let mut rules = Vec::with_capacity(intersects.value.len());
+                for value in &intersects.value {
+                    if !value.is_null() {
+                        literal(value, intersects.ignore_case)?;
+                    }
+                    rules.push(json!({ "in": [value, var] }));
+                }
+                match rules.is_empty() {
+                    true => Value::Bool(false),
+                    false => combine(rules, "or"),
+                }
+            }
+            FirstOrder::Type(_) => return Err("JsonLogic can't check the type of a value"),
+            FirstOrder::Start(_)
+            | FirstOrder::End(_)
+            | FirstOrder::Matches(_)
+            | FirstOrder::Contained(_) => return Err("the operation isn't supported"),
+        };
+
+        // The predicate can't be evaluated when the element doesn't exist,
+        // which JsonLogic reads as `null`.
+        let defined = null_check(&var, true);
+        if !negated {
+            // `null` is ordered like 0 by JsonLogic.
+            return Ok(match first_order {
+                FirstOrder::Less(_) | FirstOrder::More(_) => combine(vec![defined, check], "and"),
+                _ => check,
+            });
+        }
+        Ok(match negate(check) {
+            Value::Bool(true) => defined,
+            negation => combine(vec![defined, negation], "and"),
+        })
+    }
+
+    fn unsupported(op: &str, location: JSONPath, reason: &'static str) -> JsonLogicError {
+        JsonLogicError::Unsupported {
+            op: op.to_string(),
+            location,
+            reason,
+        }
+    }
+}
+
+/// The dotted path of `path` for a `var` operation.
+fn var_name(path: &JSONPath) -> Result<String, &'static str> {
+    let tokens = path.tokens();
+    if tokens
+        .iter()
+        .any(|token| token.is_empty() || token.contains('.'))
+    {
+        return Err("a key which is empty or has a dot can't be referenced");
+    }
+    Ok(tokens.join("."))
+}
+
+/// Check that `value` can be compared with an element the way the predicate
+/// does.
+fn literal(value: &Value, ignore_case: bool) -> Result<(), &'static str> {
+    match value {
+        Value::Null => Err("JsonLogic reads a missing value as null"),
+        Value::Array(_) | Value::Object(_) => {
+            Err("JsonLogic compares arrays and objects by reference")
+        }
+        Value::String(_) if ignore_case => {
+            Err("JsonLogic can't compare strings case-insensitively")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The rule truthy when `var` isn't `null`, or when it is.
+fn null_check(var: &Value, not_null: bool) -> Value {
+    match not_null {
+        true => json!({ "!==": [var, null] }),
+        false => json!({ "===": [var, null] }),
+    }
+}
+
+/// The negation of `rule`.
+fn negate(rule: Value) -> Value {
+    match rule {
+        Value::Bool(value) => Value::Bool(!value),
+        Value::Object(mut rule) if rule.len() == 1 && rule.contains_key("===") => {
+            json!({ "!==": rule.remove("===") })
+        }
+        rule => json!({ "!": [rule] }),
+    }
+}
+
+/// Combine the rules with `op`, `and` or `or`, flattening the rules combined
+/// with the same operation and dropping the duplicates.
+fn combine(rules: Vec<Value>, op: &str) -> Value {
+    let mut unique: Vec<Value> = Vec::with_capacity(rules.len());
+    for rule in rules {
+        let rules = match rule {
+            Value::Object(mut rule)
+                if rule.len() == 1 && rule.get(op).is_some_and(Value::is_array) =>
+            {
+                match rule.remove(op) {
+                    Some(Value::Array(rules)) => rules,
+                    _ => unreachable!(),
+                }
+            }
+            rule => vec![rule],
+        };
+        for rule in rules {
+            if !unique.contains(&rule) {
+                unique.push(rule);
+            }
+        }
+    }
+
+    match unique.len() {
+        1 => unique.remove(0),
+        _ => json!({ op: unique }),
+    }
+}
+
+/// A side of a comparison in a rule.
+enum Side<'a> {
+    Var(JSONPath),
+    Literal(&'a Value),
+}
+
+/// The predicate holding for the documents `rule` is truthy for, at
+/// `location` in the rule. Under a negation, i.e. when `negated`, a predicate
+/// which can't be evaluated when the value is missing is guarded with
+/// "defined", as JsonLogic reads it as `null`.
+fn from_rule(
+    rule: &Value,
+    negated: bool,
+    location: &mut Vec<String>,
+) -> Result<Predicate, JsonLogicError> {
+    let (op, args) = match rule {
+        Value::Bool(value) => return Ok(constant(*value)),
+        Value::Object(rule) if rule.len() == 1 => rule.iter().next().expect("one operation"),
+        _ => {
+            return Err(invalid(
+                location,
+                "a rule must be a boolean or an object with a single operation",
+            ))
+        }
+    };
+
+    location.push(op.clone());
+    let predicate = match op.as_str() {
+        "and" | "or" | "!" => from_logic(op, args, negated, location),
+        _ => from_comparison(op, args, negated).map_err(|reason| invalid(location, reason)),
+    };
+    location.pop();
+    predicate
+}
+
+/// The predicate of an `and`, `or` or `!` operation, see [`from_rule`].
+fn from_logic(
+    op: &str,
+    args: &Value,
+    negated: bool,
+    location: &mut Vec<String>,
+) -> Result<Predicate, JsonLogicError> {
+    let rules = operands(args);
+    if op == "!" && rules.len() != 1 {
+        return Err(invalid(location, "`!` takes one operand"));
+    }
+
+    let negated = negated || op == "!";
+    let mut apply = Vec::with_capacity(rules.len());
+    for (index, rule) in rules.iter().enumerate() {
+        if args.is_array() {
+            location.push(index.to_string());
+        }
+        let predicate = from_rule(rule, negated, location);
+        if args.is_array() {
+            location.pop();
+        }
+        apply.push(predicate?);
+    }
+
+    Ok(match op {
+        "!" => SecondOrder::Not(Not { path: None, apply }).into(),
+        // An empty operation evaluates as `undefined`, which is falsy.
+        _ if apply.is_empty() => constant(false),
+        "and" => all(apply),
+        _ => any(apply),
+    })
+}
+
+/// The predicate of a comparison, see [`from_rule`].
+fn from_comparison(op: &str, args: &Value, negated: bool) -> Result<Predicate, String> {
+    match op {
+        "==" | "===" | "!=" | "!==" | "in" | "<" | ">" => {}
+        "var" => return Err("a `var` must be compared with a literal value".to_string()),
+        _ => return Err(format!("the `{op}` operation isn't supported")),
+    }
+
+    let sides = operands(args)
+        .iter()
+        .map(side)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match (op, sides.as_slice()) {
+        ("==" | "===", [Side::Var(path), Side::Literal(value)])
+        | ("==" | "===", [Side::Literal(value), Side::Var(path)]) => {
+            from_equals(path, value, negated)
+        }
+        ("!=" | "!==", [Side::Var(path), Side::Literal(value)])
+        | ("!=" | "!==", [Side::Literal(value), Side::Var(path)]) => {
+            let apply = vec![from_equals(path, value, true)?];
+            Ok(SecondOrder::Not(Not { path: None, apply }).into())
+        }
+        ("<", [Side::Var(path), Side::Literal(value)])
+        | (">", [Side::Literal(value), Side::Var(path)]) => Ok(guarded(
+            path,
+            from_order(path, value, true)?.into(),
+            negated,
+        )),
+        (">", [Side::Var(path), Side::Literal(value)])
+        | ("<", [Side::Literal(value), Side::Var(path)]) => Ok(guarded(
+            path,
+            from_order(path, value, false)?.into(),
+            negated,
+        )),
+        ("<", [Side::Literal(low), Side::Var(path), Side::Literal(high)]) => {
+            let apply = vec![
+                from_order(path, low, false)?.into(),
+                from_order(path, high, true)?.into(),
+            ];
+            let between = SecondOrder::And(And { path: None, apply }).into();
+            Ok(guarded(path, between, negated))
+        }
+        ("in", [Side::Var(path), Side::Literal(Value::Array(values))]) => {
+            if values
+                .iter()
+                .any(|value| value.is_array() || value.is_object())
+            {
+                return Err("JsonLogic compares arrays and objects by reference".to_string());
+            }
+            let r#in = FirstOrder::In(In {
+                path: Some(path.clone()),
+                quantifier: None,
+                ignore_case: false,
+                value: Operand::Value(values.clone()),
+            });
+            // A missing value is `null`.
+            Ok(match values.iter().any(Value::is_null) {
+                true => or_undefined(path, r#in.into()),
+                false => guarded(path, r#in.into(), negated),
+            })
+        }
+        ("in", [Side::Literal(needle), Side::Var(path)]) => {
+            let intersects: Predicate = FirstOrder::Intersects(Intersects {
+                path: Some(path.clone()),
+                quantifier: None,
+                ignore_case: false,
+                value: vec![(*needle).clone()],
+            })
+            .into();
+            // A string is looked for in a string as well as in an array.
+            let predicate = match needle {
+                Value::Array(_) | Value::Object(_) => {
+                    return Err("JsonLogic compares arrays and objects by reference".to_string());
+                }
+                Value::String(_) => {
+                    let contains = FirstOrder::Contains(Contains {
+                        path: Some(path.clone()),
+                        quantifier: None,
+                        ignore_case: false,
+                        value: Operand::Value((*needle).clone()),
+                    });
+                    any(vec![contains.into(), intersects])
+                }
+                _ => intersects,
+            };
+            Ok(guarded(path, predicate, negated))
+        }
+        _ => Err(format!("`{op}` must compare a `var` with a literal value")),
+    }
+}
+
+/// The operands of an operation, which can be given without an array when
+/// there is only one.
+fn operands(args: &Value) -> &[Value] {
+    match args {
+        Value::Array(args) => args,
+        arg => std::slice::from_ref(arg),
+    }
+}
+
+/// A side of a comparison, a `var` or a literal value.
+fn side(value: &Value) -> Result<Side<'_>, String> {
+    match value {
+        Value::Object(rule) => match rule.get("var") {
+            Some(name) if rule.len() == 1 => var_path(name).map(Side::Var),
+            _ => Err("only a `var` can be compared with a literal value".to_string()),
+        },
+        value => Ok(Side::Literal(value)),
+    }
+}
+
+/// The path referenced by a `var` operation: a dotted path or an array index,
+/// an empty one referencing the whole document.
+fn var_path(name: &Value) -> Result<JSONPath, String> {
+    let name = match name {
+        Value::Array(args) if args.len() > 1 => {
+            return Err("a default value of `var` isn't supported".to_string());
+        }
+        Value::Array(args) => args.first().unwrap_or(&Value::Null),
+        name => name,
+    };
+    let name = match name {
+        Value::String(name) => name.clone(),
+        Value::Number(index) => index.to_string(),
+        Value::Null => String::new(),
+        _ => return Err("`var` must reference a dotted path or an array index".to_string()),
+    };
+
+    Ok(match name.is_empty() {
+        true => JSONPath::from_tokens(Vec::new()),
+        false => JSONPath::from_tokens(name.split('.').map(str::to_string).collect()),
+    })
+}
+
+/// The predicate holding when the value is equal to `value`, a `null` one
+/// also matching a missing value.
+fn from_equals(path: &JSONPath, value: &Value, negated: bool) -> Result<Predicate, String> {
+    let test = FirstOrder::Test(Test {
+        path: Some(path.clone()),
+        quantifier: None,
+        ignore_case: false,
+        value: Operand::Value(value.clone()),
+    });
+
+    match value {
+        Value::Array(_) | Value::Object(_) => {
+            Err("JsonLogic compares arrays and objects by reference".to_string())
+        }
+        Value::Null => Ok(or_undefined(path, test.into())),
+        _ => Ok(guarded(path, test.into(), negated)),
+    }
+}
+
+/// The "less" predicate, or the "more" one, comparing the value with
+/// `value`.
+fn from_order(path: &JSONPath, value: &Value, less: bool) -> Result<FirstOrder, String> {
+    let Value::Number(number) = value else {
+        return Err("only numbers can be compared".to_string());
+    };
+
+    let path = Some(path.clone());
+    let value = Operand::Value(Comparable::Number(number.clone()));
+    Ok(match less {
+        true => FirstOrder::Less(Less {
+            path,
+            quantifier: None,
+            value,
+        }),
+        false => FirstOrder::More(More {
+            path,
+            quantifier: None,
+            value,
+        }),
+    })
+}
+
+fn invalid(location: &[String], reason: impl Into<String>) -> JsonLogicError {
+    JsonLogicError::Rule {
+        location: JSONPath::from_tokens(location.to_vec()),
+        reason: reason.into(),
+    }
+}
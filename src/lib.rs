@@ -48,6 +48,10 @@
 //! ## Rust Example
 //!
 //! ```rust
+//! # use json_predicate::context::PredicateContext;
+//! # use json_predicate::{Predicate, PredicateImpl};
+//! # use serde::Deserialize;
+//! # let ENTRY = serde_json::json!({"objA": {"stringX": "X", "stringXYZ": "XYZ"}});
 //! let predicate = Predicate::deserialize(serde_json::json!({
 //!     "op": "and",
 //!     "path": "/objA",
@@ -65,12 +69,368 @@
 //!
 //! let evaluted_predicate: bool = predicate
 //!     .test(&ENTRY, PredicateContext::default());
+//! # assert!(evaluted_predicate);
+//! # Ok::<(), serde_json::Error>(())
 //! ```
 //!
 //! ## JSON Patch
 //!
 //! The JSON Patch methods described in [draft-snell-json-test-07](https://datatracker.ietf.org/doc/html/draft-snell-json-test-07)
-//! are not implemented yet.
+//! are implemented in the [`patch`] module: predicates can be used as JSON Patch
+//! operations which abort the whole patch when they don't evaluate as `true`.
+//!
+//! ```json
+//! [
+//!     { "op": "contains", "path": "/a/b/c", "value": "ABC" },
+//!     { "op": "replace", "path": "/a/b/c", "value": "DEF" }
+//! ]
+//! ```
+//!
+//! ## Explain
+//!
+//! [`Predicate::explain`] evaluates a predicate like [`PredicateImpl::evaluate`]
+//! but returns an [`Explanation`]: a tree mirroring the predicate which records,
+//! for every node, the operator, the resolved absolute pointer, the value found
+//! at that pointer and the outcome. Its `Display` implementation renders
+//! something like:
+//!
+//! ```text
+//! and /a/b/c: false
+//!   type /a/b/c = "ABC!XYZ": true
+//!   contains /a/b/c = "ABC!XYZ": false
+//! ```
+//!
+//! ## Compiled predicates
+//!
+//! When the same predicate is evaluated against a lot of documents, it can be
+//! compiled once into a [`CompiledPredicate`]: pointers are resolved, the
+//! needles of the case-insensitive operations are lowercased and regexes are
+//! built ahead of time, and its evaluation doesn't allocate.
+//! [`CompiledPredicate::matching`] and [`CompiledPredicate::filter`] evaluate it
+//! over many documents, and a [`PredicateSet`] evaluates named predicates
+//! against one document, e.g. to route it.
+//!
+//! ## Streaming NDJSON
+//!
+//! [`ndjson::NdjsonFilter`] reads newline-delimited JSON records from any
+//! reader and writes the ones a predicate holds for to any writer. Memory stays
+//! bounded as lines are processed by batches, which can be evaluated on several
+//! threads while keeping the order of the records:
+//!
+//! ```rust
+//! # use json_predicate::Predicate;
+//! use json_predicate::ndjson::{NdjsonFilter, OnInvalid};
+//!
+//! let predicate: Predicate = "/status > 499".parse()?;
+//! let input = "{\"status\": 200}\n{\"status\": 503}\nnot json\n";
+//!
+//! let mut output = Vec::new();
+//! let summary = NdjsonFilter::new(&predicate)
+//!     .with_threads(4)
+//!     .with_on_invalid(OnInvalid::Skip)
+//!     .run(input.as_bytes(), &mut output)?;
+//! assert_eq!(output, b"{\"status\": 503}\n");
+//! assert_eq!((summary.matched, summary.skipped), (1, 1));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Errors carry the number of the line which caused them, and the returned
+//! [`ndjson::Summary`] counts the records read, matched, skipped as invalid or
+//! which couldn't be evaluated.
+//!
+//! ## SQL
+//!
+//! [`Predicate::to_sql`] translates a predicate into a parameterized boolean
+//! expression, to filter documents stored in a JSON column of a PostgreSQL
+//! (`jsonb`) or SQLite table in a `WHERE` clause:
+//!
+//! ```rust
+//! # use json_predicate::context::PredicateContext;
+//! # use json_predicate::Predicate;
+//! use json_predicate::sql::{Dialect, SqlParam};
+//!
+//! let predicate: Predicate = "/status > 499".parse()?;
+//! let sql = predicate.to_sql(Dialect::Sqlite, "doc", PredicateContext::default())?;
+//! assert_eq!(
+//!     sql.sql,
+//!     "CASE WHEN json_type(doc, ?1) IS NOT NULL \
+//!      THEN (json_type(doc, ?1) IN ('integer', 'real') AND json_extract(doc, ?1) > ?2) END"
+//! );
+//! assert_eq!(sql.params, [SqlParam::Text("$.\"status\"".into()), SqlParam::Integer(499)]);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! The expression holds for the documents the predicate holds for, a
+//! predicate which can't be evaluated being `NULL`. What can't be expressed
+//! in SQL, like a quantifier or a regex, is reported as a
+//! [`sql::SqlError`], and the [`sql`] module lists the few remaining
+//! differences.
+//!
+//! ## MongoDB filters
+//!
+//! [`Predicate::to_mongo`] converts a predicate into a MongoDB query filter,
+//! JSON Pointers becoming dotted field paths, and [`Predicate::from_mongo`]
+//! converts a filter using the `$eq`, `$in`, `$lt`, `$gt`, `$regex`,
+//! `$exists`, `$type`, `$and`, `$or` and `$nor` operators into a predicate,
+//! to evaluate it in process:
+//!
+//! ```rust
+//! # use json_predicate::context::PredicateContext;
+//! # use json_predicate::{Predicate, PredicateImpl};
+//! # use serde_json::json;
+//! let predicate: Predicate = r#"/user/role in ["admin", "owner"]"#.parse()?;
+//! let filter = predicate.to_mongo(PredicateContext::default())?;
+//! assert_eq!(filter, json!({"user.role": {"$in": ["admin", "owner"]}}));
+//!
+//! let predicate = Predicate::from_mongo(&json!({"$nor": [{"age": {"$lt": 18}}]}))?;
+//! assert!(predicate.test(&json!({"age": 30}), PredicateContext::default()));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! What can't be expressed on the other side, like a quantifier or a `$ne`
+//! operator, is reported as a [`mongo::MongoError`], and the [`mongo`] module
+//! lists the few remaining differences.
+//!
+//! ## JsonLogic
+//!
+//! [`Predicate::to_jsonlogic`] converts a predicate into a
+//! [JsonLogic](https://jsonlogic.com) rule, JSON Pointers becoming the dotted
+//! paths of `var` operations, and [`Predicate::from_jsonlogic`] converts the
+//! boolean subset of JsonLogic, i.e. the `==`, `in`, `<`, `>`, `and`, `or`, `!`
+//! and `var` operations, into a predicate, so a rule written for a frontend can
+//! be stored and evaluated as is:
+//!
+//! ```rust
+//! # use json_predicate::context::PredicateContext;
+//! # use json_predicate::{Predicate, PredicateImpl};
+//! # use serde_json::json;
+//! let predicate: Predicate = r#"/user/role in ["admin", "owner"]"#.parse()?;
+//! let rule = predicate.to_jsonlogic(PredicateContext::default())?;
+//! assert_eq!(rule, json!({"in": [{"var": "user.role"}, ["admin", "owner"]]}));
+//!
+//! let predicate = Predicate::from_jsonlogic(&json!({"!": {"<": [{"var": "age"}, 18]}}))?;
+//! assert!(predicate.test(&json!({"age": 30}), PredicateContext::default()));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! What can't be expressed on the other side, like a regex or a `var` with a
+//! default value, is reported as a [`jsonlogic::JsonLogicError`], and the
+//! [`jsonlogic`] module lists the few remaining differences.
+//!
+//! ## Elasticsearch
+//!
+//! [`Predicate::to_elasticsearch`] converts a predicate into an Elasticsearch
+//! or OpenSearch `bool` query, JSON Pointers becoming dotted field names, so a
+//! saved search written once as a predicate can be both evaluated in process
+//! and sent to the search backend:
+//!
+//! ```rust
+//! # use json_predicate::context::PredicateContext;
+//! # use json_predicate::Predicate;
+//! # use serde_json::json;
+//! let predicate: Predicate =
+//!     r#"/user/role in ["admin", "owner"] and not(/user/name starts-with "test")"#.parse()?;
+//! let query = predicate.to_elasticsearch(PredicateContext::default())?;
+//! assert_eq!(
+//!     query,
+//!     json!({"bool": {"must": [
+//!         {"terms": {"user.role": ["admin", "owner"]}},
+//!         {"bool": {
+//!             "must": [{"exists": {"field": "user.name"}}],
+//!             "must_not": [{"prefix": {"user.name": {"value": "test"}}}],
+//!         }},
+//!     ]}})
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! What can't be expressed as a query, like a quantifier or a "type"
+//! predicate, is reported as an [`elasticsearch::ElasticsearchError`], and the
+//! [`elasticsearch`] module lists the mapping the query expects and the few
+//! remaining differences.
+//!
+//! ## Simplification
+//!
+//! [`Predicate::simplify`] returns an equivalent, usually smaller, predicate:
+//! nested "and" and "or" predicates are flattened, duplicates removed, shared
+//! path prefixes hoisted, "not" predicates pushed inward with De Morgan's laws
+//! and "test" predicates on the same path inside an "or" collapsed into an
+//! "in" predicate:
+//!
+//! ```rust
+//! use json_predicate::Predicate;
+//!
+//! let predicate: Predicate = r#"not(not(/a/b == 1 or /a/b == 2) and /a/c == 3)"#
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     predicate.simplify().to_string(),
+//!     r#"/a/b in [1,2] or not /a/c == 3"#
+//! );
+//! ```
+//!
+//! ## Static analysis
+//!
+//! [`Predicate::analyze`] reports, without any document, the subordinate
+//! predicates which can never be true, e.g. a "less" and a "more" predicate
+//! on the same path which can't both hold inside an "and", and the ones which
+//! are always true:
+//!
+//! ```rust
+//! use json_predicate::Predicate;
+//!
+//! let predicate: Predicate = r#"/b == 1 or (/a < 5 and /a > 10)"#.parse().unwrap();
+//! let findings = predicate.analyze();
+//!
+//! assert_eq!(
+//!     findings[0].to_string(),
+//!     "contradiction at /apply/1: `less` at /apply/1/apply/0 and `more` at /apply/1/apply/1 can't both hold for /a"
+//! );
+//! ```
+//!
+//! ## Example documents
+//!
+//! [`Predicate::example`] and [`Predicate::counterexample`] generate a minimal
+//! document for which the predicate evaluates as "true", or "false": pointers
+//! become nested objects and arrays, and values are derived from the
+//! operations, e.g. a string with the prefix of a "starts" predicate:
+//!
+//! ```rust
+//! use json_predicate::Predicate;
+//! use serde_json::json;
+//!
+//! let predicate: Predicate = r#"/user: and(/name starts-with "Jo", /age > 17)"#
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     predicate.example(),
+//!     Some(json!({"user": {"name": "Jo", "age": 18}}))
+//! );
+//! ```
+//!
+//! ## Wildcards and quantifiers
+//!
+//! A first order predicate can be given a "quantifier" member, in which case
+//! `*` in its "path" member matches every element of an array or member of an
+//! object and `**` any number of levels. The predicate is then evaluated
+//! against every matched value and the [`Quantifier`], `"any"`, `"all"`,
+//! `"none"` or a count range like `{"min": 1, "max": 3}`, tells how many of
+//! them must satisfy it:
+//!
+//! ```json
+//! {
+//!   "op": "test",
+//!   "path": "/items/*/status",
+//!   "value": "shipped",
+//!   "quantifier": "any"
+//! }
+//! ```
+//!
+//! ## Comparing with another location
+//!
+//! The "value" member of the "test", "less", "more", "in", "contains", "start"
+//! and "end" predicates can be an [`Operand`] referencing another location of
+//! the evaluated document, resolved from the same context as the "path"
+//! member, instead of a literal value:
+//!
+//! ```json
+//! {
+//!   "op": "more",
+//!   "path": "/endDate",
+//!   "value": { "$path": "/startDate" }
+//! }
+//! ```
+//!
+//! ## Variables
+//!
+//! Values can also be bound to named variables in the
+//! [`PredicateContext`](context::PredicateContext) at evaluation time, and
+//! referenced as `{"$var": "name"}`, so that one stored predicate serves many
+//! requests:
+//!
+//! ```rust
+//! use json_predicate::context::PredicateContext;
+//! use json_predicate::{Predicate, PredicateImpl};
+//! use serde::Deserialize;
+//!
+//! let predicate = Predicate::deserialize(serde_json::json!({
+//!     "op": "test",
+//!     "path": "/owner",
+//!     "value": { "$var": "userId" }
+//! }))?;
+//!
+//! let ctx = PredicateContext::default().with_variable("userId", "alice");
+//! assert!(predicate.test(&serde_json::json!({ "owner": "alice" }), ctx));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! A variable which isn't bound makes the evaluation fail with
+//! [`PredicateErrorKind::UndefinedVariable`].
+//!
+//! ## Custom operations
+//!
+//! Operations which aren't part of the specification can be registered in an
+//! [`OperatorRegistry`] under the name of their "op" member, with a type
+//! implementing [`PredicateImpl`] they are deserialized as. Predicates
+//! deserialized or parsed with the registry can then use them anywhere:
+//!
+//! ```json
+//! {
+//!   "op": "not",
+//!   "apply": [{ "op": "even", "path": "/a" }]
+//! }
+//! ```
+//!
+//! ## Text syntax
+//!
+//! Predicates can also be written in a compact text syntax, see the
+//! [`syntax`] module, which is parsed into a [`Predicate`] and rendered back by
+//! its `Display` implementation:
+//!
+//! ```rust
+//! use json_predicate::Predicate;
+//!
+//! let predicate: Predicate = r#"/a/b/c starts-with "ABC" and type(/x) == "string""#
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     predicate.to_string(),
+//!     r#"/a/b/c starts-with "ABC" and type(/x) == "string""#
+//! );
+//! ```
+//!
+//! ## Command-line tool
+//!
+//! With the `cli` feature, the crate ships a `json-predicate` binary evaluating a
+//! predicate file against JSON documents, or NDJSON records read from the
+//! standard input:
+//!
+//! ```text
+//! $ cargo install json-predicate --features cli
+//! $ cat records.ndjson | json-predicate --print predicate.json
+//! ```
+//!
+//! It exits with `0` when a record matches the predicate (every record with
+//! `--all`), `1` when none does and `2` on errors. `--explain` prints why each
+//! record matched or not and `--var NAME=VALUE` binds a variable.
+//!
+//! ## Property-based testing
+//!
+//! With the `proptest` feature, [`Predicate`], [`FirstOrder`], [`SecondOrder`]
+//! and [`JSONPath`](json_path::JSONPath) implement proptest's `Arbitrary`, and
+//! `arbitrary::document()` generates documents to evaluate them against:
+//!
+//! ```text
+//! proptest! {
+//!     #[test]
+//!     fn never_panics(predicate in any::<Predicate>(), data in document()) {
+//!         predicate.evaluate(&data, PredicateContext::default());
+//!     }
+//! }
+//! ```
 //!
 //! ## Features
 //!
@@ -93,6 +453,10 @@
 //! - [x] "test-"
 //! - [x] "less"
 //! - [x] "more"
+//! - [x] "contained"
+//! - [x] "contained-"
+//! - [x] "intersects"
+//! - [x] "intersects-"
 //!
 //! ### Second order predicate
 //!
@@ -110,20 +474,46 @@
 //!
 //! - Apache License, Version 2.0, (LICENSE-APACHE or [LICENSE-2.0](http://www.apache.org/licenses/LICENSE-2.0))
 //! - MIT license (LICENSE-MIT or [MIT](http://opensource.org/licenses/MIT)) at your option.
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+mod comparable;
+pub mod elasticsearch;
 pub mod json_path;
+pub mod jsonlogic;
+pub mod mongo;
+pub mod ndjson;
+pub mod patch;
+pub mod sql;
 
 mod predicate;
 mod regex;
 
+pub use comparable::{Comparable, Temporal, TemporalError};
+pub use predicate::analysis::{Finding, FindingKind};
+pub use predicate::compiled::CompiledPredicate;
 pub use predicate::context;
+pub use predicate::error::{
+    DeserializeError, DeserializeErrorKind, PredicateError, PredicateErrorKind,
+};
+pub use predicate::explain::Explanation;
 pub use predicate::first_order::FirstOrder;
+pub use predicate::operand::Operand;
+pub use predicate::outcome::Outcome;
+pub use predicate::quantifier::Quantifier;
+pub use predicate::registry::{Extension, OperatorRegistry};
 pub use predicate::second_order::SecondOrder;
+pub use predicate::set::PredicateSet;
+pub use predicate::syntax::{self, SyntaxError};
 pub use predicate::{Predicate, PredicateImpl};
 
 pub mod builder {
+    pub use crate::predicate::first_order::contained::{ContainedBuilder, ContainedBuilderError};
     pub use crate::predicate::first_order::contains::{ContainsBuilder, ContainsBuilderError};
     pub use crate::predicate::first_order::defined::{DefinedBuilder, DefinedBuilderError};
     pub use crate::predicate::first_order::end::{EndBuilder, EndBuilderError};
+    pub use crate::predicate::first_order::intersects::{
+        IntersectsBuilder, IntersectsBuilderError,
+    };
     pub use crate::predicate::first_order::less::{LessBuilder, LessBuilderError};
     pub use crate::predicate::first_order::matches::{MatchesBuilder, MatchesBuilderError};
     pub use crate::predicate::first_order::more::{MoreBuilder, MoreBuilderError};
@@ -0,0 +1,44 @@
+use crate::json_path::JSONPath;
+
+/// Error raised while converting a [`Predicate`](crate::Predicate) into a
+/// MongoDB filter, or a MongoDB filter into a predicate.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MongoError {
+    /// The predicate at `location` in the predicate document, e.g.
+    /// `/apply/1`, has no MongoDB equivalent.
+    #[error(
+        "`{op}`{} can't be converted into a MongoDB filter: {reason}",
+        at(location)
+    )]
+    Unsupported {
+        op: String,
+        location: JSONPath,
+        reason: &'static str,
+    },
+    /// The member at `location` in the filter, e.g. `/$and/0/age/$lt`, isn't
+    /// valid or has no predicate equivalent.
+    #[error(
+        "The MongoDB filter{} can't be converted into a predicate: {reason}",
+        at(location)
+    )]
+    Filter { location: JSONPath, reason: String },
+}
+
+impl MongoError {
+    /// The location of the predicate or of the filter member which couldn't
+    /// be converted.
+    pub fn location(&self) -> &JSONPath {
+        match self {
+            Self::Unsupported { location, .. } | Self::Filter { location, .. } => location,
+        }
+    }
+}
+
+/// The location of a predicate or a filter member, omitted for the root one.
+fn at(location: &JSONPath) -> String {
+    if location.tokens().is_empty() {
+        String::new()
+    } else {
+        format!(" at {location}")
+    }
+}
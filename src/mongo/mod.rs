@@ -0,0 +1,579 @@
+//! Conversion between predicates and [MongoDB query
+//! filters](https://www.mongodb.com/docs/manual/tutorial/query-documents/),
+//! JSON Pointers being written as dotted field paths.
+//!
+//! ```rust
+//! use json_predicate::context::PredicateContext;
+//! use json_predicate::{Predicate, PredicateImpl};
+//! use serde_json::json;
+//!
+//! let predicate: Predicate = r#"/user/age > 17 and /user/role in ["admin", "owner"]"#.parse()?;
+//! assert_eq!(
+//!     predicate.to_mongo(PredicateContext::default())?,
+//!     json!({"$and": [
+//!         {"user.age": {"$gt": 17}},
+//!         {"user.role": {"$in": ["admin", "owner"]}},
+//!     ]})
+//! );
+//!
+//! let filter = json!({"user.age": {"$gt": 17}, "deletedAt": {"$exists": false}});
+//! let predicate = Predicate::from_mongo(&filter)?;
+//! assert_eq!(predicate.to_string(), "undefined(/deletedAt) and /user/age > 17");
+//! assert!(predicate.test(&json!({"user": {"age": 30}}), PredicateContext::default()));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! A filter matches exactly the documents a predicate holds for: a negated
+//! predicate, which can't be evaluated without its field, also requires the
+//! field to exist, and the predicates converted from a `$nor` are guarded
+//! with "defined".
+//!
+//! [`Predicate::to_mongo`] converts the "test", "in", "less", "more",
+//! "starts", "ends", "contains" and "matches" predicates, their
+//! case-insensitive variants, "defined", "undefined", "type" and the Second
+//! Order predicates, variables being resolved from the [`PredicateContext`].
+//! A quantifier, a "value" member referencing another location of the
+//! document, a date or time, the "contained" and "intersects" predicates, the
+//! "date", "time", "date-time", "lang", "lang-range", "iri" and
+//! "absolute-iri" types, the operations registered by the application and a
+//! path which can't be written as a dotted field path are reported as
+//! [`MongoError::Unsupported`].
+//!
+//! [`Predicate::from_mongo`] converts the `$eq`, `$in`, `$lt`, `$gt`,
+//! `$regex`, `$options`, `$exists` and `$type` operators, fields compared
+//! with a value and the `$and`, `$or` and `$nor` operators. Any other
+//! operator, a comparison with something else than a number, a BSON type
+//! with no JSON equivalent and a regex the [`regex`](https://docs.rs/regex)
+//! crate can't compile are reported as [`MongoError::Filter`].
+//!
+//! A few differences remain:
+//!
+//! - MongoDB matches a field holding an array when one of its items
+//!   matches, and looks into the documents of an array for a dotted field
+//!   path, whereas a predicate only checks the referenced element;
+//! - MongoDB compares numbers by value, so `1` and `1.0` are equal;
+//! - case-insensitive comparisons become regexes, which don't fold the case
+//!   exactly like [`str::to_lowercase`], and MongoDB regexes follow the PCRE
+//!   syntax.
+//!
+//! [`PredicateContext`]: crate::context::PredicateContext
+use regex::Regex as LibRegex;
+use serde_json::{json, Map, Value};
+
+use crate::comparable::Comparable;
+use crate::json_path::JSONPath;
+use crate::predicate::context::PredicateContext;
+use crate::predicate::convert::{self, all, any, bound, guarded, or_undefined, Target};
+use crate::predicate::first_order::{
+    defined::Defined, less::Less, matches::Matches, more::More, r#in::In, r#type::Type, test::Test,
+    undefined::Undefined, FirstOrder,
+};
+use crate::predicate::operand::Operand;
+use crate::predicate::second_order::{and::And, not::Not, or::Or, SecondOrder};
+use crate::predicate::Predicate;
+
+mod error;
+
+pub use error::MongoError;
+
+impl Predicate {
+    /// Convert the predicate into a MongoDB filter matching the documents it
+    /// holds for, paths being resolved from `ctx`.
+    ///
+    /// See the [`mongo`](crate::mongo) module for what can be converted.
+    pub fn to_mongo(&self, ctx: PredicateContext) -> Result<Value, MongoError> {
+        convert::to_query::<Mongo>(self, &ctx, false, &mut Vec::new())
+    }
+
+    /// Convert a MongoDB filter into a predicate holding for the documents it
+    /// matches.
+    ///
+    /// See the [`mongo`](crate::mongo) module for what can be converted.
+    pub fn from_mongo(filter: &Value) -> Result<Predicate, MongoError> {
+        from_filter(filter, false, &mut Vec::new())
+    }
+}
+
+/// The MongoDB filters predicates are converted into.
+struct Mongo;
+
+impl Target for Mongo {
+    type Query = Value;
+    type Error = MongoError;
+
+    fn constant(value: bool) -> Value {
+        constant(value)
+    }
+
+    fn combine(filters: Vec<Value>, all: bool) -> Value {
+        combine(filters, if all { "$and" } else { "$or" })
+    }
+
+    fn first_order(
+        first_order: &FirstOrder,
+        path: &JSONPath,
+        ctx: &PredicateContext,
+        negated: bool,
+    ) -> Result<Value, &'static str> {
+        let field = field(path)?;
+
+        let check = match first_order {
+            FirstOrder::Defined(_) => return Ok(exists(&field, !negated)),
+            FirstOrder::Undefined(_) => return Ok(exists(&field, negated)),
+            FirstOrder::Type(ty) => match ty.value.as_str() {
+                "number" => operator(&field, "$type", "number".into()),
+                "string" => operator(&field, "$type", "string".into()),
+                "boolean" => operator(&field, "$type", "bool".into()),
+                "object" => operator(&field, "$type", "object".into()),
+                "array" => operator(&field, "$type", "array".into()),
+                "null" => operator(&field, "$type", "null".into()),
+                "date" | "time" | "date-time" | "lang" | "lang-range" | "iri" | "absolute-iri" => {
+                    return Err("the type can't be checked by MongoDB");
+                }
+                // A missing element is "undefined", and can be compared with
+                // the type like any other.
+                "undefined" => {
+                    let filter = json!({"$or": [
+                        exists(&field, false),
+                        equals(&field, &"undefined".into(), false)?,
+                    ]});
+                    return Ok(match negated {
+                        false => filter,
+                        true => json!({ "$nor": [filter] }),
+                    });
+                }
+                other => equals(&field, &other.into(), false)?,
+            },
+            FirstOrder::Test(test) => {
+                let value = bound!(ctx, &test.value);
+                equals(&field, &value, test.ignore_case)?
+            }
+            FirstOrder::In(r#in) => {
+                let values = bound!(ctx, &r#in.value);
+                one_of(&field, &values, r#in.ignore_case)?
+            }
+            FirstOrder::Less(less) => {
+                let Comparable::Number(number) = &*bound!(ctx, &less.value) else {
+                    return Err("dates and times are compared as strings by MongoDB");
+                };
+                operator(&field, "$lt", number.clone().into())
+            }
+            FirstOrder::More(more) => {
+                let Comparable::Number(number) = &*bound!(ctx, &more.value) else {
+                    return Err("dates and times are compared as strings by MongoDB");
+                };
+                operator(&field, "$gt", number.clone().into())
+            }
+            FirstOrder::Start(start) => {
+                let value = bound!(ctx, &start.value);
+                like(&field, &value, "^", "", start.ignore_case)
+            }
+            FirstOrder::End(end) => {
+                let value = bound!(ctx, &end.value);
+                like(&field, &value, "", "\\z", end.ignore_case)
+            }
+            FirstOrder::Contains(contains) => {
+                let value = bound!(ctx, &contains.value);
+                like(&field, &value, "", "", contains.ignore_case)
+            }
+            FirstOrder::Matches(matches) => {
+                let pattern = matches.value.as_ref().as_str();
+                regex(&field, pattern.to_string(), matches.ignore_case)
+            }
+            FirstOrder::Contained(_) | FirstOrder::Intersects(_) => {
+                return Err("the operation isn't supported");
+            }
+        };
+
+        Ok(match negated {
+            false => check,
+            // The predicate can't be evaluated when the element doesn't exist.
+            true => json!({ field: {"$exists": true}, "$nor": [check] }),
+        })
+    }
+
+    fn unsupported(op: &str, location: JSONPath, reason: &'static str) -> MongoError {
+        MongoError::Unsupported {
+            op: op.to_string(),
+            location,
+            reason,
+        }
+    }
+}
+
+/// The dotted field path of `path`.
+fn field(path: &JSONPath) -> Result<String, &'static str> {
+    let tokens = path.tokens();
+    if tokens.is_empty() {
+        return Err("a filter can't reference the whole document");
+    }
+    if tokens
+        .iter()
+        .any(|token| token.is_empty() || token.contains('.') || token.starts_with('$'))
+    {
+        return Err(
+            "a field whose name is empty, has a dot or starts with `$` can't be referenced",
+        );
+    }
+    Ok(tokens.join("."))
+}
+
+/// A filter matching every document when `value` is true, and none otherwise.
+fn constant(value: bool) -> Value {
+    match value {
+        true => json!({}),
+        false => json!({"$nor": [{}]}),
+    }
+}
+
+/// Combine the filters with `operator`, `$and` or `$or`.
+fn combine(mut filters: Vec<Value>, operator: &str) -> Value {
+    match filters.len() {
+        1 => filters.remove(0),
+        _ => json!({ operator: filters }),
+    }
+}
+
+fn operator(field: &str, operator: &str, value: Value) -> Value {
+    json!({ field: { operator: value } })
+}
+
+fn exists(field: &str, exists: bool) -> Value {
+    operator(field, "$exists", exists.into())
+}
+
+/// The filter matching the element when it's equal to `value`.
+fn equals(field: &str, value: &Value, ignore_case: bool) -> Result<Value, &'static str> {
+    match value {
+        Value::String(_) if ignore_case => Ok(like(field, value, "^", "\\z", true)),
+        Value::Array(_) | Value::Object(_) if ignore_case => {
+            Err("a case-insensitive comparison with an array or an object isn't supported")
+        }
+        // `null` also matches a missing field.
+        Value::Null => Ok(json!({ field: {"$exists": true, "$eq": null} })),
+        value => Ok(operator(field, "$eq", value.clone())),
+    }
+}
+
+/// The filter matching the element when it's equal to one of `values`.
+fn one_of(field: &str, values: &[Value], ignore_case: bool) -> Result<Value, &'static str> {
+    if ignore_case
+        && values
+            .iter()
+            .any(|value| value.is_string() || value.is_array() || value.is_object())
+    {
+        let filters = values
+            .iter()
+            .map(|value| equals(field, value, true))
+            .collect::<Result<_, _>>()?;
+        return Ok(combine(filters, "$or"));
+    }
+
+    // `null` also matches a missing field.
+    Ok(match values.iter().any(Value::is_null) {
+        true => json!({ field: {"$exists": true, "$in": values} }),
+        false => operator(field, "$in", values.into()),
+    })
+}
+
+/// The filter matching the element when it's a string matching the escaped
+/// `value` between `start` and `end`, a value which isn't a string never
+/// matching.
+fn like(field: &str, value: &Value, start: &str, end: &str, ignore_case: bool) -> Value {
+    match value {
+        Value::String(value) => {
+            let pattern = format!("{start}{}{end}", regex::escape(value));
+            regex(field, pattern, ignore_case)
+        }
+        _ => operator(field, "$in", json!([])),
+    }
+}
+
+fn regex(field: &str, pattern: String, ignore_case: bool) -> Value {
+    match ignore_case {
+        true => json!({ field: {"$regex": pattern, "$options": "i"} }),
+        false => operator(field, "$regex", pattern.into()),
+    }
+}
+
+/// The predicate holding for the documents `filter` matches, at `location` in
+/// the filter. Under a `$nor`, i.e. when `negated`, a predicate which can't
+/// be evaluated when the field is missing is guarded with "defined", as the
+/// filter doesn't match it.
+fn from_filter(
+    filter: &Value,
+    negated: bool,
+    location: &mut Vec<String>,
+) -> Result<Predicate, MongoError> {
+    let Value::Object(filter) = filter else {
+        return Err(invalid(location, "a filter must be an object"));
+    };
+
+    let mut apply = Vec::with_capacity(filter.len());
+    for (key, value) in filter {
+        location.push(key.clone());
+        let predicate = match key.as_str() {
+            "$and" | "$or" | "$nor" => from_combination(key, value, negated, location),
+            key if key.starts_with('$') => Err(invalid(location, "the operator isn't supported")),
+            key => from_field(key, value, negated, location),
+        };
+        location.pop();
+        apply.push(predicate?);
+    }
+
+    Ok(all(apply))
+}
+
+/// The predicate of a `$and`, `$or` or `$nor` operator, see [`from_filter`].
+fn from_combination(
+    operator: &str,
+    value: &Value,
+    negated: bool,
+    location: &mut Vec<String>,
+) -> Result<Predicate, MongoError> {
+    let filters = match value {
+        Value::Array(filters) if !filters.is_empty() => filters,
+        _ => {
+            let reason = format!("`{operator}` must be a nonempty array of filters");
+            return Err(invalid(location, reason));
+        }
+    };
+
+    let negated = negated || operator == "$nor";
+    let mut apply = Vec::with_capacity(filters.len());
+    for (index, filter) in filters.iter().enumerate() {
+        location.push(index.to_string());
+        let predicate = from_filter(filter, negated, location);
+        location.pop();
+        apply.push(predicate?);
+    }
+
+    Ok(match operator {
+        "$and" => SecondOrder::And(And { path: None, apply }),
+        "$or" => SecondOrder::Or(Or { path: None, apply }),
+        _ => SecondOrder::Not(Not { path: None, apply }),
+    }
+    .into())
+}
+
+/// The predicate holding when the field `key` satisfies `value`, either
+/// operators or a value it must be equal to, see [`from_filter`].
+fn from_field(
+    key: &str,
+    value: &Value,
+    negated: bool,
+    location: &mut Vec<String>,
+) -> Result<Predicate, MongoError> {
+    if key.split('.').any(str::is_empty) {
+        return Err(invalid(
+            location,
+            "a field path can't have an empty field name",
+        ));
+    }
+    let path = JSONPath::from_tokens(key.split('.').map(str::to_string).collect());
+
+    let operators = match value {
+        Value::Object(operators) if operators.keys().any(|key| key.starts_with('$')) => operators,
+        value => return Ok(from_equals(&path, value, negated)),
+    };
+    if operators.keys().any(|key| !key.starts_with('$')) {
+        return Err(invalid(location, "fields can't be mixed with operators"));
+    }
+
+    let mut apply = Vec::with_capacity(operators.len());
+    for (operator, operand) in operators {
+        location.push(operator.clone());
+        let predicate = from_operator(&path, operator, operand, operators, negated)
+            .map_err(|reason| invalid(location, reason));
+        location.pop();
+        apply.extend(predicate?);
+    }
+
+    Ok(all(apply))
+}
+
+/// The predicate of the `operator` of a field, `None` for `$options` which
+/// is handled with `$regex`.
+fn from_operator(
+    path: &JSONPath,
+    operator: &str,
+    operand: &Value,
+    operators: &Map<String, Value>,
+    negated: bool,
+) -> Result<Option<Predicate>, String> {
+    let guard = |first_order: FirstOrder| guarded(path, first_order.into(), negated);
+
+    let predicate = match operator {
+        "$eq" => from_equals(path, operand, negated),
+        "$in" => {
+            let Value::Array(values) = operand else {
+                return Err("`$in` must be an array".to_string());
+            };
+            let r#in = FirstOrder::In(In {
+                path: Some(path.clone()),
+                quantifier: None,
+                ignore_case: false,
+                value: Operand::Value(values.clone()),
+            });
+            // `null` also matches a missing field.
+            match values.iter().any(Value::is_null) {
+                true => or_undefined(path, r#in.into()),
+                false => guard(r#in),
+            }
+        }
+        "$lt" | "$gt" => {
+            let Value::Number(number) = operand else {
+                return Err("only numbers can be compared".to_string());
+            };
+            let value = Operand::Value(Comparable::Number(number.clone()));
+            let path = Some(path.clone());
+            guard(match operator {
+                "$lt" => FirstOrder::Less(Less {
+                    path,
+                    quantifier: None,
+                    value,
+                }),
+                _ => FirstOrder::More(More {
+                    path,
+                    quantifier: None,
+                    value,
+                }),
+            })
+        }
+        "$regex" => {
+            let Value::String(pattern) = operand else {
+                return Err("`$regex` must be a string".to_string());
+            };
+            let options = match operators.get("$options") {
+                None => "",
+                Some(Value::String(options)) => options,
+                Some(_) => return Err("`$options` must be a string".to_string()),
+            };
+
+            // Case-insensitive regexes are built by the "matches-" predicate,
+            // the other options become flags of the regex.
+            let mut ignore_case = false;
+            let mut flags = String::new();
+            for option in options.chars() {
+                match option {
+                    'i' => ignore_case = true,
+                    'm' | 's' | 'x' => flags.push(option),
+                    _ => return Err(format!("the `{option}` option isn't supported")),
+                }
+            }
+            let pattern = match flags.is_empty() {
+                true => pattern.clone(),
+                false => format!("(?{flags}){pattern}"),
+            };
+            let value = LibRegex::new(&pattern)
+                .map_err(|err| format!("the regex can't be compiled: {err}"))?;
+
+            guard(FirstOrder::Matches(Matches {
+                path: Some(path.clone()),
+                quantifier: None,
+                ignore_case,
+                value: value.into(),
+            }))
+        }
+        "$options" if operators.contains_key("$regex") => return Ok(None),
+        "$options" => return Err("`$options` must come with `$regex`".to_string()),
+        "$exists" => {
+            let exists = match operand {
+                Value::Bool(exists) => *exists,
+                Value::Number(number) => number.as_f64() != Some(0.0),
+                _ => return Err("`$exists` must be a boolean".to_string()),
+            };
+            let path = Some(path.clone());
+            match exists {
+                true => FirstOrder::Defined(Defined {
+                    path,
+                    quantifier: None,
+                }),
+                false => FirstOrder::Undefined(Undefined {
+                    path,
+                    quantifier: None,
+                }),
+            }
+            .into()
+        }
+        "$type" => {
+            let types = match operand {
+                Value::Array(types) if types.is_empty() => {
+                    return Err("`$type` must not be an empty array".to_string());
+                }
+                Value::Array(types) => types.iter().map(json_type).collect::<Result<_, _>>()?,
+                ty => vec![json_type(ty)?],
+            };
+            let types = types.into_iter().map(|ty| {
+                let ty = FirstOrder::Type(Type {
+                    path: Some(path.clone()),
+                    quantifier: None,
+                    value: ty.to_string(),
+                });
+                Predicate::from(ty)
+            });
+            guarded(path, any(types.collect()), negated)
+        }
+        _ => return Err("the operator isn't supported".to_string()),
+    };
+
+    Ok(Some(predicate))
+}
+
+/// The predicate holding when the field is equal to `value`, a `null` one
+/// also matching a missing field.
+fn from_equals(path: &JSONPath, value: &Value, negated: bool) -> Predicate {
+    let test = FirstOrder::Test(Test {
+        path: Some(path.clone()),
+        quantifier: None,
+        ignore_case: false,
+        value: Operand::Value(value.clone()),
+    });
+
+    match value {
+        Value::Null => or_undefined(path, test.into()),
+        _ => guarded(path, test.into(), negated),
+    }
+}
+
+/// The "type" predicate value checking the BSON type of a `$type` operator,
+/// given by its alias or its number.
+fn json_type(ty: &Value) -> Result<&'static str, String> {
+    let alias = match ty {
+        Value::String(alias) => alias.as_str(),
+        Value::Number(number) => match number.as_i64() {
+            Some(1) => "double",
+            Some(2) => "string",
+            Some(3) => "object",
+            Some(4) => "array",
+            Some(8) => "bool",
+            Some(10) => "null",
+            Some(16) => "int",
+            Some(18) => "long",
+            Some(19) => "decimal",
+            _ => "",
+        },
+        _ => return Err("`$type` must be a type alias, a type number or an array of them".into()),
+    };
+
+    match alias {
+        "number" => Ok("number"),
+        "string" => Ok("string"),
+        "object" => Ok("object"),
+        "array" => Ok("array"),
+        "bool" => Ok("boolean"),
+        "null" => Ok("null"),
+        "double" | "int" | "long" | "decimal" => Err(format!(
+            "JSON numbers can't be told apart as `{alias}`, use `number`"
+        )),
+        "" => Err(format!("the BSON type number {ty} has no JSON equivalent")),
+        _ => Err(format!("the `{alias}` type has no JSON equivalent")),
+    }
+}
+
+fn invalid(location: &[String], reason: impl Into<String>) -> MongoError {
+    MongoError::Filter {
+        location: JSONPath::from_tokens(location.to_vec()),
+        reason: reason.into(),
+    }
+}
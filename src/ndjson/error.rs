@@ -0,0 +1,41 @@
+use std::io;
+
+/// Error raised while filtering NDJSON records with an
+/// [`NdjsonFilter`](super::NdjsonFilter).
+///
+/// Line numbers start at 1 and count every line of the input, blank ones
+/// included.
+#[derive(Debug, thiserror::Error)]
+pub enum NdjsonError {
+    /// The input couldn't be read.
+    #[error("Couldn't read line {line}: {source}")]
+    Read {
+        line: usize,
+        #[source]
+        source: io::Error,
+    },
+    /// A line isn't a valid JSON document, only raised with
+    /// [`OnInvalid::Abort`](super::OnInvalid::Abort).
+    #[error("Line {line} isn't a valid JSON record: {source}")]
+    Invalid {
+        line: usize,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// The matching records couldn't be written.
+    #[error("Couldn't write the matching records: {source}")]
+    Write {
+        #[source]
+        source: io::Error,
+    },
+}
+
+impl NdjsonError {
+    /// The line of the input which caused the error, if any.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Self::Read { line, .. } | Self::Invalid { line, .. } => Some(*line),
+            Self::Write { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! Streaming evaluation of a predicate over newline-delimited JSON
+//! ([NDJSON](https://github.com/ndjson/ndjson-spec)) records.
+//!
+//! An [`NdjsonFilter`] reads records from any [`Read`] and writes the ones
+//! the predicate holds for to any [`Write`], as they were read. Records are
+//! processed by batches of lines so that memory stays bounded whatever the
+//! size of the input, and a batch can be split into chunks evaluated in
+//! parallel, the output keeping the order of the input.
+//!
+//! ```rust
+//! use json_predicate::ndjson::NdjsonFilter;
+//! use json_predicate::Predicate;
+//!
+//! let predicate: Predicate = r#"/level == "error""#.parse()?;
+//! let input = concat!(
+//!     r#"{"level": "info", "msg": "started"}"#, "\n",
+//!     r#"{"level": "error", "msg": "failed"}"#, "\n",
+//! );
+//!
+//! let mut output = Vec::new();
+//! let summary = NdjsonFilter::new(&predicate)
+//!     .with_threads(4)
+//!     .run(input.as_bytes(), &mut output)?;
+//!
+//! assert_eq!(summary.records, 2);
+//! assert_eq!(summary.matched, 1);
+//! assert_eq!(output, b"{\"level\": \"error\", \"msg\": \"failed\"}\n");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::thread;
+
+use serde_json::Value;
+
+use crate::predicate::compiled::CompiledPredicate;
+use crate::predicate::outcome::Outcome;
+
+mod error;
+
+pub use error::NdjsonError;
+
+/// The number of lines evaluated by a thread at once, by default.
+const CHUNK_SIZE: usize = 1024;
+
+/// What to do with a line which isn't a valid JSON document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnInvalid {
+    /// Stop with an [`NdjsonError::Invalid`], the records before it being
+    /// written.
+    #[default]
+    Abort,
+    /// Skip the line, it's counted in [`Summary::skipped`].
+    Skip,
+}
+
+/// What happened to the records of an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Summary {
+    /// The number of records read, blank lines excluded.
+    pub records: usize,
+    /// The number of records the predicate holds for, which were written.
+    pub matched: usize,
+    /// The number of records the predicate couldn't be evaluated against,
+    /// handled as not matching.
+    pub errors: usize,
+    /// The number of lines skipped as they aren't valid JSON documents.
+    pub skipped: usize,
+}
+
+/// Filters NDJSON records with a [`CompiledPredicate`].
+///
+/// Blank lines are ignored, and an [`Outcome::Error`] is handled as "false"
+/// as described by the specification. At most `threads * chunk_size` lines
+/// are held in memory at once.
+#[derive(Debug, Clone)]
+pub struct NdjsonFilter {
+    predicate: CompiledPredicate,
+    threads: usize,
+    chunk_size: usize,
+    on_invalid: OnInvalid,
+}
+
+/// The result of the evaluation of a line.
+enum Verdict {
+    Blank,
+    Invalid(serde_json::Error),
+    Evaluated(Outcome),
+}
+
+impl NdjsonFilter {
+    /// A filter evaluating the records on the current thread, invalid lines
+    /// aborting it.
+    pub fn new(predicate: impl Into<CompiledPredicate>) -> Self {
+        Self {
+            predicate: predicate.into(),
+            threads: 1,
+            chunk_size: CHUNK_SIZE,
+            on_invalid: OnInvalid::default(),
+        }
+    }
+
+    /// Evaluate the records on up to `threads` threads, e.g.
+    /// [`std::thread::available_parallelism`]. `0` is handled as `1`.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// The number of lines evaluated by a thread at once, `1024` by default.
+    /// `0` is handled as `1`.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// What to do with a line which isn't a valid JSON document.
+    pub fn with_on_invalid(mut self, on_invalid: OnInvalid) -> Self {
+        self.on_invalid = on_invalid;
+        self
+    }
+
+    /// Read the records from `reader` until its end and write the ones the
+    /// predicate holds for to `writer`, one per line.
+    ///
+    /// Both are buffered, and `writer` is flushed before returning.
+    pub fn run<R: Read, W: Write>(&self, reader: R, writer: W) -> Result<Summary, NdjsonError> {
+        let mut reader = BufReader::new(reader);
+        let mut writer = BufWriter::new(writer);
+        let mut summary = Summary::default();
+
+        // The lines are reused from one batch to the other.
+        let mut lines: Vec<Vec<u8>> = Vec::new();
+        let mut line = 0;
+
+        loop {
+            let (len, failure) = self.read_batch(&mut reader, &mut lines, line);
+            if len == 0 && failure.is_none() {
+                break;
+            }
+
+            let batch = &lines[..len];
+            for (offset, (record, verdict)) in batch.iter().zip(self.verdicts(batch)).enumerate() {
+                match verdict {
+                    Verdict::Blank => {}
+                    Verdict::Invalid(source) => match self.on_invalid {
+                        OnInvalid::Abort => {
+                            writer
+                                .flush()
+                                .map_err(|source| NdjsonError::Write { source })?;
+                            return Err(NdjsonError::Invalid {
+                                line: line + offset + 1,
+                                source,
+                            });
+                        }
+                        OnInvalid::Skip => summary.skipped += 1,
+                    },
+                    Verdict::Evaluated(outcome) => {
+                        summary.records += 1;
+                        match outcome {
+                            Outcome::True => {
+                                summary.matched += 1;
+                                writer
+                                    .write_all(record)
+                                    .and_then(|()| writer.write_all(b"\n"))
+                                    .map_err(|source| NdjsonError::Write { source })?;
+                            }
+                            Outcome::False => {}
+                            Outcome::Error(_) => summary.errors += 1,
+                        }
+                    }
+                }
+            }
+            line += len;
+
+            if let Some(err) = failure {
+                writer
+                    .flush()
+                    .map_err(|source| NdjsonError::Write { source })?;
+                return Err(err);
+            }
+        }
+
+        writer
+            .flush()
+            .map_err(|source| NdjsonError::Write { source })?;
+        Ok(summary)
+    }
+
+    /// Read up to `threads * chunk_size` lines, without their line terminator, the
+    /// first one being the line after `line`. Returns the number of lines
+    /// read, `0` at the end of the input, and the error which stopped the
+    /// reading if any.
+    fn read_batch<R: BufRead>(
+        &self,
+        reader: &mut R,
+        lines: &mut Vec<Vec<u8>>,
+        line: usize,
+    ) -> (usize, Option<NdjsonError>) {
+        let capacity = self.threads * self.chunk_size;
+        let mut len = 0;
+
+        while len < capacity {
+            if len == lines.len() {
+                lines.push(Vec::new());
+            }
+            let buffer = &mut lines[len];
+            buffer.clear();
+
+            match reader.read_until(b'\n', buffer) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(source) => {
+                    let line = line + len + 1;
+                    return (len, Some(NdjsonError::Read { line, source }));
+                }
+            }
+            if buffer.last() == Some(&b'\n') {
+                buffer.pop();
+                if buffer.last() == Some(&b'\r') {
+                    buffer.pop();
+                }
+            }
+            len += 1;
+        }
+
+        (len, None)
+    }
+
+    /// Evaluate the lines, in chunks evaluated in parallel when there are
+    /// several threads.
+    fn verdicts(&self, lines: &[Vec<u8>]) -> Vec<Verdict> {
+        if self.threads == 1 || lines.len() <= self.chunk_size {
+            return lines.iter().map(|line| self.verdict(line)).collect();
+        }
+
+        thread::scope(|scope| {
+            let chunks: Vec<_> = lines
+                .chunks(self.chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|line| self.verdict(line))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            chunks
+                .into_iter()
+                .flat_map(|chunk| {
+                    chunk
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    }
+
+    fn verdict(&self, line: &[u8]) -> Verdict {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            return Verdict::Blank;
+        }
+
+        match serde_json::from_slice::<Value>(line) {
+            Ok(record) => Verdict::Evaluated(self.predicate.evaluate(&record)),
+            Err(err) => Verdict::Invalid(err),
+        }
+    }
+}
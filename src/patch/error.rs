@@ -0,0 +1,47 @@
+use crate::predicate::error::PredicateError;
+
+/// Error raised while applying a [`Patch`](super::Patch).
+///
+/// Every variant carries the `index` of the operation, inside the patch
+/// document, which caused the patch to be aborted.
+#[derive(Debug, thiserror::Error)]
+pub enum PatchError {
+    /// A predicate operation evaluated as "false".
+    #[error("The predicate operation at index {index} evaluated to false.")]
+    PredicateFailed { index: usize },
+    /// A predicate operation couldn't be evaluated, it's handled as if it
+    /// evaluated to "false".
+    #[error("The predicate operation at index {index} couldn't be evaluated: {source}")]
+    PredicateError {
+        index: usize,
+        #[source]
+        source: PredicateError,
+    },
+    /// The "path" or "from" member of the operation references a value that
+    /// does not exist.
+    #[error("The operation at index {index} references a nonexistent value.")]
+    NoSuchValue { index: usize },
+    /// The operation references an array element with an invalid index.
+    #[error("The operation at index {index} references an invalid array index.")]
+    InvalidIndex { index: usize },
+    /// The "move" operation tried to move a value into one of its children.
+    #[error("The operation at index {index} tries to move a value into one of its children.")]
+    MoveIntoChild { index: usize },
+    /// The operation tried to remove the whole document.
+    #[error("The operation at index {index} tries to remove the whole document.")]
+    RemoveRoot { index: usize },
+}
+
+impl PatchError {
+    /// The index of the operation which caused the patch to be aborted.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::PredicateFailed { index }
+            | Self::PredicateError { index, .. }
+            | Self::NoSuchValue { index }
+            | Self::InvalidIndex { index }
+            | Self::MoveIntoChild { index }
+            | Self::RemoveRoot { index } => *index,
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! JSON Patch [RFC6902](https://datatracker.ietf.org/doc/html/rfc6902)
+//! extended with JSON Predicates as described in
+//! [draft-snell-json-test-07](https://datatracker.ietf.org/doc/html/draft-snell-json-test-07).
+//!
+//! Every predicate can be used as a JSON Patch operation, it acts as a
+//! conditional guard: if it evaluates as "false" the whole patch is aborted
+//! and the document is left untouched.
+//!
+//! ```rust
+//! use json_predicate::patch::Patch;
+//! use serde::Deserialize;
+//!
+//! let mut document = serde_json::json!({
+//!     "a": { "b": "ABC!XYZ" }
+//! });
+//!
+//! let patch = Patch::deserialize(serde_json::json!([
+//!     { "op": "contains", "path": "/a/b", "value": "ABC" },
+//!     { "op": "replace", "path": "/a/b", "value": "DEF" }
+//! ]))?;
+//!
+//! patch.apply(&mut document)?;
+//! assert_eq!(document, serde_json::json!({ "a": { "b": "DEF" } }));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::json_path::JSONPath;
+use crate::predicate::context::PredicateContext;
+use crate::predicate::outcome::Outcome;
+use crate::predicate::{Predicate, PredicateImpl};
+
+mod error;
+
+pub use error::PatchError;
+
+/// A JSON Patch document: an ordered list of operations applied one after the
+/// other.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(transparent)]
+pub struct Patch(pub Vec<PatchOperation>);
+
+/// A single operation of a [`Patch`], either one of the operations defined by
+/// [RFC6902](https://datatracker.ietf.org/doc/html/rfc6902) or a
+/// [`Predicate`].
+///
+/// The RFC6902 "test" operation is handled as the "test" predicate, which
+/// has the same semantics.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum PatchOperation {
+    Operation(Operation),
+    Predicate(Predicate),
+}
+
+/// The operations defined by [RFC6902, Section 4](https://datatracker.ietf.org/doc/html/rfc6902#section-4).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum Operation {
+    /// Adds a value to an object or inserts it into an array.
+    Add { path: JSONPath, value: Value },
+    /// Removes the value at the target location.
+    Remove { path: JSONPath },
+    /// Replaces the value at the target location with a new value.
+    Replace { path: JSONPath, value: Value },
+    /// Removes the value at a specified location and adds it to the target
+    /// location.
+    Move { from: JSONPath, path: JSONPath },
+    /// Copies the value at a specified location to the target location.
+    Copy { from: JSONPath, path: JSONPath },
+}
+
+impl From<Operation> for PatchOperation {
+    fn from(value: Operation) -> Self {
+        PatchOperation::Operation(value)
+    }
+}
+
+impl From<Predicate> for PatchOperation {
+    fn from(value: Predicate) -> Self {
+        PatchOperation::Predicate(value)
+    }
+}
+
+impl From<Vec<PatchOperation>> for Patch {
+    fn from(value: Vec<PatchOperation>) -> Self {
+        Patch(value)
+    }
+}
+
+impl Patch {
+    /// Apply the patch to the provided JSON.
+    ///
+    /// The patch is atomic: operations are applied in order on a copy of the
+    /// document which only replaces the provided one when every operation
+    /// succeeded. If an operation fails, or if a predicate doesn't evaluate as
+    /// "true", the document is left untouched.
+    pub fn apply(&self, data: &mut Value) -> Result<(), PatchError> {
+        let mut patched = data.clone();
+
+        for (index, operation) in self.0.iter().enumerate() {
+            operation.apply(&mut patched, index)?;
+        }
+
+        *data = patched;
+        Ok(())
+    }
+}
+
+impl PatchOperation {
+    fn apply(&self, data: &mut Value, index: usize) -> Result<(), PatchError> {
+        match self {
+            PatchOperation::Operation(operation) => operation.apply(data, index),
+            PatchOperation::Predicate(predicate) => {
+                match predicate.evaluate(data, PredicateContext::default()) {
+                    Outcome::True => Ok(()),
+                    Outcome::False => Err(PatchError::PredicateFailed { index }),
+                    Outcome::Error(source) => Err(PatchError::PredicateError { index, source }),
+                }
+            }
+        }
+    }
+}
+
+impl Operation {
+    fn apply(&self, data: &mut Value, index: usize) -> Result<(), PatchError> {
+        match self {
+            Operation::Add { path, value } => add(data, path, value.clone(), index),
+            Operation::Remove { path } => remove(data, path, index).map(|_| ()),
+            Operation::Replace { path, value } => {
+                let target = path
+                    .clone()
+                    .take()
+                    .get_mut(data)
+                    .map_err(|_| PatchError::NoSuchValue { index })?;
+                *target = value.clone();
+                Ok(())
+            }
+            Operation::Move { from, path } => {
+                // The "from" location MUST exist for the operation to be
+                // successful.
+                if from.get(data).is_err() {
+                    return Err(PatchError::NoSuchValue { index });
+                }
+                if from == path {
+                    return Ok(());
+                }
+                // The "from" location MUST NOT be a proper prefix of the "path"
+                // location.
+                if path.to_string().starts_with(&format!("{}/", from)) {
+                    return Err(PatchError::MoveIntoChild { index });
+                }
+                let value = remove(data, from, index)?;
+                add(data, path, value, index)
+            }
+            Operation::Copy { from, path } => {
+                let value = from
+                    .clone()
+                    .take()
+                    .get(data)
+                    .map_err(|_| PatchError::NoSuchValue { index })?
+                    .clone();
+                add(data, path, value, index)
+            }
+        }
+    }
+}
+
+/// Parse an array index as described in [RFC6901, Section 4](https://datatracker.ietf.org/doc/html/rfc6901#section-4):
+/// leading zeros are not allowed.
+fn array_index(token: &str, index: usize) -> Result<usize, PatchError> {
+    if token.is_empty()
+        || (token.len() > 1 && token.starts_with('0'))
+        || !token.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(PatchError::InvalidIndex { index });
+    }
+    token
+        .parse()
+        .map_err(|_| PatchError::InvalidIndex { index })
+}
+
+fn add(data: &mut Value, path: &JSONPath, value: Value, index: usize) -> Result<(), PatchError> {
+    let mut parent = path.clone().take();
+    let Some(token) = parent.pop() else {
+        *data = value;
+        return Ok(());
+    };
+
+    match parent
+        .get_mut(data)
+        .map_err(|_| PatchError::NoSuchValue { index })?
+    {
+        Value::Object(map) => {
+            map.insert(token, value);
+            Ok(())
+        }
+        Value::Array(array) => {
+            let position = if token == "-" {
+                array.len()
+            } else {
+                array_index(&token, index)?
+            };
+            if position > array.len() {
+                return Err(PatchError::InvalidIndex { index });
+            }
+            array.insert(position, value);
+            Ok(())
+        }
+        _ => Err(PatchError::NoSuchValue { index }),
+    }
+}
+
+fn remove(data: &mut Value, path: &JSONPath, index: usize) -> Result<Value, PatchError> {
+    let mut parent = path.clone().take();
+    let Some(token) = parent.pop() else {
+        return Err(PatchError::RemoveRoot { index });
+    };
+
+    match parent
+        .get_mut(data)
+        .map_err(|_| PatchError::NoSuchValue { index })?
+    {
+        Value::Object(map) => map.remove(&token).ok_or(PatchError::NoSuchValue { index }),
+        Value::Array(array) => {
+            let position = array_index(&token, index)?;
+            if position >= array.len() {
+                return Err(PatchError::InvalidIndex { index });
+            }
+            Ok(array.remove(position))
+        }
+        _ => Err(PatchError::NoSuchValue { index }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::patch::{Operation, Patch, PatchOperation};
+
+    #[test]
+    fn deser_test() {
+        let patch_expect = serde_json::json!([
+            { "op": "test", "path": "/a/b", "value": "c" },
+            { "op": "add", "path": "/a/d", "value": 1 },
+            { "op": "move", "from": "/a/d", "path": "/e" }
+        ]);
+
+        let deser = Patch::deserialize(patch_expect.clone()).unwrap();
+
+        assert!(matches!(deser.0[0], PatchOperation::Predicate(_)));
+        assert!(matches!(
+            deser.0[1],
+            PatchOperation::Operation(Operation::Add { .. })
+        ));
+        assert!(matches!(
+            deser.0[2],
+            PatchOperation::Operation(Operation::Move { .. })
+        ));
+        assert_eq!(serde_json::to_value(&deser.0[1]).unwrap(), patch_expect[1]);
+        assert_eq!(serde_json::to_value(&deser.0[2]).unwrap(), patch_expect[2]);
+    }
+}
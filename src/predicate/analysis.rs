@@ -0,0 +1,563 @@
+//! Static analysis of predicates, without any document.
+
+use std::fmt::Display;
+
+use serde_json::Value;
+
+use crate::comparable::Comparable;
+use crate::json_path::JSONPath;
+
+use super::context::PredicateContext;
+use super::first_order::FirstOrder;
+use super::operand::Operand;
+use super::second_order::SecondOrder;
+use super::Predicate;
+
+/// A predicate found by [`Predicate::analyze`] to evaluate the same way
+/// against every document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// The JSON Pointer of the predicate in the predicate document, e.g.
+    /// `/apply/1`.
+    pub location: JSONPath,
+    /// Whether the predicate is never or always "true".
+    pub kind: FindingKind,
+    /// Why the predicate evaluates the same way against every document.
+    pub reason: String,
+}
+
+/// What a [`Finding`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindingKind {
+    /// The predicate never evaluates as "true".
+    Contradiction,
+    /// The predicate always evaluates as "true", at least where it is, e.g.
+    /// a subordinate predicate implied by another one of the same "and"
+    /// predicate.
+    Tautology,
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            FindingKind::Contradiction => "contradiction",
+            FindingKind::Tautology => "tautology",
+        };
+        if self.location.tokens().is_empty() {
+            write!(f, "{kind}: {}", self.reason)
+        } else {
+            write!(f, "{kind} at {}: {}", self.location, self.reason)
+        }
+    }
+}
+
+impl Predicate {
+    /// Report the predicates, this one or its subordinate ones, which never
+    /// evaluate as "true" whatever the document, e.g. a "less" and a "more"
+    /// predicate on the same path which can't both hold inside an "and", or
+    /// which always evaluate as "true".
+    ///
+    /// Only the predicate at the origin of a finding is reported, not the
+    /// ones it makes evaluate the same way in turn. Predicates with a
+    /// quantifier, a "value" member referencing another location or a
+    /// variable, or an operation registered in an
+    /// [`OperatorRegistry`](super::registry::OperatorRegistry) are assumed to
+    /// evaluate either way.
+    pub fn analyze(&self) -> Vec<Finding> {
+        let mut analyzer = Analyzer::default();
+        analyzer.visit(self, &PredicateContext::default(), false, &mut Vec::new());
+        analyzer.findings
+    }
+}
+
+/// How a predicate evaluates whatever the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verdict {
+    Never,
+    Always,
+    Unknown,
+}
+
+/// The types of JSON values, as a set of bits.
+type Types = u8;
+
+const NUMBER: Types = 1;
+const STRING: Types = 1 << 1;
+const BOOLEAN: Types = 1 << 2;
+const OBJECT: Types = 1 << 3;
+const ARRAY: Types = 1 << 4;
+const NULL: Types = 1 << 5;
+
+fn type_of(value: &Value) -> Types {
+    match value {
+        Value::Null => NULL,
+        Value::Bool(_) => BOOLEAN,
+        Value::Number(_) => NUMBER,
+        Value::String(_) => STRING,
+        Value::Array(_) => ARRAY,
+        Value::Object(_) => OBJECT,
+    }
+}
+
+/// The subordinate predicate a constraint comes from.
+#[derive(Debug, Clone)]
+struct Source {
+    op: &'static str,
+    location: JSONPath,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` at {}", self.op, self.location)
+    }
+}
+
+/// What the subordinate predicates of an "and" predicate require from the
+/// value at one path.
+struct Constraints {
+    path: JSONPath,
+    /// The predicates requiring the value to be defined.
+    defined: Vec<Source>,
+    undefined: Option<Source>,
+    types: Option<(Types, Source)>,
+    /// The values it can be among.
+    values: Option<(Vec<Value>, Source)>,
+    /// The exclusive bounds of a number.
+    lower: Option<(f64, Source)>,
+    upper: Option<(f64, Source)>,
+}
+
+/// What a First Order predicate requires from the value at its path.
+#[derive(Default)]
+struct Requirement {
+    defined: bool,
+    undefined: bool,
+    types: Option<Types>,
+    values: Option<Vec<Value>>,
+    lower: Option<f64>,
+    upper: Option<f64>,
+}
+
+impl Requirement {
+    fn of(first_order: &FirstOrder) -> Requirement {
+        let mut requirement = Requirement {
+            defined: true,
+            ..Default::default()
+        };
+        if first_order.quantifier().is_some() {
+            requirement.defined = false;
+            return requirement;
+        }
+
+        match first_order {
+            FirstOrder::Defined(_) => {}
+            FirstOrder::Undefined(_) => {
+                requirement.defined = false;
+                requirement.undefined = true;
+            }
+            FirstOrder::Type(ty) => {
+                requirement.types = match ty.value.as_str() {
+                    "number" => Some(NUMBER),
+                    "string" | "date" | "time" | "date-time" | "lang" | "lang-range" | "iri"
+                    | "absolute-iri" => Some(STRING),
+                    "boolean" => Some(BOOLEAN),
+                    "object" => Some(OBJECT),
+                    "array" => Some(ARRAY),
+                    "null" => Some(NULL),
+                    _ => None,
+                };
+                if ty.value == "undefined" {
+                    requirement.defined = false;
+                    requirement.undefined = true;
+                }
+            }
+            FirstOrder::Contains(_)
+            | FirstOrder::Start(_)
+            | FirstOrder::End(_)
+            | FirstOrder::Matches(_) => requirement.types = Some(STRING),
+            FirstOrder::Contained(_) | FirstOrder::Intersects(_) => requirement.types = Some(ARRAY),
+            FirstOrder::Test(test) => {
+                if let Operand::Value(value) = &test.value {
+                    requirement.types = Some(type_of(value));
+                    if !test.ignore_case {
+                        requirement.values = Some(vec![value.clone()]);
+                    }
+                }
+            }
+            FirstOrder::In(r#in) => {
+                if let Operand::Value(values) = &r#in.value {
+                    requirement.types = Some(values.iter().map(type_of).fold(0, |a, b| a | b));
+                    if !r#in.ignore_case {
+                        requirement.values = Some(values.clone());
+                    }
+                }
+            }
+            FirstOrder::Less(less) => match less.value.literal() {
+                Some(Comparable::Number(number)) => {
+                    requirement.types = Some(NUMBER);
+                    requirement.upper = number.as_f64();
+                }
+                Some(Comparable::Temporal(_)) => requirement.types = Some(STRING),
+                None => {}
+            },
+            FirstOrder::More(more) => match more.value.literal() {
+                Some(Comparable::Number(number)) => {
+                    requirement.types = Some(NUMBER);
+                    requirement.lower = number.as_f64();
+                }
+                Some(Comparable::Temporal(_)) => requirement.types = Some(STRING),
+                None => {}
+            },
+        }
+
+        requirement
+    }
+}
+
+/// Two subordinate predicates which can't both hold for the value at `path`.
+struct Conflict {
+    path: JSONPath,
+    first: Source,
+    second: Source,
+}
+
+impl Constraints {
+    fn new(path: JSONPath) -> Self {
+        Constraints {
+            path,
+            defined: Vec::new(),
+            undefined: None,
+            types: None,
+            values: None,
+            lower: None,
+            upper: None,
+        }
+    }
+
+    /// Add the requirement of the predicate `source`, the conflict with a
+    /// previous one if they can't both hold.
+    fn add(&mut self, requirement: Requirement, source: Source) -> Result<(), Conflict> {
+        let path = self.path.clone();
+        let conflict = |first: &Source| Conflict {
+            path: path.clone(),
+            first: first.clone(),
+            second: source.clone(),
+        };
+
+        if requirement.defined {
+            if let Some(undefined) = &self.undefined {
+                return Err(conflict(undefined));
+            }
+            self.defined.push(source.clone());
+        }
+        if requirement.undefined {
+            if let Some(defined) = self.defined.first() {
+                return Err(conflict(defined));
+            }
+            self.undefined.get_or_insert(source.clone());
+        }
+
+        if let Some(types) = requirement.types {
+            match &mut self.types {
+                Some((existing, from)) => {
+                    *existing &= types;
+                    if *existing == 0 {
+                        return Err(conflict(from));
+                    }
+                }
+                None => self.types = Some((types, source.clone())),
+            }
+        }
+        if let Some(lower) = requirement.lower {
+            if !matches!(&self.lower, Some((existing, _)) if *existing >= lower) {
+                self.lower = Some((lower, source.clone()));
+            }
+        }
+        if let Some(upper) = requirement.upper {
+            if !matches!(&self.upper, Some((existing, _)) if *existing <= upper) {
+                self.upper = Some((upper, source.clone()));
+            }
+        }
+        if let (Some((lower, from)), Some((upper, to))) = (&self.lower, &self.upper) {
+            // No number is strictly between them.
+            if lower >= upper {
+                let first = if from.location == source.location {
+                    to
+                } else {
+                    from
+                };
+                return Err(conflict(first));
+            }
+        }
+
+        if let Some(values) = requirement.values {
+            match &mut self.values {
+                Some((existing, from)) => {
+                    existing.retain(|value| values.contains(value));
+                    if existing.is_empty() {
+                        return Err(conflict(from));
+                    }
+                }
+                None => self.values = Some((values, source.clone())),
+            }
+        }
+        if let Some((values, from)) = &mut self.values {
+            let (types, lower, upper) = (&self.types, &self.lower, &self.upper);
+            values.retain(|value| {
+                let number = value.as_f64();
+                types.iter().all(|(types, _)| type_of(value) & types != 0)
+                    && lower
+                        .iter()
+                        .all(|(lower, _)| number.iter().all(|n| n > lower))
+                    && upper
+                        .iter()
+                        .all(|(upper, _)| number.iter().all(|n| n < upper))
+            });
+            if values.is_empty() {
+                let first = if from.location == source.location {
+                    let types = self.types.as_ref().map(|(_, source)| source);
+                    let lower = self.lower.as_ref().map(|(_, source)| source);
+                    let upper = self.upper.as_ref().map(|(_, source)| source);
+                    [types, lower, upper]
+                        .into_iter()
+                        .flatten()
+                        .find(|other| other.location != source.location)
+                        .unwrap_or(from)
+                } else {
+                    from
+                };
+                return Err(conflict(first));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Analyzer {
+    findings: Vec<Finding>,
+}
+
+impl Analyzer {
+    fn report(&mut self, location: JSONPath, kind: FindingKind, reason: String) {
+        let finding = Finding {
+            location,
+            kind,
+            reason,
+        };
+        if !self.findings.contains(&finding) {
+            self.findings.push(finding);
+        }
+    }
+
+    /// The verdict of `predicate`, at `location` in the predicate document
+    /// and evaluated from `ctx`, `negated` when it's under an odd number of
+    /// "not" predicates.
+    fn visit(
+        &mut self,
+        predicate: &Predicate,
+        ctx: &PredicateContext,
+        negated: bool,
+        location: &mut Vec<String>,
+    ) -> Verdict {
+        let second_order = match predicate {
+            Predicate::FirstOrder(FirstOrder::In(r#in)) => {
+                // A quantified "in" can hold without any value, e.g. when no
+                // element is referenced or with the "none" quantifier.
+                let never = r#in.quantifier.is_none()
+                    && matches!(&r#in.value, Operand::Value(values) if values.is_empty());
+                if never {
+                    let reason = format!("`{}` without any value is never true", r#in.op());
+                    let location = JSONPath::from_tokens(location.clone());
+                    self.report(location, FindingKind::Contradiction, reason);
+                    return Verdict::Never;
+                }
+                return Verdict::Unknown;
+            }
+            Predicate::FirstOrder(_) | Predicate::Extension(_) => return Verdict::Unknown,
+            Predicate::SecondOrder(second_order) => second_order,
+        };
+
+        let ctx = ctx.scoped(ctx.final_path(&second_order.path().cloned()));
+        let negate = negated != matches!(second_order, SecondOrder::Not(_));
+        let verdicts: Vec<Verdict> = second_order
+            .apply()
+            .iter()
+            .enumerate()
+            .map(|(index, predicate)| {
+                location.extend(["apply".to_string(), index.to_string()]);
+                let verdict = self.visit(predicate, &ctx, negate, location);
+                location.truncate(location.len() - 2);
+                verdict
+            })
+            .collect();
+        let here = JSONPath::from_tokens(location.clone());
+
+        if verdicts.is_empty() {
+            let reason = format!(
+                "`{}` without any subordinate predicate is always true",
+                second_order.op()
+            );
+            self.report(here, FindingKind::Tautology, reason);
+            return Verdict::Always;
+        }
+
+        let any = |verdict| verdicts.contains(&verdict);
+        let all = |verdict| verdicts.iter().all(|v| *v == verdict);
+        match second_order {
+            SecondOrder::And(_) => {
+                if any(Verdict::Never)
+                    || self.contradicts(second_order.apply(), &ctx, negated, location)
+                {
+                    Verdict::Never
+                } else if all(Verdict::Always) {
+                    Verdict::Always
+                } else {
+                    Verdict::Unknown
+                }
+            }
+            SecondOrder::Or(_) => {
+                if any(Verdict::Always) {
+                    Verdict::Always
+                } else if all(Verdict::Never) {
+                    Verdict::Never
+                } else if self.covers(second_order.apply(), &ctx, &here) {
+                    Verdict::Always
+                } else {
+                    Verdict::Unknown
+                }
+            }
+            SecondOrder::Not(_) => {
+                if any(Verdict::Always) {
+                    Verdict::Never
+                } else if all(Verdict::Never) {
+                    Verdict::Always
+                } else {
+                    Verdict::Unknown
+                }
+            }
+        }
+    }
+
+    /// Gather the requirements of the subordinate predicates of an "and"
+    /// predicate, and of the "and" predicates among them, per path.
+    fn gather(
+        apply: &[Predicate],
+        ctx: &PredicateContext,
+        location: &mut Vec<String>,
+        constraints: &mut Vec<Constraints>,
+    ) -> Result<(), Conflict> {
+        for (index, predicate) in apply.iter().enumerate() {
+            location.extend(["apply".to_string(), index.to_string()]);
+            let result = match predicate {
+                Predicate::FirstOrder(first_order) => {
+                    let path = ctx
+                        .final_path(&first_order.path().cloned())
+                        .unwrap_or(JSONPath::empty());
+                    let source = Source {
+                        op: first_order.op(),
+                        location: JSONPath::from_tokens(location.clone()),
+                    };
+                    let position = constraints
+                        .iter()
+                        .position(|c| c.path == path)
+                        .unwrap_or_else(|| {
+                            constraints.push(Constraints::new(path));
+                            constraints.len() - 1
+                        });
+                    constraints[position].add(Requirement::of(first_order), source)
+                }
+                Predicate::SecondOrder(SecondOrder::And(and)) => {
+                    let ctx = ctx.scoped(ctx.final_path(&and.path));
+                    Self::gather(&and.apply, &ctx, location, constraints)
+                }
+                _ => Ok(()),
+            };
+            location.truncate(location.len() - 2);
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Whether the subordinate predicates of the "and" predicate at
+    /// `location` can't all hold, reporting it, or which of them are implied
+    /// by the others unless it's `negated`.
+    fn contradicts(
+        &mut self,
+        apply: &[Predicate],
+        ctx: &PredicateContext,
+        negated: bool,
+        location: &mut Vec<String>,
+    ) -> bool {
+        let mut constraints = Vec::new();
+        if let Err(Conflict {
+            path,
+            first,
+            second,
+        }) = Self::gather(apply, ctx, location, &mut constraints)
+        {
+            let reason = format!("{first} and {second} can't both hold for {path}");
+            let here = JSONPath::from_tokens(location.clone());
+            self.report(here, FindingKind::Contradiction, reason);
+            return true;
+        }
+
+        // A "defined" predicate is implied by any other one on the same path.
+        // Under a negation it isn't redundant though: it makes the predicate
+        // false instead of reporting an error when the element is missing,
+        // so the negation holds.
+        if negated {
+            return false;
+        }
+        for constraints in &constraints {
+            let implied_by = constraints.defined.iter().find(|s| s.op != "defined");
+            if let Some(implied_by) = implied_by {
+                for source in constraints.defined.iter().filter(|s| s.op == "defined") {
+                    let reason = format!("`defined` is implied by {implied_by}");
+                    self.report(source.location.clone(), FindingKind::Tautology, reason);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Whether the subordinate predicates of the "or" predicate at `here`
+    /// include a "defined" and an "undefined" predicate on the same path,
+    /// reporting it.
+    fn covers(&mut self, apply: &[Predicate], ctx: &PredicateContext, here: &JSONPath) -> bool {
+        let unquantified = |index: usize, predicate: &Predicate| match predicate {
+            Predicate::FirstOrder(
+                first_order @ (FirstOrder::Defined(_) | FirstOrder::Undefined(_)),
+            ) if first_order.quantifier().is_none() => {
+                let path = ctx
+                    .final_path(&first_order.path().cloned())
+                    .unwrap_or(JSONPath::empty());
+                Some((index, first_order.op(), path))
+            }
+            _ => None,
+        };
+        let checks: Vec<_> = apply
+            .iter()
+            .enumerate()
+            .filter_map(|(index, predicate)| unquantified(index, predicate))
+            .collect();
+
+        for (index, op, path) in &checks {
+            let covered = checks
+                .iter()
+                .find(|(_, other, other_path)| other != op && other_path == path);
+            if let (&"defined", Some((other, ..))) = (op, covered) {
+                let reason = format!(
+                    "`defined` at {here}/apply/{index} and `undefined` at {here}/apply/{other} \
+                     cover every document"
+                );
+                self.report(here.clone(), FindingKind::Tautology, reason);
+                return true;
+            }
+        }
+
+        false
+    }
+}
@@ -0,0 +1,570 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+use regex::{Regex, RegexBuilder};
+use serde_json::Value;
+
+use crate::comparable::Comparable;
+use crate::json_path::{self, JSONPath};
+
+use super::{
+    context::PredicateContext,
+    error::{PredicateError, PredicateErrorKind},
+    first_order::r#type::Type,
+    first_order::FirstOrder,
+    operand::{Operand, Reference},
+    outcome::Outcome,
+    quantifier::Quantifier,
+    registry::Extension,
+    second_order::SecondOrder,
+    Predicate, PredicateImpl,
+};
+
+/// A [`Predicate`] prepared for repeated evaluations.
+///
+/// Every pointer and variable is resolved once against the
+/// [`PredicateContext`] used to compile the predicate, needles of the
+/// case-insensitive operations are lowercased and regexes are built ahead of
+/// time, so [`CompiledPredicate::evaluate`] doesn't allocate unless the
+/// predicate can't be evaluated and an error has to be reported, or a "value"
+/// member referencing another location or a variable has to be lowercased or
+/// parsed as a date or time. Predicates whose operation was registered by
+/// the application are evaluated as they are.
+///
+/// ```rust
+/// # use json_predicate::{CompiledPredicate, Predicate};
+/// # use serde::Deserialize;
+/// let predicate = Predicate::deserialize(serde_json::json!({
+///     "op": "contains-",
+///     "path": "/a/b",
+///     "value": "abc"
+/// }))?;
+///
+/// let compiled = CompiledPredicate::from(&predicate);
+/// assert!(compiled.test(&serde_json::json!({ "a": { "b": "ABC!XYZ" } })));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompiledPredicate {
+    root: Node,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    And(Vec<Node>),
+    Or(Vec<Node>),
+    Not(Vec<Node>),
+    Defined(Pointer),
+    Undefined(Pointer),
+    Type(Pointer, Type),
+    /// The First Order predicates which need the referenced element.
+    Leaf(Pointer, Leaf),
+    /// The First Order predicates whose "value" member references another
+    /// location of the document or a variable, compared as they are.
+    Compare(Pointer, Source, FirstOrder),
+    /// A predicate whose operation was registered by the application,
+    /// evaluated as it is with the compilation context.
+    Extension(Extension, PredicateContext),
+}
+
+/// Where the "value" member of a [`Node::Compare`] comes from.
+#[derive(Debug, Clone)]
+enum Source {
+    Pointer(Pointer),
+    /// The value bound to the variable when the predicate was compiled, or
+    /// the error reported if it wasn't.
+    Variable(Result<Value, PredicateError>),
+}
+
+#[derive(Debug, Clone)]
+enum Leaf {
+    /// `None` when the "value" member isn't a string, which never matches.
+    Contains(Option<String>),
+    ContainsIgnoreCase(Option<String>),
+    Start(Option<String>),
+    StartIgnoreCase(Option<String>),
+    End(Option<String>),
+    EndIgnoreCase(Option<String>),
+    In(Vec<Value>),
+    /// The lowercased string representation of every candidate.
+    InIgnoreCase(Vec<String>),
+    Contained(Vec<Value>),
+    /// The lowercased string representation of every candidate.
+    ContainedIgnoreCase(Vec<String>),
+    Intersects(Vec<Value>),
+    /// The lowercased string representation of every candidate.
+    IntersectsIgnoreCase(Vec<String>),
+    Test(Value),
+    /// The lowercased string representation of the value.
+    TestIgnoreCase(String),
+    /// `None` when the case-insensitive regex couldn't be built.
+    Matches(Option<Regex>),
+    Less(Comparable),
+    More(Comparable),
+}
+
+/// An absolute JSON Pointer with its reference tokens split ahead of time,
+/// and the operation it's resolved for to report errors.
+#[derive(Debug, Clone)]
+struct Pointer {
+    tokens: Box<[String]>,
+    path: JSONPath,
+    op: &'static str,
+    quantifier: Option<Quantifier>,
+}
+
+impl Pointer {
+    fn new(path: JSONPath, op: &'static str, quantifier: Option<Quantifier>) -> Self {
+        Self {
+            tokens: path.tokens().into_boxed_slice(),
+            path,
+            op,
+            quantifier,
+        }
+    }
+
+    /// Resolve the pointer with the same rules as [`JSONPath::get`], without
+    /// allocating.
+    fn find<'a>(&self, data: &'a Value) -> Option<&'a Value> {
+        self.tokens
+            .iter()
+            .try_fold(data, |value, token| json_path::index(value, token))
+    }
+
+    /// Resolve the pointer, the error is only computed when the pointer
+    /// doesn't reference anything.
+    fn get<'a>(&self, data: &'a Value) -> Result<&'a Value, PredicateError> {
+        match self.find(data) {
+            Some(value) => Ok(value),
+            None => self.path.get(data).map_err(|err| self.error(err.into())),
+        }
+    }
+
+    fn error(&self, kind: PredicateErrorKind) -> PredicateError {
+        PredicateError::new(kind, self.op, self.path.clone())
+    }
+}
+
+/// A pointer which doesn't reference anything, the error is only computed
+/// when it's converted into an [`Outcome`].
+struct Missing<'a> {
+    ptr: &'a Pointer,
+    data: &'a Value,
+}
+
+impl From<Missing<'_>> for Outcome {
+    fn from(value: Missing<'_>) -> Self {
+        match value.ptr.get(value.data) {
+            Ok(_) => Outcome::False,
+            Err(err) => err.into(),
+        }
+    }
+}
+
+impl CompiledPredicate {
+    /// Compile the predicate, paths being resolved from the provided
+    /// context.
+    pub fn new(predicate: &Predicate, ctx: PredicateContext) -> Self {
+        Self {
+            root: Node::new(predicate, &ctx),
+        }
+    }
+
+    /// Evaluate the compiled predicate against the provided JSON.
+    pub fn evaluate(&self, data: &Value) -> Outcome {
+        self.root.evaluate(data)
+    }
+
+    /// Test the compiled predicate against the provided JSON, an
+    /// [`Outcome::Error`] is handled as "false".
+    pub fn test(&self, data: &Value) -> bool {
+        self.evaluate(data).is_true()
+    }
+
+    /// The indices of the `documents` the compiled predicate holds for, an
+    /// [`Outcome::Error`] being handled as "false".
+    pub fn matching<'a, I>(&'a self, documents: I) -> impl Iterator<Item = usize> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: Borrow<Value>,
+    {
+        documents
+            .into_iter()
+            .enumerate()
+            .filter(|(_, document)| self.test(document.borrow()))
+            .map(|(index, _)| index)
+    }
+
+    /// The `documents` the compiled predicate holds for, an
+    /// [`Outcome::Error`] being handled as "false".
+    pub fn filter<'a, I>(&'a self, documents: I) -> impl Iterator<Item = I::Item> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: Borrow<Value>,
+    {
+        documents
+            .into_iter()
+            .filter(|document| self.test(document.borrow()))
+    }
+}
+
+impl From<&Predicate> for CompiledPredicate {
+    fn from(value: &Predicate) -> Self {
+        Self::new(value, PredicateContext::default())
+    }
+}
+
+impl From<Predicate> for CompiledPredicate {
+    fn from(value: Predicate) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Node {
+    fn new(predicate: &Predicate, ctx: &PredicateContext) -> Self {
+        match predicate {
+            Predicate::FirstOrder(fo) => {
+                let path = ctx
+                    .final_path(&fo.path().cloned())
+                    .unwrap_or(JSONPath::empty());
+                let ptr = Pointer::new(path, fo.op(), fo.quantifier().copied());
+                match fo {
+                    FirstOrder::Defined(_) => Node::Defined(ptr),
+                    FirstOrder::Undefined(_) => Node::Undefined(ptr),
+                    FirstOrder::Type(ty) => Node::Type(ptr, ty.clone()),
+                    fo => match fo.value_reference() {
+                        Some(Reference::Path(operand)) => {
+                            let operand = ctx
+                                .final_path(&Some(operand.clone()))
+                                .unwrap_or(JSONPath::empty());
+                            let source = Source::Pointer(Pointer::new(operand, fo.op(), None));
+                            Node::Compare(ptr, source, fo.clone())
+                        }
+                        Some(Reference::Var(name)) => {
+                            let value = ctx.variable(name).cloned().ok_or_else(|| {
+                                let name = name.to_owned();
+                                ptr.error(PredicateErrorKind::UndefinedVariable { name })
+                            });
+                            Node::Compare(ptr, Source::Variable(value), fo.clone())
+                        }
+                        None => Node::Leaf(ptr, Leaf::new(fo)),
+                    },
+                }
+            }
+            Predicate::Extension(ext) => Node::Extension(ext.clone(), ctx.clone()),
+            Predicate::SecondOrder(so) => {
+                let ctx = ctx.scoped(ctx.final_path(&so.path().cloned()));
+                let apply = so.apply().iter().map(|ap| Node::new(ap, &ctx)).collect();
+                match so {
+                    SecondOrder::And(_) => Node::And(apply),
+                    SecondOrder::Or(_) => Node::Or(apply),
+                    SecondOrder::Not(_) => Node::Not(apply),
+                }
+            }
+        }
+    }
+
+    fn evaluate(&self, data: &Value) -> Outcome {
+        match self {
+            Node::And(apply) => Outcome::all(apply.iter().map(|ap| ap.evaluate(data))),
+            Node::Or(apply) => Outcome::any(apply.iter().map(|ap| ap.evaluate(data))),
+            Node::Not(apply) => Outcome::none(apply.iter().map(|ap| ap.evaluate(data))),
+            Node::Extension(ext, ctx) => ext.evaluate(data, ctx.clone()),
+            Node::Defined(ptr) => match &ptr.quantifier {
+                Some(quantifier) => quantifier.evaluate(&ptr.tokens, data, |_| Outcome::True),
+                None => ptr.find(data).is_some().into(),
+            },
+            Node::Undefined(ptr) => match &ptr.quantifier {
+                Some(quantifier) => quantifier.evaluate(&ptr.tokens, data, |_| Outcome::False),
+                None => ptr.find(data).is_none().into(),
+            },
+            Node::Type(ptr, ty) => match &ptr.quantifier {
+                Some(quantifier) => quantifier.evaluate(&ptr.tokens, data, |context_value| {
+                    ty.check(Ok::<_, Outcome>(context_value))
+                }),
+                None => ty.check(ptr.find(data).ok_or(Missing { ptr, data })),
+            },
+            Node::Leaf(ptr, leaf) => match &ptr.quantifier {
+                Some(quantifier) => quantifier.evaluate(&ptr.tokens, data, |context_value| {
+                    leaf.evaluate(ptr, context_value)
+                }),
+                None => match ptr.get(data) {
+                    Ok(context_value) => leaf.evaluate(ptr, context_value),
+                    Err(err) => err.into(),
+                },
+            },
+            Node::Compare(ptr, source, fo) => {
+                // Errors about the operand are reported at its own pointer,
+                // or at the predicate's one for a variable.
+                let (operand_value, operand) = match source {
+                    Source::Pointer(operand) => match operand.get(data) {
+                        Ok(operand_value) => (operand_value, operand),
+                        Err(err) => return err.into(),
+                    },
+                    Source::Variable(Ok(operand_value)) => (operand_value, ptr),
+                    Source::Variable(Err(err)) => return err.clone().into(),
+                };
+                // Like when evaluating the predicate, an operand of the wrong
+                // type is reported even if nothing is matched.
+                if fo.check_operand(&Value::Null, operand_value).is_none() {
+                    return operand.error(PredicateErrorKind::IncorrectType).into();
+                }
+                let check = |context_value: &Value| {
+                    fo.check_operand(context_value, operand_value)
+                        .unwrap_or_else(|| operand.error(PredicateErrorKind::IncorrectType).into())
+                };
+                match &ptr.quantifier {
+                    Some(quantifier) => quantifier.evaluate(&ptr.tokens, data, check),
+                    None => match ptr.get(data) {
+                        Ok(context_value) => check(context_value),
+                        Err(err) => err.into(),
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl Leaf {
+    /// `predicate` must not be a "defined", "undefined" or "type" predicate,
+    /// nor reference another location in its "value" member, they are
+    /// handled by [`Node`] directly.
+    fn new(predicate: &FirstOrder) -> Self {
+        let needle = |value: &Operand<Value>| literal(value).as_str().map(str::to_owned);
+        let lowercase_needle = |value: &Operand<Value>| literal(value).as_str().map(lowercase);
+
+        match predicate {
+            FirstOrder::Contains(p) if p.ignore_case => {
+                Leaf::ContainsIgnoreCase(lowercase_needle(&p.value))
+            }
+            FirstOrder::Contains(p) => Leaf::Contains(needle(&p.value)),
+            FirstOrder::Start(p) if p.ignore_case => {
+                Leaf::StartIgnoreCase(lowercase_needle(&p.value))
+            }
+            FirstOrder::Start(p) => Leaf::Start(needle(&p.value)),
+            FirstOrder::End(p) if p.ignore_case => Leaf::EndIgnoreCase(lowercase_needle(&p.value)),
+            FirstOrder::End(p) => Leaf::End(needle(&p.value)),
+            FirstOrder::In(p) if p.ignore_case => {
+                Leaf::InIgnoreCase(lowercase_values(literal(&p.value).as_slice()))
+            }
+            FirstOrder::In(p) => Leaf::In(literal(&p.value).clone()),
+            FirstOrder::Contained(p) if p.ignore_case => {
+                Leaf::ContainedIgnoreCase(lowercase_values(&p.value))
+            }
+            FirstOrder::Contained(p) => Leaf::Contained(p.value.clone()),
+            FirstOrder::Intersects(p) if p.ignore_case => {
+                Leaf::IntersectsIgnoreCase(lowercase_values(&p.value))
+            }
+            FirstOrder::Intersects(p) => Leaf::Intersects(p.value.clone()),
+            FirstOrder::Test(p) if p.ignore_case => {
+                Leaf::TestIgnoreCase(lowercase(&literal(&p.value).to_string()))
+            }
+            FirstOrder::Test(p) => Leaf::Test(literal(&p.value).clone()),
+            FirstOrder::Matches(p) if p.ignore_case => Leaf::Matches(
+                RegexBuilder::new(p.value.as_ref().as_str())
+                    .case_insensitive(true)
+                    .build()
+                    .ok(),
+            ),
+            FirstOrder::Matches(p) => Leaf::Matches(Some(p.value.as_ref().clone())),
+            FirstOrder::Less(p) => Leaf::Less(literal(&p.value).clone()),
+            FirstOrder::More(p) => Leaf::More(literal(&p.value).clone()),
+            FirstOrder::Defined(_) | FirstOrder::Undefined(_) | FirstOrder::Type(_) => {
+                unreachable!("handled by Node")
+            }
+        }
+    }
+
+    fn evaluate(&self, ptr: &Pointer, context_value: &Value) -> Outcome {
+        match self {
+            Leaf::Contains(needle) => with_str(context_value, needle, |context, needle| {
+                context.contains(needle)
+            }),
+            Leaf::ContainsIgnoreCase(needle) => {
+                with_str(context_value, needle, contains_ignore_case)
+            }
+            Leaf::Start(needle) => with_str(context_value, needle, |context, needle| {
+                context.starts_with(needle)
+            }),
+            Leaf::StartIgnoreCase(needle) => {
+                with_str(context_value, needle, starts_with_ignore_case)
+            }
+            Leaf::End(needle) => with_str(context_value, needle, |context, needle| {
+                context.ends_with(needle)
+            }),
+            Leaf::EndIgnoreCase(needle) => with_str(context_value, needle, ends_with_ignore_case),
+            Leaf::In(values) => values.contains(context_value).into(),
+            Leaf::InIgnoreCase(needles) => in_ignore_case(context_value, needles).into(),
+            Leaf::Contained(values) => match context_value {
+                Value::Array(context) => context.iter().all(|x| values.contains(x)).into(),
+                _ => Outcome::False,
+            },
+            Leaf::ContainedIgnoreCase(needles) => match context_value {
+                Value::Array(context) => context.iter().all(|x| in_ignore_case(x, needles)).into(),
+                _ => Outcome::False,
+            },
+            Leaf::Intersects(values) => match context_value {
+                Value::Array(context) => context.iter().any(|x| values.contains(x)).into(),
+                _ => Outcome::False,
+            },
+            Leaf::IntersectsIgnoreCase(needles) => match context_value {
+                Value::Array(context) => context.iter().any(|x| in_ignore_case(x, needles)).into(),
+                _ => Outcome::False,
+            },
+            Leaf::Test(value) => Outcome::from(context_value == value),
+            Leaf::TestIgnoreCase(needle) => eq_ignore_case(context_value, needle).into(),
+            Leaf::Matches(regex) => match (context_value, regex) {
+                (Value::String(context), Some(regex)) => regex.is_match(context).into(),
+                (Value::String(_), None) => ptr.error(PredicateErrorKind::IncorrectType).into(),
+                _ => Outcome::False,
+            },
+            Leaf::Less(value) => {
+                Outcome::from(value.cmp_value(context_value) == Some(Ordering::Less))
+            }
+            Leaf::More(value) => {
+                Outcome::from(value.cmp_value(context_value) == Some(Ordering::Greater))
+            }
+        }
+    }
+}
+
+/// The literal value of an operand which doesn't reference another location.
+fn literal<T>(operand: &Operand<T>) -> &T {
+    operand.literal().expect("handled by Node")
+}
+
+fn with_str(context_value: &Value, needle: &Option<String>, f: fn(&str, &str) -> bool) -> Outcome {
+    match (context_value, needle) {
+        (Value::String(context), Some(needle)) => f(context, needle).into(),
+        _ => Outcome::False,
+    }
+}
+
+fn lowercase(value: &str) -> String {
+    value.to_lowercase()
+}
+
+/// Whether lowercasing `value` character by character gives the same result
+/// as [`str::to_lowercase`], which maps a final capital sigma to "ς" rather
+/// than "σ".
+fn folds_per_char(value: &str) -> bool {
+    !value.contains('Σ')
+}
+
+fn lowercase_values(values: &[Value]) -> Vec<String> {
+    values
+        .iter()
+        .map(|value| lowercase(&value.to_string()))
+        .collect()
+}
+
+/// `needles` must be lowercased string representations.
+fn in_ignore_case(value: &Value, needles: &[String]) -> bool {
+    needles.iter().any(|needle| eq_ignore_case(value, needle))
+}
+
+/// `needle` must already be lowercased.
+fn starts_with_ignore_case(context: &str, needle: &str) -> bool {
+    if !folds_per_char(context) {
+        return context.to_lowercase().starts_with(needle);
+    }
+    let mut context = context.chars().flat_map(char::to_lowercase);
+    needle.chars().all(|c| context.next() == Some(c))
+}
+
+/// `needle` must already be lowercased.
+fn ends_with_ignore_case(context: &str, needle: &str) -> bool {
+    if !folds_per_char(context) {
+        return context.to_lowercase().ends_with(needle);
+    }
+    let mut context = context.chars().flat_map(char::to_lowercase).rev();
+    needle.chars().rev().all(|c| context.next() == Some(c))
+}
+
+/// `needle` must already be lowercased.
+fn contains_ignore_case(context: &str, needle: &str) -> bool {
+    if !folds_per_char(context) {
+        return context.to_lowercase().contains(needle);
+    }
+    needle.is_empty()
+        || context
+            .char_indices()
+            .any(|(idx, _)| starts_with_ignore_case(&context[idx..], needle))
+}
+
+/// Compare the lowercased string representation of `value` with `needle`,
+/// which must already be lowercased, without building the representation.
+fn eq_ignore_case(value: &Value, needle: &str) -> bool {
+    struct Sink<'a> {
+        remaining: &'a str,
+        matches: bool,
+        folds_per_char: bool,
+    }
+
+    impl Write for Sink<'_> {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            // Returning an error to stop early would make the serializer
+            // allocate it, so the remaining output is ignored instead.
+            self.folds_per_char &= folds_per_char(s);
+            if !self.matches || !self.folds_per_char {
+                return Ok(());
+            }
+            for c in s.chars().flat_map(char::to_lowercase) {
+                match self.remaining.strip_prefix(c) {
+                    Some(remaining) => self.remaining = remaining,
+                    None => {
+                        self.matches = false;
+                        break;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    let mut sink = Sink {
+        remaining: needle,
+        matches: true,
+        folds_per_char: true,
+    };
+    let _ = write!(sink, "{}", value);
+    if !sink.folds_per_char {
+        return value.to_string().to_lowercase() == needle;
+    }
+    sink.matches && sink.remaining.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::predicate::compiled::{
+        contains_ignore_case, ends_with_ignore_case, eq_ignore_case, starts_with_ignore_case,
+    };
+
+    #[test]
+    fn ignore_case_test() {
+        assert!(contains_ignore_case("ABC!XYZ", "c!x"));
+        assert!(contains_ignore_case("ABC", ""));
+        assert!(!contains_ignore_case("ABC", "abcd"));
+
+        assert!(starts_with_ignore_case("AbC_123", "abc"));
+        assert!(!starts_with_ignore_case("AbC_123", "bc"));
+
+        assert!(ends_with_ignore_case("XyZ_789", "z_789"));
+        assert!(!ends_with_ignore_case("XyZ_789", "xyz"));
+
+        assert!(eq_ignore_case(&serde_json::json!("ABC"), "\"abc\""));
+        assert!(eq_ignore_case(
+            &serde_json::json!({"A": [1]}),
+            "{\"a\":[1]}"
+        ));
+        assert!(!eq_ignore_case(&serde_json::json!("ABC"), "\"ab\""));
+        assert!(!eq_ignore_case(&serde_json::json!("AB"), "\"abc\""));
+
+        assert!(contains_ignore_case("ΟΔΟΣ", "ος"));
+        assert!(ends_with_ignore_case("ΟΔΟΣ", "ς"));
+        assert!(eq_ignore_case(&serde_json::json!("ΟΔΟΣ"), "\"οδος\""));
+    }
+}
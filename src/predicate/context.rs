@@ -1,12 +1,20 @@
 use std::error::Error;
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
 
 use crate::json_path::{JSONPath, JSONPathError};
 
 /// The context to run the evaluation, you can have an empty context with
 /// `PredicateContext::default()`.
+///
+/// Besides the location the predicate is evaluated from, it binds the named
+/// variables a "value" member can reference as `{"$var": "userId"}`, so that
+/// a single predicate can be evaluated for different users or tenants.
 #[derive(Default, Clone, Debug)]
 pub struct PredicateContext {
     location: Option<JSONPath>,
+    variables: Arc<Map<String, Value>>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -19,15 +27,16 @@ pub enum PredicateContextError {
 
 impl From<JSONPath> for PredicateContext {
     fn from(value: JSONPath) -> Self {
-        Self {
-            location: Some(value),
-        }
+        Self::from(Some(value))
     }
 }
 
 impl From<Option<JSONPath>> for PredicateContext {
     fn from(value: Option<JSONPath>) -> Self {
-        Self { location: value }
+        Self {
+            location: value,
+            variables: Arc::default(),
+        }
     }
 }
 
@@ -35,9 +44,27 @@ impl PredicateContext {
     pub fn new(path: String) -> Result<Self, PredicateContextError> {
         let location = JSONPath::new(path)?;
 
-        Ok(Self {
-            location: Some(location),
-        })
+        Ok(Self::from(location))
+    }
+
+    /// Bind the variable `name` to `value`, replacing any previous binding.
+    pub fn with_variable(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        Arc::make_mut(&mut self.variables).insert(name.into(), value.into());
+        self
+    }
+
+    /// The value bound to the variable `name`.
+    pub fn variable(&self, name: &str) -> Option<&Value> {
+        self.variables.get(name)
+    }
+
+    /// The context of the subordinate predicates of a Second Order predicate
+    /// evaluated from `location`, the variables being kept.
+    pub(crate) fn scoped(&self, location: Option<JSONPath>) -> Self {
+        Self {
+            location,
+            variables: Arc::clone(&self.variables),
+        }
     }
 
     pub fn final_path(&self, path: &Option<JSONPath>) -> Option<JSONPath> {
@@ -0,0 +1,200 @@
+//! What the conversions between predicates and the queries of other systems,
+//! like MongoDB filters or JsonLogic rules, have in common.
+//!
+//! A predicate which can't be evaluated, e.g. because it references a value
+//! that does not exist, holds for no document even when it's negated. So a
+//! predicate isn't converted as a query and a negation of it: negations are
+//! pushed down to the First Order predicates, which are converted as negated
+//! when they must evaluate as "false", and then also require the referenced
+//! value to exist. The other way around, the predicates converted from a
+//! negation are guarded with "defined", see [`guarded`].
+
+use crate::json_path::JSONPath;
+
+use super::context::PredicateContext;
+use super::first_order::{defined::Defined, undefined::Undefined, FirstOrder};
+use super::operand::Reference;
+use super::second_order::{and::And, not::Not, or::Or, SecondOrder};
+use super::Predicate;
+
+/// The queries of a system predicates are converted into.
+pub(crate) trait Target {
+    /// A query, e.g. a MongoDB filter.
+    type Query;
+    /// The error reported when a predicate has no equivalent query.
+    type Error;
+
+    /// The query matching every document when `value` is true, and none
+    /// otherwise.
+    fn constant(value: bool) -> Self::Query;
+
+    /// Combine the queries, `queries` not being empty, into one matching the
+    /// documents all of them match when `all`, or any of them otherwise.
+    fn combine(queries: Vec<Self::Query>, all: bool) -> Self::Query;
+
+    /// The query matching the documents `first_order` evaluates as "true"
+    /// for, or as "false" for when `negated`, the element referenced by
+    /// `path`, resolved from `ctx`, being tested.
+    ///
+    /// Quantifiers and "value" members referencing another location have
+    /// already been reported as unsupported.
+    fn first_order(
+        first_order: &FirstOrder,
+        path: &JSONPath,
+        ctx: &PredicateContext,
+        negated: bool,
+    ) -> Result<Self::Query, &'static str>;
+
+    /// The error of the predicate `op`, at `location` in the predicate
+    /// document, which has no equivalent query for `reason`.
+    fn unsupported(op: &str, location: JSONPath, reason: &'static str) -> Self::Error;
+}
+
+/// The query matching the documents `predicate` evaluates as "true" for, or
+/// as "false" for when `negated`, at `location` in the predicate document.
+pub(crate) fn to_query<T: Target>(
+    predicate: &Predicate,
+    ctx: &PredicateContext,
+    negated: bool,
+    location: &mut Vec<String>,
+) -> Result<T::Query, T::Error> {
+    let unsupported = |op: &str, location: &[String], reason| {
+        T::unsupported(op, JSONPath::from_tokens(location.to_vec()), reason)
+    };
+
+    match predicate {
+        Predicate::FirstOrder(first_order) => to_condition::<T>(first_order, ctx, negated)
+            .map_err(|reason| unsupported(first_order.op(), location, reason)),
+        Predicate::Extension(extension) => Err(unsupported(
+            extension.op(),
+            location,
+            "the operation is registered by the application",
+        )),
+        Predicate::SecondOrder(second_order) => {
+            // An empty combination holds whatever the document.
+            if second_order.apply().is_empty() {
+                return Ok(T::constant(!negated));
+            }
+
+            // "and" is false when any of its predicates is, "not" is true when
+            // all of its predicates are false and false when any is true.
+            let (all, negate) = match second_order {
+                SecondOrder::And(_) => (!negated, negated),
+                SecondOrder::Or(_) => (negated, negated),
+                SecondOrder::Not(_) => (!negated, !negated),
+            };
+
+            let ctx = ctx.scoped(ctx.final_path(&second_order.path().cloned()));
+            let mut queries = Vec::with_capacity(second_order.apply().len());
+            for (index, predicate) in second_order.apply().iter().enumerate() {
+                location.extend(["apply".to_string(), index.to_string()]);
+                let query = to_query::<T>(predicate, &ctx, negate, location);
+                location.truncate(location.len() - 2);
+                queries.push(query?);
+            }
+
+            Ok(T::combine(queries, all))
+        }
+    }
+}
+
+/// The query of a First Order predicate, see [`to_query`].
+fn to_condition<T: Target>(
+    first_order: &FirstOrder,
+    ctx: &PredicateContext,
+    negated: bool,
+) -> Result<T::Query, &'static str> {
+    if first_order.quantifier().is_some() {
+        return Err("quantifiers aren't supported");
+    }
+    if let Some(Reference::Path(_)) = first_order.value_reference() {
+        return Err("a \"value\" member referencing another location isn't supported");
+    }
+
+    let path = ctx
+        .final_path(&first_order.path().cloned())
+        .unwrap_or(JSONPath::empty());
+    T::first_order(first_order, &path, ctx, negated)
+}
+
+/// The value of an [`Operand`](super::operand::Operand) bound in `ctx`, or an
+/// early return of the query matching no document from
+/// [`Target::first_order`]: like when evaluating the predicate, a variable
+/// which isn't bound or isn't of the expected type is reported whatever the
+/// document.
+macro_rules! bound {
+    ($ctx:expr, $operand:expr) => {
+        match $operand.bound($ctx) {
+            Some(value) => value,
+            None => return Ok(Self::constant(false)),
+        }
+    };
+}
+
+pub(crate) use bound;
+
+/// `predicate`, which can't be evaluated when the value at `path` is
+/// missing, guarded with "defined" when `negated`.
+pub(crate) fn guarded(path: &JSONPath, predicate: Predicate, negated: bool) -> Predicate {
+    if !negated {
+        return predicate;
+    }
+
+    let defined = FirstOrder::Defined(Defined {
+        path: Some(path.clone()),
+        quantifier: None,
+    });
+    SecondOrder::And(And {
+        path: None,
+        apply: vec![defined.into(), predicate],
+    })
+    .into()
+}
+
+/// `predicate`, also holding when the value at `path` is missing.
+pub(crate) fn or_undefined(path: &JSONPath, predicate: Predicate) -> Predicate {
+    let undefined = FirstOrder::Undefined(Undefined {
+        path: Some(path.clone()),
+        quantifier: None,
+    });
+    SecondOrder::Or(Or {
+        path: None,
+        apply: vec![undefined.into(), predicate],
+    })
+    .into()
+}
+
+/// A predicate holding whatever the document when `value` is true, and never
+/// otherwise.
+pub(crate) fn constant(value: bool) -> Predicate {
+    let and: Predicate = SecondOrder::And(And {
+        path: None,
+        apply: Vec::new(),
+    })
+    .into();
+
+    match value {
+        true => and,
+        false => SecondOrder::Not(Not {
+            path: None,
+            apply: vec![and],
+        })
+        .into(),
+    }
+}
+
+/// The predicate holding when all of `apply` do.
+pub(crate) fn all(mut apply: Vec<Predicate>) -> Predicate {
+    match apply.len() {
+        1 => apply.remove(0),
+        _ => SecondOrder::And(And { path: None, apply }).into(),
+    }
+}
+
+/// The predicate holding when any of `apply` does, `apply` not being empty.
+pub(crate) fn any(mut apply: Vec<Predicate>) -> Predicate {
+    match apply.len() {
+        1 => apply.remove(0),
+        _ => SecondOrder::Or(Or { path: None, apply }).into(),
+    }
+}
@@ -0,0 +1,432 @@
+//! Deserialization of predicates, dispatched on their "op" member.
+//!
+//! The predicate object is buffered and its "op" member picks the type it's
+//! deserialized as, so that errors name the unknown operation, the missing
+//! member or the member of the wrong type, with its JSON Pointer in the
+//! predicate document, e.g. "value missing for op `contains` at /apply/1".
+
+use std::marker::PhantomData;
+
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{
+    self, Deserialize, Deserializer, EnumAccess, Error, IntoDeserializer, Unexpected,
+    VariantAccess, Visitor,
+};
+use serde_json::{Map, Value};
+
+use crate::json_path::JSONPath;
+
+use super::error::{DeserializeError, DeserializeErrorKind};
+use super::first_order::{
+    contained::Contained, contains::Contains, defined::Defined, end::End, intersects::Intersects,
+    less::Less, matches::Matches, more::More, r#in::In, r#type::Type, start::Start, test::Test,
+    undefined::Undefined, FirstOrder,
+};
+use super::quantifier::Quantifier;
+use super::registry::Extension;
+use super::second_order::{and::And, not::Not, or::Or, SecondOrder};
+use super::Predicate;
+
+/// The members an operation of the specification requires besides "op".
+enum Members {
+    /// "path", and "value" when `value` is set.
+    FirstOrder { value: bool },
+    /// "apply".
+    SecondOrder,
+}
+
+impl Members {
+    /// `None` for an operation which isn't part of the specification.
+    fn of(op: &str) -> Option<Self> {
+        match op {
+            "defined" | "undefined" => Some(Members::FirstOrder { value: false }),
+            "contains" | "contains-" | "start" | "start-" | "end" | "end-" | "type" | "in"
+            | "in-" | "test" | "test-" | "matches" | "matches-" | "less" | "more" | "contained"
+            | "contained-" | "intersects" | "intersects-" => {
+                Some(Members::FirstOrder { value: true })
+            }
+            "and" | "or" | "not" => Some(Members::SecondOrder),
+            _ => None,
+        }
+    }
+}
+
+/// `value` wasn't of the `expected` type.
+fn invalid_type(value: &Value, expected: &str) -> DeserializeError {
+    DeserializeError::invalid_type(unexpected(value), &expected)
+}
+
+/// The `value` as reported in the errors.
+fn unexpected(value: &Value) -> Unexpected<'_> {
+    match value {
+        Value::Null => Unexpected::Unit,
+        Value::Bool(value) => Unexpected::Bool(*value),
+        Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(value), _) => Unexpected::Unsigned(value),
+            (None, Some(value)) => Unexpected::Signed(value),
+            (None, None) => Unexpected::Float(number.as_f64().unwrap_or_default()),
+        },
+        Value::String(value) => Unexpected::Str(value),
+        Value::Array(_) => Unexpected::Seq,
+        Value::Object(_) => Unexpected::Map,
+    }
+}
+
+/// `member` is missing for the operation `op`.
+fn missing(member: &str, op: &str) -> DeserializeError {
+    DeserializeError::new(
+        DeserializeErrorKind::MissingMember {
+            member: member.to_string(),
+        },
+        format_args!("{member} missing for op `{op}`"),
+    )
+}
+
+/// Deserialize the optional `member` of `map`.
+fn member<T>(
+    map: &Map<String, Value>,
+    member: &str,
+    location: &str,
+) -> Result<Option<T>, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    match map.get(member) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => T::deserialize(ValueDeserializer::<DeserializeError>::new(value))
+            .map(Some)
+            .map_err(|err| err.at(&format!("{location}/{member}"))),
+    }
+}
+
+/// Deserialize the predicate `value`, found at `location` in the predicate
+/// document.
+fn predicate(value: &Value, location: &str) -> Result<Predicate, DeserializeError> {
+    let Value::Object(map) = value else {
+        return Err(invalid_type(value, "a predicate object").at(location));
+    };
+
+    let op = match map.get("op") {
+        Some(Value::String(op)) => op,
+        Some(op) => return Err(invalid_type(op, "a string").at(&format!("{location}/op"))),
+        None => {
+            let kind = DeserializeErrorKind::MissingMember {
+                member: "op".to_string(),
+            };
+            return Err(DeserializeError::new(kind, "op missing").at(location));
+        }
+    };
+
+    let Some(members) = Members::of(op) else {
+        return Extension::resolve(value)
+            .map(Predicate::Extension)
+            .map_err(|err| err.at(location));
+    };
+
+    match members {
+        Members::FirstOrder { value: has_value } => {
+            if !map.contains_key("path") {
+                return Err(missing("path", op).at(location));
+            }
+            if has_value && !map.contains_key("value") {
+                return Err(missing("value", op).at(location));
+            }
+            member::<JSONPath>(map, "path", location)?;
+            member::<Quantifier>(map, "quantifier", location)?;
+
+            // Only the "value" member can be wrong at this point.
+            first_order(op, ValueDeserializer::new(value))
+                .map(Predicate::FirstOrder)
+                .map_err(|err| err.at(&format!("{location}/value")))
+        }
+        Members::SecondOrder => {
+            let apply = match map.get("apply") {
+                Some(Value::Array(apply)) => apply,
+                Some(apply) => {
+                    return Err(invalid_type(apply, "an array of predicates")
+                        .at(&format!("{location}/apply")))
+                }
+                None => return Err(missing("apply", op).at(location)),
+            };
+            let apply = apply
+                .iter()
+                .enumerate()
+                .map(|(index, value)| predicate(value, &format!("{location}/apply/{index}")))
+                .collect::<Result<Vec<_>, _>>()?;
+            let path = member::<JSONPath>(map, "path", location)?;
+
+            let second_order = match op.as_str() {
+                "and" => SecondOrder::And(And { path, apply }),
+                "or" => SecondOrder::Or(Or { path, apply }),
+                _ => SecondOrder::Not(Not { path, apply }),
+            };
+            Ok(Predicate::SecondOrder(second_order))
+        }
+    }
+}
+
+/// Deserialize the First Order predicate `value` whose "op" member is `op`.
+fn first_order(
+    op: &str,
+    value: ValueDeserializer<'_, DeserializeError>,
+) -> Result<FirstOrder, DeserializeError> {
+    match op {
+        "contains" | "contains-" => Contains::deserialize(value).map(FirstOrder::Contains),
+        "defined" => Defined::deserialize(value).map(FirstOrder::Defined),
+        "undefined" => Undefined::deserialize(value).map(FirstOrder::Undefined),
+        "start" | "start-" => Start::deserialize(value).map(FirstOrder::Start),
+        "end" | "end-" => End::deserialize(value).map(FirstOrder::End),
+        "type" => Type::deserialize(value).map(FirstOrder::Type),
+        "in" | "in-" => In::deserialize(value).map(FirstOrder::In),
+        "test" | "test-" => Test::deserialize(value).map(FirstOrder::Test),
+        "matches" | "matches-" => Matches::deserialize(value).map(FirstOrder::Matches),
+        "less" => Less::deserialize(value).map(FirstOrder::Less),
+        "more" => More::deserialize(value).map(FirstOrder::More),
+        "contained" | "contained-" => Contained::deserialize(value).map(FirstOrder::Contained),
+        _ => Intersects::deserialize(value).map(FirstOrder::Intersects),
+    }
+}
+
+impl Predicate {
+    /// Deserialize the predicate object `value`, with an error telling what
+    /// was wrong and where.
+    ///
+    /// ```rust
+    /// # use json_predicate::{DeserializeErrorKind, Predicate};
+    /// let err = Predicate::from_value(&serde_json::json!({
+    ///     "op": "and",
+    ///     "apply": [{ "op": "contains", "path": "/a" }]
+    /// }))
+    /// .unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     &DeserializeErrorKind::MissingMember { member: "value".to_string() }
+    /// );
+    /// assert_eq!(err.location(), "/apply/0");
+    /// ```
+    pub fn from_value(value: &Value) -> Result<Self, DeserializeError> {
+        predicate(value, "")
+    }
+}
+
+/// The operations registered in an [`OperatorRegistry`] are only resolved when
+/// the predicate is deserialized through [`OperatorRegistry::deserialize`],
+/// which makes the registry available on the calling thread for the duration
+/// of the call. Otherwise, they're reported as unknown operations.
+///
+/// [`OperatorRegistry`]: super::registry::OperatorRegistry
+/// [`OperatorRegistry::deserialize`]: super::registry::OperatorRegistry::deserialize
+impl<'de> Deserialize<'de> for Predicate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        predicate(&value, "").map_err(D::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for FirstOrder {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Predicate::deserialize(deserializer)? {
+            Predicate::FirstOrder(first_order) => Ok(first_order),
+            other => Err(D::Error::custom(format!(
+                "op `{}` isn't a first order operation",
+                other.op()
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SecondOrder {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Predicate::deserialize(deserializer)? {
+            Predicate::SecondOrder(second_order) => Ok(second_order),
+            other => Err(D::Error::custom(format!(
+                "op `{}` isn't a second order operation",
+                other.op()
+            ))),
+        }
+    }
+}
+
+/// Deserializes a borrowed [`Value`] with the error type `E`, so that the
+/// errors of the types deserialized from it keep their kind, unlike with
+/// [`serde_json::Error`].
+pub(crate) struct ValueDeserializer<'a, E> {
+    value: &'a Value,
+    error: PhantomData<E>,
+}
+
+impl<'a, E> ValueDeserializer<'a, E> {
+    pub(crate) fn new(value: &'a Value) -> Self {
+        Self {
+            value,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for ValueDeserializer<'de, E> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for ValueDeserializer<'de, E> {
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+                (Some(value), _) => visitor.visit_u64(value),
+                (None, Some(value)) => visitor.visit_i64(value),
+                (None, None) => visitor.visit_f64(number.as_f64().unwrap_or_default()),
+            },
+            Value::String(value) => visitor.visit_borrowed_str(value),
+            Value::Array(values) => {
+                let mut seq = SeqDeserializer::new(values.iter().map(Self::new));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Value::Object(map) => {
+                let mut map = MapDeserializer::new(
+                    map.iter()
+                        .map(|(key, value)| (key.as_str(), Self::new(value))),
+                );
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        // Either `"variant"` or `{ "variant": content }`.
+        let (variant, content) = match self.value {
+            Value::String(variant) => (variant, None),
+            Value::Object(map) if map.len() == 1 => match map.iter().next() {
+                Some((variant, content)) => (variant, Some(content)),
+                None => unreachable!(),
+            },
+            value => return Err(E::invalid_type(unexpected(value), &"a string or a map")),
+        };
+        visitor.visit_enum(Variant {
+            variant,
+            content,
+            error: PhantomData,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// The variant of an enum, and its content unless it's a unit variant.
+struct Variant<'a, E> {
+    variant: &'a str,
+    content: Option<&'a Value>,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> EnumAccess<'de> for Variant<'de, E> {
+    type Error = E;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), E>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, E: de::Error> VariantAccess<'de> for Variant<'de, E> {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.content {
+            None | Some(Value::Null) => Ok(()),
+            Some(content) => Err(E::invalid_type(unexpected(content), &"a unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, E>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        match self.content {
+            Some(content) => seed.deserialize(ValueDeserializer::new(content)),
+            None => Err(E::invalid_type(
+                Unexpected::UnitVariant,
+                &"a newtype variant",
+            )),
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Some(content) => ValueDeserializer::new(content).deserialize_seq(visitor),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"a tuple variant")),
+        }
+    }
+
+    fn struct_variant<V>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Some(content) => ValueDeserializer::new(content).deserialize_map(visitor),
+            None => Err(E::invalid_type(
+                Unexpected::UnitVariant,
+                &"a struct variant",
+            )),
+        }
+    }
+}
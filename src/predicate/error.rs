@@ -1,53 +1,65 @@
 use std::fmt::Display;
 
 use json_pointer::IndexError;
+use serde::de::{Expected, Unexpected};
 
-#[derive(Debug)]
+use crate::json_path::JSONPath;
+
+/// The error returned when a predicate couldn't be evaluated against a
+/// document.
+///
+/// Besides its [`PredicateErrorKind`], it carries the name of the operation
+/// and the absolute JSON Pointer which caused the error.
+#[derive(Debug, Clone)]
 pub struct PredicateError {
     kind: PredicateErrorKind,
+    op: &'static str,
+    path: JSONPath,
 }
 
 impl PredicateError {
-    pub fn unimplemented() -> Self {
-        Self {
-            kind: PredicateErrorKind::Unimplemented,
-        }
+    pub(crate) fn new(kind: PredicateErrorKind, op: &'static str, path: JSONPath) -> Self {
+        Self { kind, op, path }
     }
-    pub fn ty_issue() -> Self {
-        Self {
-            kind: PredicateErrorKind::IncorrectType,
-        }
+
+    /// The reason why the predicate couldn't be evaluated.
+    pub fn kind(&self) -> &PredicateErrorKind {
+        &self.kind
+    }
+
+    /// The "op" member of the predicate which couldn't be evaluated.
+    pub fn op(&self) -> &'static str {
+        self.op
+    }
+
+    /// The absolute JSON Pointer, resolved from the context, of the
+    /// predicate which couldn't be evaluated.
+    pub fn path(&self) -> &JSONPath {
+        &self.path
     }
 }
 
-#[derive(Debug)]
-enum PredicateErrorKind {
-    Unimplemented,
-    /// The Predicate Object specifies an unknown predicate operation.
-    #[allow(dead_code)]
-    UnknownPredicateOperation,
+/// The reason why a predicate couldn't be evaluated.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PredicateErrorKind {
     /// The Predicate Object specifies a JSON Pointer referencing a value that
     /// does not exist and the specified Predicate operation is not
     /// specifically intended to test for the absence of a value
     /// (i.e. the "undefined" and "defined" predicates)
-    JsonPointerIssue {
-        issue: IndexError,
-    },
-    /// A First Order Predicate Object specifies a predicate operation that
-    /// requires a "value" member providing the condition to test but no
-    /// "value" member is provided.
-    #[allow(dead_code)]
-    NoValueMember,
+    JsonPointerIssue { issue: IndexError },
     /// The "value" member given for a given predicate operation is of an
     /// unexpected or unsupported type for that operation
-    /// (e.g. specifying a string value for the "more" and "less" predicate operations).
+    /// (e.g. a "matches-" pattern which isn't a valid regular expression).
     IncorrectType,
+    /// The "value" member references a variable which isn't bound in the
+    /// [`PredicateContext`](crate::context::PredicateContext).
+    UndefinedVariable { name: String },
 }
 
 impl Display for PredicateErrorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::UnknownPredicateOperation { .. } => f.write_str("UnknownPredicateOperation"),
             Self::JsonPointerIssue { issue } => match issue {
                 IndexError::NoSuchKey(key) => f.write_fmt(format_args!(
                     "The pointer pointed to a nonexistent key, pointed key: {}",
@@ -61,9 +73,12 @@ impl Display for PredicateErrorKind {
                     idx
                 )),
             },
-            Self::NoValueMember { .. } => f.write_str("NoValueMember"),
-            Self::IncorrectType { .. } => f.write_str("IncorrectType"),
-            Self::Unimplemented { .. } => f.write_str("Unimplemented"),
+            Self::IncorrectType => f.write_str(
+                "The \"value\" member is of an unexpected or unsupported type for the operation",
+            ),
+            Self::UndefinedVariable { name } => {
+                f.write_fmt(format_args!("The variable \"{}\" isn't defined", name))
+            }
         }
     }
 }
@@ -72,14 +87,110 @@ impl std::error::Error for PredicateError {}
 
 impl Display for PredicateError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{}", self.kind))
+        f.write_fmt(format_args!(
+            "\"{}\" predicate at \"{}\": {}",
+            self.op, self.path, self.kind
+        ))
     }
 }
 
-impl From<IndexError> for PredicateError {
+impl From<IndexError> for PredicateErrorKind {
     fn from(value: IndexError) -> Self {
-        PredicateError {
-            kind: PredicateErrorKind::JsonPointerIssue { issue: value },
+        PredicateErrorKind::JsonPointerIssue { issue: value }
+    }
+}
+
+/// The error returned when a predicate object couldn't be deserialized.
+///
+/// Besides its [`DeserializeErrorKind`], it carries the JSON Pointer, in the
+/// predicate document, of the value which caused the error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeserializeError {
+    kind: DeserializeErrorKind,
+    message: String,
+    location: String,
+}
+
+impl DeserializeError {
+    pub(crate) fn new(kind: DeserializeErrorKind, message: impl Display) -> Self {
+        Self {
+            kind,
+            message: message.to_string(),
+            location: String::new(),
+        }
+    }
+
+    /// The error reported in the value found at `location`, which is
+    /// prepended to its own location.
+    pub(crate) fn at(mut self, location: &str) -> Self {
+        self.location.insert_str(0, location);
+        self
+    }
+
+    /// The reason why the predicate couldn't be deserialized.
+    pub fn kind(&self) -> &DeserializeErrorKind {
+        &self.kind
+    }
+
+    /// What was wrong.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The JSON Pointer, in the predicate document, of the value which
+    /// caused the error, empty for the whole document.
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+}
+
+/// The reason why a predicate object couldn't be deserialized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeserializeErrorKind {
+    /// The Predicate Object specifies an unknown predicate operation, the
+    /// UnknownPredicateOperation error of the specification.
+    UnknownOperation { op: String },
+    /// A member required by the operation is missing, e.g. the "value"
+    /// member, the NoValueMember error of the specification.
+    MissingMember { member: String },
+    /// A member is of an unexpected type for the operation.
+    InvalidType,
+    /// A member is of the expected type but its value is invalid (e.g. a
+    /// JSON Pointer without a leading slash).
+    InvalidValue,
+}
+
+impl std::error::Error for DeserializeError {}
+
+impl Display for DeserializeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.location.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            f.write_fmt(format_args!("{} at {}", self.message, self.location))
         }
     }
 }
+
+impl serde::de::Error for DeserializeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(DeserializeErrorKind::InvalidValue, msg)
+    }
+
+    fn invalid_type(unexp: Unexpected, exp: &dyn Expected) -> Self {
+        Self::new(
+            DeserializeErrorKind::InvalidType,
+            format_args!("invalid type: {unexp}, expected {exp}"),
+        )
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::new(
+            DeserializeErrorKind::MissingMember {
+                member: field.to_string(),
+            },
+            format_args!("missing field `{field}`"),
+        )
+    }
+}
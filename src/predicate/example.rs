@@ -0,0 +1,483 @@
+//! Generation of documents for which a predicate evaluates as "true" or
+//! "false".
+//!
+//! The predicate is turned into alternatives, each a list of First Order
+//! predicates on fixed paths which must hold or fail, "not" predicates being
+//! pushed down to them. A value is picked for each path among the ones derived
+//! from its predicates, the document is assembled from them and the first one
+//! the whole predicate evaluates as expected against is kept.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+use crate::comparable::Comparable;
+use crate::json_path::JSONPath;
+
+use super::context::PredicateContext;
+use super::first_order::FirstOrder;
+use super::operand::Operand;
+use super::outcome::Outcome;
+use super::quantifier::Quantifier;
+use super::second_order::SecondOrder;
+use super::{Predicate, PredicateImpl};
+
+/// The maximum number of alternatives tried.
+const ALTERNATIVES: usize = 64;
+
+impl Predicate {
+    /// A minimal document for which the predicate evaluates as "true", `None`
+    /// if none was found.
+    ///
+    /// Pointers become nested objects, and arrays for their numeric tokens,
+    /// and values are derived from the "value" members, e.g. the number
+    /// between the bounds of a "more" and a "less" predicate. Predicates
+    /// comparing with another location or a variable, and operations
+    /// registered in an [`OperatorRegistry`](super::registry::OperatorRegistry)
+    /// are only satisfied by chance.
+    pub fn example(&self) -> Option<Value> {
+        self.generate(true)
+    }
+
+    /// A minimal document for which the predicate evaluates as "false", and
+    /// not as an error, `None` if none was found. See [`Predicate::example`].
+    pub fn counterexample(&self) -> Option<Value> {
+        self.generate(false)
+    }
+
+    fn generate(&self, holds: bool) -> Option<Value> {
+        alternatives(self, &PredicateContext::default(), holds)
+            .iter()
+            .filter_map(|literals| document(literals))
+            .find(|document| {
+                matches!(
+                    (self.evaluate(document, PredicateContext::default()), holds),
+                    (Outcome::True, true) | (Outcome::False, false)
+                )
+            })
+    }
+}
+
+/// A First Order predicate which must hold or fail, its "path" member being
+/// absolute and without any wildcard.
+#[derive(Clone)]
+struct Literal {
+    first_order: FirstOrder,
+    path: JSONPath,
+    holds: bool,
+}
+
+impl Literal {
+    fn new(first_order: &FirstOrder, ctx: &PredicateContext, holds: bool) -> Self {
+        let mut first_order = first_order.clone();
+        let path = ctx
+            .final_path(&first_order.path().cloned())
+            .unwrap_or(JSONPath::empty());
+
+        // A single match stands for every value matched by the wildcards.
+        let (path, holds) = match first_order.quantifier_mut().take() {
+            Some(quantifier) => {
+                let tokens = path
+                    .tokens()
+                    .into_iter()
+                    .filter(|token| token != "**")
+                    .map(|token| if token == "*" { "0".to_string() } else { token })
+                    .collect();
+                let holds = holds != (quantifier == Quantifier::None);
+                (JSONPath::from_tokens(tokens), holds)
+            }
+            None => (path, holds),
+        };
+        *first_order.path_mut() = Some(path.clone());
+        if let Some(value_path) = first_order.value_path_mut() {
+            if let Some(absolute) = ctx.final_path(&Some(value_path.clone())) {
+                *value_path = absolute;
+            }
+        }
+
+        Literal {
+            first_order,
+            path,
+            holds,
+        }
+    }
+
+    /// Whether the predicate evaluates as expected when the value at its path
+    /// in `document` is `value`, `None` for a missing one.
+    fn accepts(&self, document: &Value, value: Option<&Value>) -> bool {
+        let mut document = document.clone();
+        match value {
+            Some(value) => set(&mut document, &self.path.tokens(), value.clone()),
+            None => remove(&mut document, &self.path.tokens()),
+        }
+
+        matches!(
+            (
+                self.first_order
+                    .evaluate(&document, PredicateContext::default()),
+                self.holds
+            ),
+            (Outcome::True, true) | (Outcome::False, false)
+        )
+    }
+
+    /// The "value" member of the predicate, resolved against `document` when
+    /// it references another location.
+    fn operand(&self, document: &Value) -> Option<Value> {
+        fn resolve<T: Serialize>(operand: &Operand<T>, document: &Value) -> Option<Value> {
+            match operand {
+                Operand::Value(value) => serde_json::to_value(value).ok(),
+                Operand::Path(path) => path.get(document).ok().cloned(),
+                Operand::Var(_) => None,
+            }
+        }
+
+        match &self.first_order {
+            FirstOrder::Test(test) => resolve(&test.value, document),
+            FirstOrder::In(r#in) => resolve(&r#in.value, document),
+            FirstOrder::Contains(contains) => resolve(&contains.value, document),
+            FirstOrder::Start(start) => resolve(&start.value, document),
+            FirstOrder::End(end) => resolve(&end.value, document),
+            FirstOrder::Less(less) => resolve(&less.value, document),
+            FirstOrder::More(more) => resolve(&more.value, document),
+            _ => None,
+        }
+    }
+
+    /// The values derived from the "value" member of the predicate.
+    fn candidates(&self, document: &Value, candidates: &mut Vec<Value>) {
+        let operand = self.operand(document);
+        let comparable = || {
+            operand
+                .clone()
+                .and_then(|operand| Comparable::deserialize(operand).ok())
+        };
+
+        if !self.holds {
+            if let FirstOrder::Less(_) | FirstOrder::More(_) = &self.first_order {
+                candidates.extend(operand);
+            }
+            return;
+        }
+
+        match &self.first_order {
+            FirstOrder::Test(_)
+            | FirstOrder::Contains(_)
+            | FirstOrder::Start(_)
+            | FirstOrder::End(_) => candidates.extend(operand),
+            FirstOrder::In(_) => {
+                if let Some(Value::Array(values)) = operand {
+                    candidates.extend(values);
+                }
+            }
+            FirstOrder::Matches(matches) => {
+                candidates.push(Value::String(pattern_text(matches.value.as_ref().as_str())))
+            }
+            FirstOrder::Type(ty) => candidates.extend(sample(&ty.value)),
+            FirstOrder::Less(_) => {
+                candidates.extend(comparable().and_then(|value| shifted(&value, false)))
+            }
+            FirstOrder::More(_) => {
+                candidates.extend(comparable().and_then(|value| shifted(&value, true)))
+            }
+            FirstOrder::Contained(_) => candidates.push(Value::Array(Vec::new())),
+            FirstOrder::Intersects(intersects) => candidates.extend(
+                intersects
+                    .value
+                    .first()
+                    .map(|value| Value::Array(vec![value.clone()])),
+            ),
+            FirstOrder::Defined(_) | FirstOrder::Undefined(_) => {}
+        }
+    }
+}
+
+/// The alternatives for which `predicate`, evaluated from `ctx`, holds or
+/// fails.
+fn alternatives(predicate: &Predicate, ctx: &PredicateContext, holds: bool) -> Vec<Vec<Literal>> {
+    let second_order = match predicate {
+        Predicate::FirstOrder(first_order) => {
+            return vec![vec![Literal::new(first_order, ctx, holds)]]
+        }
+        Predicate::Extension(_) => return vec![Vec::new()],
+        Predicate::SecondOrder(second_order) => second_order,
+    };
+
+    // A Second Order predicate without any subordinate predicate always
+    // holds.
+    if second_order.apply().is_empty() {
+        return if holds { vec![Vec::new()] } else { Vec::new() };
+    }
+
+    // Either every subordinate predicate must hold or fail, or one of them is
+    // enough.
+    let (every, subordinate_holds) = match second_order {
+        SecondOrder::And(_) => (holds, holds),
+        SecondOrder::Or(_) => (!holds, holds),
+        SecondOrder::Not(_) => (holds, !holds),
+    };
+    let ctx = ctx.scoped(ctx.final_path(&second_order.path().cloned()));
+    let subordinates = second_order
+        .apply()
+        .iter()
+        .map(|predicate| alternatives(predicate, &ctx, subordinate_holds));
+
+    if every {
+        subordinates.fold(vec![Vec::new()], |alternatives, subordinate| {
+            alternatives
+                .iter()
+                .flat_map(|alternative| {
+                    subordinate.iter().map(move |literals| {
+                        let mut alternative = alternative.clone();
+                        alternative.extend(literals.iter().cloned());
+                        alternative
+                    })
+                })
+                .take(ALTERNATIVES)
+                .collect()
+        })
+    } else {
+        subordinates.flatten().take(ALTERNATIVES).collect()
+    }
+}
+
+/// The document for which every predicate of `literals` evaluates as
+/// expected, if their paths can each be given a value.
+fn document(literals: &[Literal]) -> Option<Value> {
+    let mut paths: Vec<&JSONPath> = Vec::new();
+    for literal in literals {
+        if !paths.contains(&&literal.path) {
+            paths.push(&literal.path);
+        }
+    }
+    // Containers are created before the values they hold, and referenced
+    // locations before the values compared with them.
+    let references = |path: &JSONPath| {
+        literals
+            .iter()
+            .any(|l| &l.path == path && l.first_order.value_reference().is_some())
+    };
+    paths.sort_by_key(|path| (references(path), path.tokens().len()));
+
+    let mut document = Value::Object(Map::new());
+    for path in paths {
+        let literals: Vec<&Literal> = literals.iter().filter(|l| &l.path == path).collect();
+        match value(&literals, &document)? {
+            Some(value) => set(&mut document, &path.tokens(), value),
+            None => remove(&mut document, &path.tokens()),
+        }
+    }
+
+    Some(document)
+}
+
+/// The value for which every predicate of `literals`, on the same path,
+/// evaluates as expected against `document`, `Some(None)` when the path must
+/// be missing.
+fn value(literals: &[&Literal], document: &Value) -> Option<Option<Value>> {
+    let mut candidates = Vec::new();
+    for literal in literals {
+        literal.candidates(document, &mut candidates);
+    }
+    candidates.extend(combined(literals));
+
+    let accepted = |value: Option<&Value>| literals.iter().all(|l| l.accepts(document, value));
+    if let Some(value) = candidates.into_iter().find(|value| accepted(Some(value))) {
+        return Some(Some(value));
+    }
+    if accepted(None) {
+        return Some(None);
+    }
+
+    [
+        Value::Null,
+        Value::Bool(true),
+        Value::from(0),
+        Value::String(String::new()),
+        Value::Array(Vec::new()),
+        Value::Object(Map::new()),
+    ]
+    .into_iter()
+    .find(|value| accepted(Some(value)))
+    .map(Some)
+}
+
+/// The values derived from the "value" members of several predicates which
+/// must all hold: a string with every prefix, substring and suffix, and the
+/// number between the bounds.
+fn combined(literals: &[&Literal]) -> Vec<Value> {
+    let mut candidates = Vec::new();
+    let holding = || literals.iter().filter(|l| l.holds).map(|l| &l.first_order);
+
+    let string = |first_order: &FirstOrder| {
+        let value = match first_order {
+            FirstOrder::Start(start) => start.value.literal(),
+            FirstOrder::Contains(contains) => contains.value.literal(),
+            FirstOrder::End(end) => end.value.literal(),
+            _ => None,
+        };
+        value.and_then(Value::as_str).map(str::to_string)
+    };
+    let parts: Vec<String> = holding()
+        .filter(|f| matches!(f, FirstOrder::Start(_)))
+        .chain(holding().filter(|f| matches!(f, FirstOrder::Contains(_))))
+        .chain(holding().filter(|f| matches!(f, FirstOrder::End(_))))
+        .filter_map(string)
+        .collect();
+    if parts.len() > 1 {
+        candidates.push(Value::String(parts.concat()));
+    }
+
+    let number = |value: Option<&Comparable>| match value {
+        Some(Comparable::Number(number)) => number.as_f64(),
+        _ => None,
+    };
+    let lower = holding()
+        .filter_map(|f| match f {
+            FirstOrder::More(more) => number(more.value.literal()),
+            _ => None,
+        })
+        .reduce(f64::max);
+    let upper = holding()
+        .filter_map(|f| match f {
+            FirstOrder::Less(less) => number(less.value.literal()),
+            _ => None,
+        })
+        .reduce(f64::min);
+    let between = match (lower, upper) {
+        (Some(lower), Some(upper)) if lower.floor() + 1.0 < upper => Some(lower.floor() + 1.0),
+        (Some(lower), Some(upper)) => Some((lower + upper) / 2.0),
+        (Some(lower), None) => Some(lower.floor() + 1.0),
+        (None, Some(upper)) => Some(upper.ceil() - 1.0),
+        (None, None) => None,
+    };
+    candidates.extend(between.and_then(float));
+
+    candidates
+}
+
+/// `value` as a JSON number, an integer when it has no fractional part.
+fn float(value: f64) -> Option<Value> {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        Some(Value::from(value as i64))
+    } else {
+        Number::from_f64(value).map(Value::Number)
+    }
+}
+
+/// A value just after or before `value`.
+fn shifted(value: &Comparable, later: bool) -> Option<Value> {
+    match value {
+        Comparable::Number(number) => {
+            let number = number.as_f64()?;
+            float(if later {
+                number.floor() + 1.0
+            } else {
+                number.ceil() - 1.0
+            })
+        }
+        Comparable::Temporal(temporal) => temporal
+            .shifted(later)
+            .map(|temporal| Value::String(temporal.as_str().to_string())),
+    }
+}
+
+/// A value of the type named by the "value" member of a "type" predicate.
+fn sample(ty: &str) -> Option<Value> {
+    let value = match ty {
+        "number" => Value::from(0),
+        "string" => Value::String(String::new()),
+        "boolean" => Value::Bool(true),
+        "object" => Value::Object(Map::new()),
+        "array" => Value::Array(Vec::new()),
+        "null" => Value::Null,
+        "date" => Value::from("1970-01-01"),
+        "time" => Value::from("00:00:00Z"),
+        "date-time" => Value::from("1970-01-01T00:00:00Z"),
+        "lang" | "lang-range" => Value::from("en"),
+        "iri" | "absolute-iri" => Value::from("https://example.com"),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// The text a regex made of literal characters matches, its anchors and
+/// escapes removed.
+fn pattern_text(pattern: &str) -> String {
+    let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+    let pattern = pattern.strip_suffix('$').unwrap_or(pattern);
+
+    let mut text = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.extend(chars.next()),
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+/// Set the value at `tokens`, creating the objects and arrays holding it.
+fn set(document: &mut Value, tokens: &[String], value: Value) {
+    let Some((token, rest)) = tokens.split_first() else {
+        *document = value;
+        return;
+    };
+
+    let child = match token.parse::<usize>() {
+        Ok(index) => {
+            if !document.is_array() {
+                *document = Value::Array(Vec::new());
+            }
+            let Value::Array(array) = document else {
+                unreachable!()
+            };
+            if array.len() <= index {
+                array.resize(index + 1, Value::Null);
+            }
+            &mut array[index]
+        }
+        Err(_) => {
+            if !document.is_object() {
+                *document = Value::Object(Map::new());
+            }
+            let Value::Object(object) = document else {
+                unreachable!()
+            };
+            object.entry(token.clone()).or_insert(Value::Null)
+        }
+    };
+    set(child, rest, value);
+}
+
+/// Remove the value at `tokens`, if any.
+fn remove(document: &mut Value, tokens: &[String]) {
+    let Some((last, parents)) = tokens.split_last() else {
+        return;
+    };
+    let mut parent = document;
+    for token in parents {
+        parent = match parent {
+            Value::Object(object) => match object.get_mut(token) {
+                Some(child) => child,
+                None => return,
+            },
+            Value::Array(array) => match token.parse::<usize>().ok().and_then(|i| array.get_mut(i))
+            {
+                Some(child) => child,
+                None => return,
+            },
+            _ => return,
+        };
+    }
+    match parent {
+        Value::Object(object) => {
+            object.remove(last);
+        }
+        // Only the last element can be removed without moving the others.
+        Value::Array(array) if last.parse::<usize>().ok() == array.len().checked_sub(1) => {
+            array.pop();
+        }
+        _ => {}
+    }
+}
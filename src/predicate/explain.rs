@@ -0,0 +1,198 @@
+use std::fmt::Display;
+
+use serde_json::Value;
+
+use crate::json_path::JSONPath;
+
+use super::{
+    context::PredicateContext, first_order::FirstOrder, outcome::Outcome, registry::Extension,
+    second_order::SecondOrder, Predicate, PredicateImpl,
+};
+
+/// The trace of the evaluation of a [`Predicate`], it mirrors the structure of
+/// the evaluated predicate: each node describes how one predicate evaluated.
+///
+/// The `Display` implementation renders the trace as an indented tree, one
+/// predicate per line.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    /// The "op" member of the predicate.
+    pub op: &'static str,
+    /// The absolute JSON Pointer resolved from the context and the "path"
+    /// member of the predicate.
+    pub path: Option<JSONPath>,
+    /// The value referenced by `path`, `None` if it doesn't reference
+    /// anything.
+    pub value: Option<Value>,
+    /// The outcome of the evaluation of the predicate.
+    pub outcome: Outcome,
+    /// The explanations of the subordinate predicates of a Second Order
+    /// predicate, in the same order as the "apply" member.
+    pub children: Vec<Explanation>,
+}
+
+impl Explanation {
+    /// The explanations of the First Order predicates which didn't evaluate
+    /// as "true", depth first.
+    pub fn failures(&self) -> Vec<&Explanation> {
+        let mut failures = Vec::new();
+        self.collect_failures(&mut failures);
+        failures
+    }
+
+    fn collect_failures<'a>(&'a self, failures: &mut Vec<&'a Explanation>) {
+        if self.children.is_empty() {
+            if !self.outcome.is_true() {
+                failures.push(self);
+            }
+        } else {
+            for child in &self.children {
+                child.collect_failures(failures);
+            }
+        }
+    }
+
+    fn fmt_indented(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        write!(f, "{:width$}{}", "", self.op, width = depth * 2)?;
+        if let Some(path) = &self.path {
+            write!(f, " {}", path)?;
+        }
+        if let Some(value) = &self.value {
+            if self.children.is_empty() {
+                write!(f, " = {}", value)?;
+            }
+        }
+        match &self.outcome {
+            Outcome::True => writeln!(f, ": true")?,
+            Outcome::False => writeln!(f, ": false")?,
+            Outcome::Error(err) => writeln!(f, ": error ({})", err.kind())?,
+        }
+        for child in &self.children {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+fn resolve(data: &Value, path: &Option<JSONPath>) -> Option<Value> {
+    let ptr = path.clone().unwrap_or(JSONPath::empty()).take();
+    ptr.get(data).ok().cloned()
+}
+
+impl Predicate {
+    /// Evaluate the predicate against the provided JSON and trace the
+    /// evaluation of every subordinate predicate.
+    ///
+    /// Unlike [`PredicateImpl::evaluate`], every subordinate predicate is
+    /// evaluated, even when the result is already known.
+    pub fn explain(&self, data: &Value, ctx: PredicateContext) -> Explanation {
+        match self {
+            Predicate::FirstOrder(fo) => fo.explain(data, ctx),
+            Predicate::SecondOrder(so) => so.explain(data, ctx),
+            Predicate::Extension(ext) => ext.explain(data, ctx),
+        }
+    }
+}
+
+impl FirstOrder {
+    /// Evaluate the predicate against the provided JSON and trace the
+    /// evaluation.
+    pub fn explain(&self, data: &Value, ctx: PredicateContext) -> Explanation {
+        let path = ctx.final_path(&self.path().cloned());
+        let value = resolve(data, &path);
+
+        Explanation {
+            op: self.op(),
+            path,
+            value,
+            outcome: self.evaluate(data, ctx),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl Extension {
+    /// Evaluate the predicate against the provided JSON and trace the
+    /// evaluation.
+    pub fn explain(&self, data: &Value, ctx: PredicateContext) -> Explanation {
+        let path = ctx.final_path(&self.path().cloned());
+        let value = resolve(data, &path);
+
+        Explanation {
+            op: self.op(),
+            path,
+            value,
+            outcome: self.evaluate(data, ctx),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl SecondOrder {
+    /// Evaluate the predicate against the provided JSON and trace the
+    /// evaluation of every subordinate predicate.
+    pub fn explain(&self, data: &Value, ctx: PredicateContext) -> Explanation {
+        let path = ctx.final_path(&self.path().cloned());
+        let value = resolve(data, &path);
+        let ctx = ctx.scoped(path.clone());
+
+        let children: Vec<Explanation> = self
+            .apply()
+            .iter()
+            .map(|ap| ap.explain(data, ctx.clone()))
+            .collect();
+
+        let outcomes = children.iter().map(|child| child.outcome.clone());
+        let outcome = match self {
+            SecondOrder::And(_) => Outcome::all(outcomes),
+            SecondOrder::Or(_) => Outcome::any(outcomes),
+            SecondOrder::Not(_) => Outcome::none(outcomes),
+        };
+
+        Explanation {
+            op: self.op(),
+            path,
+            value,
+            outcome,
+            children,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{context::PredicateContext, Predicate};
+
+    #[test]
+    fn display_test() {
+        let predicate = Predicate::deserialize(serde_json::json!({
+            "op": "and",
+            "path": "/a",
+            "apply": [
+                { "op": "defined", "path": "/b" },
+                { "op": "contains", "path": "/b", "value": "XYZ" },
+                { "op": "not", "apply": [{ "op": "test", "path": "/c", "value": 1 }] }
+            ]
+        }))
+        .unwrap();
+
+        let data = serde_json::json!({ "a": { "b": "ABC" } });
+        let explanation = predicate.explain(&data, PredicateContext::default());
+
+        insta::assert_display_snapshot!(explanation, @r###"
+        and /a: false
+          defined /a/b = "ABC": true
+          contains /a/b = "ABC": false
+          not /a: error (The pointer pointed to a nonexistent key, pointed key: c)
+            test /a/c: error (The pointer pointed to a nonexistent key, pointed key: c)
+        "###);
+    }
+}
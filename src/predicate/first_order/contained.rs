@@ -0,0 +1,297 @@
+use derive_builder::Builder;
+use serde_json::Value;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::{ser::SerializeStruct, Serialize};
+
+use crate::json_path::JSONPath;
+use crate::predicate::context::PredicateContext;
+use crate::predicate::error::PredicateError;
+use crate::predicate::outcome::Outcome;
+use crate::predicate::quantifier::Quantifier;
+use crate::predicate::PredicateImpl;
+
+use super::FirstOrder;
+
+/// The "contained" predicate evaluates as true if the referenced element is
+/// an array whose members are all exactly equal to one of the members of a
+/// JSON array provided by the predicate's "value" member.  Equality is
+/// determined following the same rules as the "test" predicate, with the same
+/// exception given for optional case-insensitive comparisons.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(pattern = "owned", setter(into, strip_option))]
+pub struct Contained {
+    /// Must be a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
+    /// If the "path" member is not specified within the predicate object, it's value is assumed to be an empty string.
+    pub path: Option<JSONPath>,
+    /// When present, `*` and `**` in the "path" member are wildcards and the
+    /// predicate must hold for the matched values as quantified.
+    #[builder(default)]
+    pub quantifier: Option<Quantifier>,
+    #[builder(default)]
+    pub ignore_case: bool,
+    pub value: Vec<serde_json::Value>,
+}
+
+impl Serialize for Contained {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("Contained", 3)?;
+        if self.ignore_case {
+            state.serialize_field("op", "contained-")?;
+        } else {
+            state.serialize_field("op", "contained")?;
+        }
+        state.serialize_field("path", &self.path)?;
+        if let Some(quantifier) = &self.quantifier {
+            state.serialize_field("quantifier", quantifier)?;
+        }
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Contained {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[allow(non_camel_case_types)]
+        enum Field {
+            op,
+            path,
+            quantifier,
+            value,
+            __ignore,
+        }
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("field identifier")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    "op" => Ok(Field::op),
+                    "path" => Ok(Field::path),
+                    "quantifier" => Ok(Field::quantifier),
+                    "value" => Ok(Field::value),
+                    _ => Ok(Field::__ignore),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Field {
+            #[inline]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Deserializer::deserialize_identifier(deserializer, FieldVisitor)
+            }
+        }
+
+        struct VisitorContained<'de> {
+            marker: PhantomData<Contained>,
+            lifetime: PhantomData<&'de ()>,
+        }
+
+        impl<'de> Visitor<'de> for VisitorContained<'de> {
+            type Value = Contained;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("Contained")
+            }
+
+            #[inline]
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path: Option<Option<JSONPath>> = None;
+                let mut quantifier: Option<Option<Quantifier>> = None;
+                let mut value: Option<Vec<serde_json::Value>> = None;
+                let mut op: Option<String> = None;
+
+                while let Some(key) = MapAccess::next_key::<Field>(&mut map)? {
+                    match key {
+                        Field::op => {
+                            if op.is_some() {
+                                return Err(serde::de::Error::duplicate_field("op"));
+                            }
+                            op = Some(MapAccess::next_value::<String>(&mut map)?);
+                        }
+                        Field::path => {
+                            if path.is_some() {
+                                return Err(serde::de::Error::duplicate_field("path"));
+                            }
+                            path = Some(MapAccess::next_value::<Option<JSONPath>>(&mut map)?);
+                        }
+                        Field::quantifier => {
+                            if quantifier.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantifier"));
+                            }
+                            quantifier =
+                                Some(MapAccess::next_value::<Option<Quantifier>>(&mut map)?);
+                        }
+                        Field::value => {
+                            if value.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value =
+                                Some(MapAccess::next_value::<Vec<serde_json::Value>>(&mut map)?);
+                        }
+                        Field::__ignore => {}
+                    }
+                }
+
+                let path = path.ok_or(serde::de::Error::missing_field("path"))?;
+                let quantifier = quantifier.flatten();
+                let value = value.ok_or(serde::de::Error::missing_field("value"))?;
+                let op = op.ok_or(serde::de::Error::missing_field("op"))?;
+
+                let ignore_case = match op.as_str() {
+                    "contained" => false,
+                    "contained-" => true,
+                    _ => {
+                        return Err(serde::de::Error::custom(
+                            "`op` should be either `contained` or `contained-`",
+                        ));
+                    }
+                };
+
+                Ok(Contained {
+                    path,
+                    quantifier,
+                    ignore_case,
+                    value,
+                })
+            }
+        }
+
+        const FIELDS: &[&str] = &["path", "quantifier", "op", "value"];
+        Deserializer::deserialize_struct(
+            deserializer,
+            "Contained",
+            FIELDS,
+            VisitorContained {
+                marker: PhantomData::<Contained>,
+                lifetime: PhantomData,
+            },
+        )
+    }
+}
+
+impl From<Contained> for FirstOrder {
+    fn from(value: Contained) -> Self {
+        FirstOrder::Contained(value)
+    }
+}
+
+impl Contained {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        if self.ignore_case {
+            "contained-"
+        } else {
+            "contained"
+        }
+    }
+
+    /// Evaluate the predicate against the referenced element.
+    fn check(&self, context_value: &Value) -> Outcome {
+        let value = &self.value;
+
+        let context_value = match context_value {
+            Value::Array(context_value) => context_value,
+            _ => return Outcome::False,
+        };
+
+        if self.ignore_case {
+            let value: Vec<String> = value.iter().map(|x| x.to_string().to_lowercase()).collect();
+            context_value
+                .iter()
+                .all(|x| value.contains(&x.to_string().to_lowercase()))
+                .into()
+        } else {
+            context_value.iter().all(|x| value.contains(x)).into()
+        }
+    }
+}
+
+impl PredicateImpl for Contained {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
+        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
+
+        if let Some(quantifier) = &self.quantifier {
+            return quantifier.evaluate(&path.tokens(), data, |context_value| {
+                self.check(context_value)
+            });
+        }
+
+        let context_value = match path.get(data) {
+            Ok(context_value) => context_value,
+            Err(err) => return PredicateError::new(err.into(), self.op(), path).into(),
+        };
+
+        self.check(context_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{json_path::JSONPath, predicate::first_order::contained::Contained};
+
+    #[test]
+    fn snapshot_test() {
+        let contained_expect = serde_json::json!({
+             "op": "contained",
+             "path": "/a",
+             "value": ["a", "b"],
+        });
+
+        let contained = Contained {
+            path: Some(JSONPath::new("/a").unwrap()),
+            quantifier: None,
+            ignore_case: false,
+            value: vec![
+                serde_json::Value::String("a".to_string()),
+                serde_json::Value::String("b".to_string()),
+            ],
+        };
+
+        assert_eq!(serde_json::to_value(contained).unwrap(), contained_expect);
+    }
+
+    #[test]
+    fn deser_test() {
+        let contained_expect = serde_json::json!({
+             "op": "contained",
+             "path": "/a",
+             "value": ["a", "b"],
+        });
+
+        let contained = Contained {
+            path: Some(JSONPath::new("/a").unwrap()),
+            quantifier: None,
+            ignore_case: false,
+            value: vec![
+                serde_json::Value::String("a".to_string()),
+                serde_json::Value::String("b".to_string()),
+            ],
+        };
+
+        let deser = Contained::deserialize(contained_expect).unwrap();
+
+        assert_eq!(contained, deser);
+    }
+}
@@ -8,6 +8,9 @@ use serde::{ser::SerializeStruct, Serialize};
 use crate::json_path::JSONPath;
 use crate::predicate::context::PredicateContext;
 use crate::predicate::error::PredicateError;
+use crate::predicate::operand::Operand;
+use crate::predicate::outcome::Outcome;
+use crate::predicate::quantifier::Quantifier;
 use crate::predicate::PredicateImpl;
 
 use super::FirstOrder;
@@ -22,8 +25,16 @@ pub struct Contains {
     /// Must be a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
     /// If the "path" member is not specified within the predicate object, it's value is assumed to be an empty string.
     pub path: Option<JSONPath>,
+    /// When present, `*` and `**` in the "path" member are wildcards and the
+    /// predicate must hold for the matched values as quantified.
+    #[builder(default)]
+    pub quantifier: Option<Quantifier>,
     pub ignore_case: bool,
-    pub value: serde_json::Value,
+    /// Either a literal value, a JSON Pointer to the value to compare with in
+    /// the evaluated document, written `{"$path": "/a/b"}`, or a variable
+    /// bound in the context, written `{"$var": "name"}`.
+    #[builder(setter(custom))]
+    pub value: Operand<serde_json::Value>,
 }
 
 impl Serialize for Contains {
@@ -38,6 +49,9 @@ impl Serialize for Contains {
             state.serialize_field("op", "contains")?;
         }
         state.serialize_field("path", &self.path)?;
+        if let Some(quantifier) = &self.quantifier {
+            state.serialize_field("quantifier", quantifier)?;
+        }
         state.serialize_field("value", &self.value)?;
         state.end()
     }
@@ -52,6 +66,7 @@ impl<'de> Deserialize<'de> for Contains {
         enum Field {
             op,
             path,
+            quantifier,
             value,
             __ignore,
         }
@@ -70,6 +85,7 @@ impl<'de> Deserialize<'de> for Contains {
                 match value {
                     "op" => Ok(Field::op),
                     "path" => Ok(Field::path),
+                    "quantifier" => Ok(Field::quantifier),
                     "value" => Ok(Field::value),
                     _ => Ok(Field::__ignore),
                 }
@@ -103,7 +119,8 @@ impl<'de> Deserialize<'de> for Contains {
                 A: MapAccess<'de>,
             {
                 let mut path: Option<Option<JSONPath>> = None;
-                let mut value: Option<serde_json::Value> = None;
+                let mut quantifier: Option<Option<Quantifier>> = None;
+                let mut value: Option<Operand<serde_json::Value>> = None;
                 let mut op: Option<String> = None;
 
                 while let Some(key) = MapAccess::next_key::<Field>(&mut map)? {
@@ -120,17 +137,27 @@ impl<'de> Deserialize<'de> for Contains {
                             }
                             path = Some(MapAccess::next_value::<Option<JSONPath>>(&mut map)?);
                         }
+                        Field::quantifier => {
+                            if quantifier.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantifier"));
+                            }
+                            quantifier =
+                                Some(MapAccess::next_value::<Option<Quantifier>>(&mut map)?);
+                        }
                         Field::value => {
                             if value.is_some() {
                                 return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            value = Some(MapAccess::next_value::<serde_json::Value>(&mut map)?);
+                            value = Some(MapAccess::next_value::<Operand<serde_json::Value>>(
+                                &mut map,
+                            )?);
                         }
                         Field::__ignore => {}
                     }
                 }
 
                 let path = path.ok_or(serde::de::Error::missing_field("path"))?;
+                let quantifier = quantifier.flatten();
                 let value = value.ok_or(serde::de::Error::missing_field("value"))?;
                 let op = op.ok_or(serde::de::Error::missing_field("op"))?;
 
@@ -146,13 +173,14 @@ impl<'de> Deserialize<'de> for Contains {
 
                 Ok(Contains {
                     path,
+                    quantifier,
                     ignore_case,
                     value,
                 })
             }
         }
 
-        const FIELDS: &[&str] = &["path", "op", "value"];
+        const FIELDS: &[&str] = &["path", "quantifier", "op", "value"];
         Deserializer::deserialize_struct(
             deserializer,
             "Contains",
@@ -165,20 +193,47 @@ impl<'de> Deserialize<'de> for Contains {
     }
 }
 
+impl ContainsBuilder {
+    /// The literal "value" member of the predicate.
+    pub fn value<V: Into<Value>>(mut self, value: V) -> Self {
+        self.value = Some(Operand::Value(value.into()));
+        self
+    }
+
+    /// Compare with the value referenced by `path` in the evaluated
+    /// document instead of a literal value.
+    pub fn value_path(mut self, path: JSONPath) -> Self {
+        self.value = Some(Operand::Path(path));
+        self
+    }
+
+    /// Compare with the value bound to the variable `name` in the context
+    /// instead of a literal value.
+    pub fn value_var(mut self, name: impl Into<String>) -> Self {
+        self.value = Some(Operand::Var(name.into()));
+        self
+    }
+}
+
 impl From<Contains> for FirstOrder {
     fn from(value: Contains) -> Self {
         FirstOrder::Contains(value)
     }
 }
 
-impl PredicateImpl for Contains {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
-        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
-        let ptr = path.take();
-
-        let value = &self.value;
-        let context_value = ptr.get(data)?;
+impl Contains {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        if self.ignore_case {
+            "contains-"
+        } else {
+            "contains"
+        }
+    }
 
+    /// Evaluate the predicate against the referenced element, `value` being
+    /// the resolved "value" member.
+    pub(crate) fn check(&self, context_value: &Value, value: &Value) -> Outcome {
         // The "contains" predicate evaluates as true if the referenced
         // element is defined and has a value whose string representation
         // contains the exact sequence of characters given by the predicate
@@ -186,13 +241,39 @@ impl PredicateImpl for Contains {
         match (context_value, value) {
             (Value::String(context), Value::String(value)) => {
                 if self.ignore_case {
-                    Ok(context.to_lowercase().contains(&value.to_lowercase()))
+                    context
+                        .to_lowercase()
+                        .contains(&value.to_lowercase())
+                        .into()
                 } else {
-                    Ok(context.contains(value))
+                    context.contains(value).into()
                 }
             }
-            _ => Ok(false),
+            _ => Outcome::False,
+        }
+    }
+}
+
+impl PredicateImpl for Contains {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
+        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
+        let value = match self.value.resolve(data, &ctx, self.op(), &path) {
+            Ok(value) => value,
+            Err(outcome) => return outcome,
+        };
+
+        if let Some(quantifier) = &self.quantifier {
+            return quantifier.evaluate(&path.tokens(), data, |context_value| {
+                self.check(context_value, &value)
+            });
         }
+
+        let context_value = match path.get(data) {
+            Ok(context_value) => context_value,
+            Err(err) => return PredicateError::new(err.into(), self.op(), path).into(),
+        };
+
+        self.check(context_value, &value)
     }
 }
 
@@ -212,8 +293,9 @@ mod tests {
 
         let contains = Contains {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
             ignore_case: false,
-            value: serde_json::Value::String(" is a ".to_string()),
+            value: serde_json::Value::String(" is a ".to_string()).into(),
         };
 
         assert_eq!(serde_json::to_value(contains).unwrap(), contains_expect);
@@ -229,8 +311,9 @@ mod tests {
 
         let contains = Contains {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
             ignore_case: false,
-            value: serde_json::Value::String(" is a ".to_string()),
+            value: serde_json::Value::String(" is a ".to_string()).into(),
         };
 
         let deser = Contains::deserialize(contains_expect).unwrap();
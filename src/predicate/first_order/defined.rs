@@ -7,7 +7,8 @@ use serde_json::Value;
 
 use crate::context::PredicateContext;
 use crate::json_path::JSONPath;
-use crate::predicate::error::PredicateError;
+use crate::predicate::outcome::Outcome;
+use crate::predicate::quantifier::Quantifier;
 use crate::{FirstOrder, PredicateImpl};
 
 /// The "defined" predicate evaluates as true if the referenced element exists
@@ -18,6 +19,10 @@ pub struct Defined {
     /// Must be a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
     /// If the "path" member is not specified within the predicate object, it's value is assumed to be an empty string.
     pub path: Option<JSONPath>,
+    /// When present, `*` and `**` in the "path" member are wildcards and the
+    /// predicate must hold for the matched values as quantified.
+    #[builder(default)]
+    pub quantifier: Option<Quantifier>,
 }
 
 impl Serialize for Defined {
@@ -28,6 +33,9 @@ impl Serialize for Defined {
         let mut state = serializer.serialize_struct("Defined", 2)?;
         state.serialize_field("op", "defined")?;
         state.serialize_field("path", &self.path)?;
+        if let Some(quantifier) = &self.quantifier {
+            state.serialize_field("quantifier", quantifier)?;
+        }
         state.end()
     }
 }
@@ -41,6 +49,7 @@ impl<'de> Deserialize<'de> for Defined {
         enum Field {
             op,
             path,
+            quantifier,
             __ignore,
         }
         struct FieldVisitor;
@@ -58,6 +67,7 @@ impl<'de> Deserialize<'de> for Defined {
                 match value {
                     "op" => Ok(Field::op),
                     "path" => Ok(Field::path),
+                    "quantifier" => Ok(Field::quantifier),
                     _ => Ok(Field::__ignore),
                 }
             }
@@ -90,6 +100,7 @@ impl<'de> Deserialize<'de> for Defined {
                 A: MapAccess<'de>,
             {
                 let mut path: Option<Option<JSONPath>> = None;
+                let mut quantifier: Option<Option<Quantifier>> = None;
                 let mut op: Option<String> = None;
 
                 while let Some(key) = MapAccess::next_key::<Field>(&mut map)? {
@@ -106,22 +117,30 @@ impl<'de> Deserialize<'de> for Defined {
                             }
                             path = Some(MapAccess::next_value::<Option<JSONPath>>(&mut map)?);
                         }
+                        Field::quantifier => {
+                            if quantifier.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantifier"));
+                            }
+                            quantifier =
+                                Some(MapAccess::next_value::<Option<Quantifier>>(&mut map)?);
+                        }
                         Field::__ignore => {}
                     }
                 }
 
                 let path = path.ok_or(serde::de::Error::missing_field("path"))?;
+                let quantifier = quantifier.flatten();
                 let op = op.ok_or(serde::de::Error::missing_field("op"))?;
 
                 if op != "defined" {
                     return Err(serde::de::Error::custom("`op` should be `defined`"));
                 }
 
-                Ok(Defined { path })
+                Ok(Defined { path, quantifier })
             }
         }
 
-        const FIELDS: &[&str] = &["path", "op"];
+        const FIELDS: &[&str] = &["path", "quantifier", "op"];
         Deserializer::deserialize_struct(
             deserializer,
             "Defined",
@@ -140,19 +159,29 @@ impl From<Defined> for FirstOrder {
     }
 }
 
+impl Defined {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        "defined"
+    }
+}
+
 impl PredicateImpl for Defined {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
-        let ptr = path.take();
 
-        let _context_value = ptr.get(data)?;
+        // Every value matched by a quantified path is defined.
+        if let Some(quantifier) = &self.quantifier {
+            return quantifier.evaluate(&path.tokens(), data, |_| Outcome::True);
+        }
 
         // The "defined" predicate evaluates as true if the referenced element
         // exists within the target context.
         //
-        // If the element doesn't exist we woulnd't be here so even if
-        // it's null, it's enough to tell it exists.
-        Ok(true)
+        // Even if it's null, it's enough to tell it exists. A pointer which
+        // doesn't reference anything isn't an error here as the "defined"
+        // predicate is specifically intended to test for it.
+        path.get(data).is_ok().into()
     }
 }
 
@@ -171,6 +200,7 @@ mod tests {
 
         let defined = Defined {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
         };
 
         assert_eq!(serde_json::to_value(defined).unwrap(), defined_expect);
@@ -185,6 +215,7 @@ mod tests {
 
         let defined = Defined {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
         };
 
         let deser = Defined::deserialize(defined_expect).unwrap();
@@ -1,13 +1,18 @@
 use derive_builder::Builder;
-use serde_json::{Number, Value};
+use serde_json::Value;
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 
 use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
 use serde::{ser::SerializeStruct, Serialize};
 
+use crate::comparable::Comparable;
 use crate::json_path::JSONPath;
 use crate::predicate::context::PredicateContext;
 use crate::predicate::error::PredicateError;
+use crate::predicate::operand::Operand;
+use crate::predicate::outcome::Outcome;
+use crate::predicate::quantifier::Quantifier;
 use crate::predicate::PredicateImpl;
 
 use super::FirstOrder;
@@ -15,13 +20,26 @@ use super::FirstOrder;
 /// The "less" predicate evaluates as true if the referenced element is defined
 /// and specifies a number whose value is less than that specified by the
 /// predicate object's "value" member.
+///
+/// The "value" member can also be a date, a time or a date-time as described
+/// by [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339), in which case
+/// the referenced element must be a string of the same kind, and they are
+/// compared chronologically.
 #[derive(Debug, Clone, PartialEq, Eq, Builder)]
 #[builder(pattern = "owned", setter(into, strip_option))]
 pub struct Less {
     /// Must be a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
     /// If the "path" member is not specified within the predicate object, it's value is assumed to be an empty string.
     pub path: Option<JSONPath>,
-    pub value: Number,
+    /// When present, `*` and `**` in the "path" member are wildcards and the
+    /// predicate must hold for the matched values as quantified.
+    #[builder(default)]
+    pub quantifier: Option<Quantifier>,
+    /// Either a literal value, a JSON Pointer to the value to compare with in
+    /// the evaluated document, written `{"$path": "/a/b"}`, or a variable
+    /// bound in the context, written `{"$var": "name"}`.
+    #[builder(setter(custom))]
+    pub value: Operand<Comparable>,
 }
 
 impl Serialize for Less {
@@ -32,6 +50,9 @@ impl Serialize for Less {
         let mut state = serializer.serialize_struct("Less", 3)?;
         state.serialize_field("op", "less")?;
         state.serialize_field("path", &self.path)?;
+        if let Some(quantifier) = &self.quantifier {
+            state.serialize_field("quantifier", quantifier)?;
+        }
         state.serialize_field("value", &self.value)?;
         state.end()
     }
@@ -46,6 +67,7 @@ impl<'de> Deserialize<'de> for Less {
         enum Field {
             op,
             path,
+            quantifier,
             value,
             __ignore,
         }
@@ -64,6 +86,7 @@ impl<'de> Deserialize<'de> for Less {
                 match value {
                     "op" => Ok(Field::op),
                     "path" => Ok(Field::path),
+                    "quantifier" => Ok(Field::quantifier),
                     "value" => Ok(Field::value),
                     _ => Ok(Field::__ignore),
                 }
@@ -97,7 +120,8 @@ impl<'de> Deserialize<'de> for Less {
                 A: MapAccess<'de>,
             {
                 let mut path: Option<Option<JSONPath>> = None;
-                let mut value: Option<Number> = None;
+                let mut quantifier: Option<Option<Quantifier>> = None;
+                let mut value: Option<Operand<Comparable>> = None;
                 let mut op: Option<String> = None;
 
                 while let Some(key) = MapAccess::next_key::<Field>(&mut map)? {
@@ -114,17 +138,25 @@ impl<'de> Deserialize<'de> for Less {
                             }
                             path = Some(MapAccess::next_value::<Option<JSONPath>>(&mut map)?);
                         }
+                        Field::quantifier => {
+                            if quantifier.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantifier"));
+                            }
+                            quantifier =
+                                Some(MapAccess::next_value::<Option<Quantifier>>(&mut map)?);
+                        }
                         Field::value => {
                             if value.is_some() {
                                 return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            value = Some(MapAccess::next_value::<Number>(&mut map)?);
+                            value = Some(MapAccess::next_value::<Operand<Comparable>>(&mut map)?);
                         }
                         Field::__ignore => {}
                     }
                 }
 
                 let path = path.ok_or(serde::de::Error::missing_field("path"))?;
+                let quantifier = quantifier.flatten();
                 let value = value.ok_or(serde::de::Error::missing_field("value"))?;
                 let op = op.ok_or(serde::de::Error::missing_field("op"))?;
 
@@ -132,11 +164,15 @@ impl<'de> Deserialize<'de> for Less {
                     return Err(serde::de::Error::custom("`op` should be `less`"));
                 }
 
-                Ok(Less { path, value })
+                Ok(Less {
+                    path,
+                    quantifier,
+                    value,
+                })
             }
         }
 
-        const FIELDS: &[&str] = &["path", "op", "value"];
+        const FIELDS: &[&str] = &["path", "quantifier", "op", "value"];
         Deserializer::deserialize_struct(
             deserializer,
             "Less",
@@ -149,25 +185,67 @@ impl<'de> Deserialize<'de> for Less {
     }
 }
 
+impl LessBuilder {
+    /// The literal "value" member of the predicate.
+    pub fn value<V: Into<Comparable>>(mut self, value: V) -> Self {
+        self.value = Some(Operand::Value(value.into()));
+        self
+    }
+
+    /// Compare with the value referenced by `path` in the evaluated
+    /// document instead of a literal value.
+    pub fn value_path(mut self, path: JSONPath) -> Self {
+        self.value = Some(Operand::Path(path));
+        self
+    }
+
+    /// Compare with the value bound to the variable `name` in the context
+    /// instead of a literal value.
+    pub fn value_var(mut self, name: impl Into<String>) -> Self {
+        self.value = Some(Operand::Var(name.into()));
+        self
+    }
+}
+
 impl From<Less> for FirstOrder {
     fn from(value: Less) -> Self {
         FirstOrder::Less(value)
     }
 }
 
+impl Less {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        "less"
+    }
+
+    /// Evaluate the predicate against the referenced element, `value` being
+    /// the resolved "value" member.
+    pub(crate) fn check(&self, context_value: &Value, value: &Comparable) -> Outcome {
+        Outcome::from(value.cmp_value(context_value) == Some(Ordering::Less))
+    }
+}
+
 impl PredicateImpl for Less {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
-        let ptr = path.take();
-
-        let value = &self.value;
-        let context_value = ptr.get(data)?;
+        let value = match self.value.resolve(data, &ctx, self.op(), &path) {
+            Ok(value) => value,
+            Err(outcome) => return outcome,
+        };
 
-        if context_value.is_number() {
-            Ok(context_value.as_f64() < value.as_f64())
-        } else {
-            Ok(false)
+        if let Some(quantifier) = &self.quantifier {
+            return quantifier.evaluate(&path.tokens(), data, |context_value| {
+                self.check(context_value, &value)
+            });
         }
+
+        let context_value = match path.get(data) {
+            Ok(context_value) => context_value,
+            Err(err) => return PredicateError::new(err.into(), self.op(), path).into(),
+        };
+
+        self.check(context_value, &value)
     }
 }
 
@@ -176,6 +254,7 @@ mod tests {
     use serde::Deserialize;
     use serde_json::Number;
 
+    use crate::predicate::operand::Operand;
     use crate::{json_path::JSONPath, predicate::first_order::less::Less};
 
     #[test]
@@ -188,7 +267,8 @@ mod tests {
 
         let less = Less {
             path: Some(JSONPath::new("/a/b").unwrap()),
-            value: Number::from(12),
+            quantifier: None,
+            value: Operand::Value(Number::from(12).into()),
         };
 
         assert_eq!(serde_json::to_value(less).unwrap(), less_expect);
@@ -204,7 +284,8 @@ mod tests {
 
         let less = Less {
             path: Some(JSONPath::new("/a/b").unwrap()),
-            value: Number::from(12),
+            quantifier: None,
+            value: Operand::Value(Number::from(12).into()),
         };
 
         let deser = Less::deserialize(less_expect).unwrap();
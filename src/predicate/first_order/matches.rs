@@ -8,7 +8,9 @@ use serde::{ser::SerializeStruct, Serialize};
 
 use crate::json_path::JSONPath;
 use crate::predicate::context::PredicateContext;
-use crate::predicate::error::PredicateError;
+use crate::predicate::error::{PredicateError, PredicateErrorKind};
+use crate::predicate::outcome::Outcome;
+use crate::predicate::quantifier::Quantifier;
 use crate::predicate::PredicateImpl;
 use crate::regex::Regex;
 
@@ -23,6 +25,10 @@ pub struct Matches {
     /// Must be a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
     /// If the "path" member is not specified within the predicate object, it's value is assumed to be an empty string.
     pub path: Option<JSONPath>,
+    /// When present, `*` and `**` in the "path" member are wildcards and the
+    /// predicate must hold for the matched values as quantified.
+    #[builder(default)]
+    pub quantifier: Option<Quantifier>,
     #[builder(default)]
     pub ignore_case: bool,
     /// Must be a Regex
@@ -41,6 +47,9 @@ impl Serialize for Matches {
             state.serialize_field("op", "matches")?;
         }
         state.serialize_field("path", &self.path)?;
+        if let Some(quantifier) = &self.quantifier {
+            state.serialize_field("quantifier", quantifier)?;
+        }
         state.serialize_field("value", &self.value)?;
         state.end()
     }
@@ -55,6 +64,7 @@ impl<'de> Deserialize<'de> for Matches {
         enum Field {
             op,
             path,
+            quantifier,
             value,
             __ignore,
         }
@@ -73,6 +83,7 @@ impl<'de> Deserialize<'de> for Matches {
                 match value {
                     "op" => Ok(Field::op),
                     "path" => Ok(Field::path),
+                    "quantifier" => Ok(Field::quantifier),
                     "value" => Ok(Field::value),
                     _ => Ok(Field::__ignore),
                 }
@@ -106,6 +117,7 @@ impl<'de> Deserialize<'de> for Matches {
                 A: MapAccess<'de>,
             {
                 let mut path: Option<Option<JSONPath>> = None;
+                let mut quantifier: Option<Option<Quantifier>> = None;
                 let mut value: Option<Regex> = None;
                 let mut op: Option<String> = None;
 
@@ -123,6 +135,13 @@ impl<'de> Deserialize<'de> for Matches {
                             }
                             path = Some(MapAccess::next_value::<Option<JSONPath>>(&mut map)?);
                         }
+                        Field::quantifier => {
+                            if quantifier.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantifier"));
+                            }
+                            quantifier =
+                                Some(MapAccess::next_value::<Option<Quantifier>>(&mut map)?);
+                        }
                         Field::value => {
                             if value.is_some() {
                                 return Err(serde::de::Error::duplicate_field("value"));
@@ -134,6 +153,7 @@ impl<'de> Deserialize<'de> for Matches {
                 }
 
                 let path = path.ok_or(serde::de::Error::missing_field("path"))?;
+                let quantifier = quantifier.flatten();
                 let value = value.ok_or(serde::de::Error::missing_field("value"))?;
                 let op = op.ok_or(serde::de::Error::missing_field("op"))?;
 
@@ -149,13 +169,14 @@ impl<'de> Deserialize<'de> for Matches {
 
                 Ok(Matches {
                     path,
+                    quantifier,
                     ignore_case,
                     value,
                 })
             }
         }
 
-        const FIELDS: &[&str] = &["path", "op", "value"];
+        const FIELDS: &[&str] = &["path", "quantifier", "op", "value"];
         Deserializer::deserialize_struct(
             deserializer,
             "Matches",
@@ -174,32 +195,67 @@ impl From<Matches> for FirstOrder {
     }
 }
 
-impl PredicateImpl for Matches {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
-        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
-        let ptr = path.take();
+impl Matches {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        if self.ignore_case {
+            "matches-"
+        } else {
+            "matches"
+        }
+    }
 
+    /// Evaluate the predicate against the referenced element.
+    fn check(&self, context_value: &Value, path: &JSONPath) -> Outcome {
         let value = &self.value;
-        let context_value = ptr.get(data)?;
 
         match context_value {
             Value::String(context) => {
                 if self.ignore_case {
-                    let value = RegexBuilder::new(value.as_ref().as_str())
+                    let value = match RegexBuilder::new(value.as_ref().as_str())
                         .case_insensitive(true)
                         .build()
-                        .map_err(|_err| PredicateError::ty_issue())?;
+                    {
+                        Ok(value) => value,
+                        Err(_err) => {
+                            return PredicateError::new(
+                                PredicateErrorKind::IncorrectType,
+                                self.op(),
+                                path.clone(),
+                            )
+                            .into()
+                        }
+                    };
 
-                    Ok(value.is_match(context))
+                    value.is_match(context).into()
                 } else {
-                    Ok(value.as_ref().is_match(context))
+                    value.as_ref().is_match(context).into()
                 }
             }
-            _ => Ok(false),
+            _ => Outcome::False,
         }
     }
 }
 
+impl PredicateImpl for Matches {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
+        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
+
+        if let Some(quantifier) = &self.quantifier {
+            return quantifier.evaluate(&path.tokens(), data, |context_value| {
+                self.check(context_value, &path)
+            });
+        }
+
+        let context_value = match path.get(data) {
+            Ok(context_value) => context_value,
+            Err(err) => return PredicateError::new(err.into(), self.op(), path).into(),
+        };
+
+        self.check(context_value, &path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use regex::Regex;
@@ -217,6 +273,7 @@ mod tests {
 
         let matches = Matches {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
             ignore_case: false,
             value: Regex::new(".*").unwrap().into(),
         };
@@ -234,6 +291,7 @@ mod tests {
 
         let matches = Matches {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
             ignore_case: false,
             value: Regex::new(".*").unwrap().into(),
         };
@@ -1,18 +1,28 @@
+use crate::json_path::JSONPath;
 use crate::Predicate;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use serde_json::Value;
 
 use self::{
-    contains::Contains, defined::Defined, end::End, less::Less, matches::Matches, more::More,
-    r#in::In, r#type::Type, start::Start, test::Test, undefined::Undefined,
+    contained::Contained, contains::Contains, defined::Defined, end::End, intersects::Intersects,
+    less::Less, matches::Matches, more::More, r#in::In, r#type::Type, start::Start, test::Test,
+    undefined::Undefined,
 };
 
-use super::{context::PredicateContext, error::PredicateError, PredicateImpl};
+use super::{
+    context::PredicateContext,
+    operand::{OperandValue, Reference},
+    outcome::Outcome,
+    quantifier::Quantifier,
+    PredicateImpl,
+};
 
+pub mod contained;
 pub mod contains;
 pub mod defined;
 pub mod end;
 pub mod r#in;
+pub mod intersects;
 pub mod less;
 pub mod matches;
 pub mod more;
@@ -21,7 +31,7 @@ pub mod test;
 pub mod r#type;
 pub mod undefined;
 
-#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum FirstOrder {
     Contains(Contains),
@@ -35,12 +45,164 @@ pub enum FirstOrder {
     Matches(Matches),
     Less(Less),
     More(More),
-    // Contained,
-    // Intersects,
+    Contained(Contained),
+    Intersects(Intersects),
+}
+
+impl FirstOrder {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        match self {
+            FirstOrder::Contains(ty) => ty.op(),
+            FirstOrder::Defined(ty) => ty.op(),
+            FirstOrder::Undefined(ty) => ty.op(),
+            FirstOrder::Start(ty) => ty.op(),
+            FirstOrder::End(ty) => ty.op(),
+            FirstOrder::Type(ty) => ty.op(),
+            FirstOrder::In(ty) => ty.op(),
+            FirstOrder::Test(ty) => ty.op(),
+            FirstOrder::Matches(ty) => ty.op(),
+            FirstOrder::Less(ty) => ty.op(),
+            FirstOrder::More(ty) => ty.op(),
+            FirstOrder::Contained(ty) => ty.op(),
+            FirstOrder::Intersects(ty) => ty.op(),
+        }
+    }
+
+    /// The "path" member of the predicate.
+    pub fn path(&self) -> Option<&JSONPath> {
+        match self {
+            FirstOrder::Contains(contains) => contains.path.as_ref(),
+            FirstOrder::Defined(defined) => defined.path.as_ref(),
+            FirstOrder::Undefined(undefined) => undefined.path.as_ref(),
+            FirstOrder::Start(start) => start.path.as_ref(),
+            FirstOrder::End(end) => end.path.as_ref(),
+            FirstOrder::Type(ty) => ty.path.as_ref(),
+            FirstOrder::In(ty) => ty.path.as_ref(),
+            FirstOrder::Test(ty) => ty.path.as_ref(),
+            FirstOrder::Matches(ty) => ty.path.as_ref(),
+            FirstOrder::Less(ty) => ty.path.as_ref(),
+            FirstOrder::More(ty) => ty.path.as_ref(),
+            FirstOrder::Contained(ty) => ty.path.as_ref(),
+            FirstOrder::Intersects(ty) => ty.path.as_ref(),
+        }
+    }
+
+    /// The "quantifier" member of the predicate.
+    pub fn quantifier(&self) -> Option<&Quantifier> {
+        match self {
+            FirstOrder::Contains(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Defined(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Undefined(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Start(ty) => ty.quantifier.as_ref(),
+            FirstOrder::End(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Type(ty) => ty.quantifier.as_ref(),
+            FirstOrder::In(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Test(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Matches(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Less(ty) => ty.quantifier.as_ref(),
+            FirstOrder::More(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Contained(ty) => ty.quantifier.as_ref(),
+            FirstOrder::Intersects(ty) => ty.quantifier.as_ref(),
+        }
+    }
+
+    /// The "path" member of the predicate, to rewrite it.
+    pub(crate) fn path_mut(&mut self) -> &mut Option<JSONPath> {
+        match self {
+            FirstOrder::Contains(ty) => &mut ty.path,
+            FirstOrder::Defined(ty) => &mut ty.path,
+            FirstOrder::Undefined(ty) => &mut ty.path,
+            FirstOrder::Start(ty) => &mut ty.path,
+            FirstOrder::End(ty) => &mut ty.path,
+            FirstOrder::Type(ty) => &mut ty.path,
+            FirstOrder::In(ty) => &mut ty.path,
+            FirstOrder::Test(ty) => &mut ty.path,
+            FirstOrder::Matches(ty) => &mut ty.path,
+            FirstOrder::Less(ty) => &mut ty.path,
+            FirstOrder::More(ty) => &mut ty.path,
+            FirstOrder::Contained(ty) => &mut ty.path,
+            FirstOrder::Intersects(ty) => &mut ty.path,
+        }
+    }
+
+    /// The "quantifier" member of the predicate, to rewrite it.
+    pub(crate) fn quantifier_mut(&mut self) -> &mut Option<Quantifier> {
+        match self {
+            FirstOrder::Contains(ty) => &mut ty.quantifier,
+            FirstOrder::Defined(ty) => &mut ty.quantifier,
+            FirstOrder::Undefined(ty) => &mut ty.quantifier,
+            FirstOrder::Start(ty) => &mut ty.quantifier,
+            FirstOrder::End(ty) => &mut ty.quantifier,
+            FirstOrder::Type(ty) => &mut ty.quantifier,
+            FirstOrder::In(ty) => &mut ty.quantifier,
+            FirstOrder::Test(ty) => &mut ty.quantifier,
+            FirstOrder::Matches(ty) => &mut ty.quantifier,
+            FirstOrder::Less(ty) => &mut ty.quantifier,
+            FirstOrder::More(ty) => &mut ty.quantifier,
+            FirstOrder::Contained(ty) => &mut ty.quantifier,
+            FirstOrder::Intersects(ty) => &mut ty.quantifier,
+        }
+    }
+
+    /// The JSON Pointer referenced by the "value" member, to rewrite it.
+    /// `None` when the member isn't a [`Operand::Path`](super::operand::Operand::Path).
+    pub(crate) fn value_path_mut(&mut self) -> Option<&mut JSONPath> {
+        match self {
+            FirstOrder::Contains(ty) => ty.value.path_mut(),
+            FirstOrder::Start(ty) => ty.value.path_mut(),
+            FirstOrder::End(ty) => ty.value.path_mut(),
+            FirstOrder::In(ty) => ty.value.path_mut(),
+            FirstOrder::Test(ty) => ty.value.path_mut(),
+            FirstOrder::Less(ty) => ty.value.path_mut(),
+            FirstOrder::More(ty) => ty.value.path_mut(),
+            _ => None,
+        }
+    }
+
+    /// What the "value" member references, `None` when it's a literal value
+    /// or the operation doesn't take an [`Operand`](super::operand::Operand).
+    pub(crate) fn value_reference(&self) -> Option<Reference<'_>> {
+        match self {
+            FirstOrder::Contains(ty) => ty.value.reference(),
+            FirstOrder::Start(ty) => ty.value.reference(),
+            FirstOrder::End(ty) => ty.value.reference(),
+            FirstOrder::In(ty) => ty.value.reference(),
+            FirstOrder::Test(ty) => ty.value.reference(),
+            FirstOrder::Less(ty) => ty.value.reference(),
+            FirstOrder::More(ty) => ty.value.reference(),
+            _ => None,
+        }
+    }
+
+    /// Evaluate the predicate against the referenced element, `operand` being
+    /// the element or the variable referenced by the "value" member. `None` when `operand`
+    /// isn't of the type the operation expects.
+    pub(crate) fn check_operand(&self, context_value: &Value, operand: &Value) -> Option<Outcome> {
+        fn check<T: OperandValue>(
+            operand: &Value,
+            f: impl FnOnce(&T) -> Outcome,
+        ) -> Option<Outcome> {
+            T::from_json(operand).map(|value| f(&value))
+        }
+
+        match self {
+            FirstOrder::Contains(ty) => check(operand, |value| ty.check(context_value, value)),
+            FirstOrder::Start(ty) => check(operand, |value| ty.check(context_value, value)),
+            FirstOrder::End(ty) => check(operand, |value| ty.check(context_value, value)),
+            FirstOrder::In(ty) => {
+                check(operand, |value: &Vec<Value>| ty.check(context_value, value))
+            }
+            FirstOrder::Test(ty) => check(operand, |value| ty.check(context_value, value)),
+            FirstOrder::Less(ty) => check(operand, |value| ty.check(context_value, value)),
+            FirstOrder::More(ty) => check(operand, |value| ty.check(context_value, value)),
+            _ => None,
+        }
+    }
 }
 
 impl PredicateImpl for FirstOrder {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         match self {
             FirstOrder::Contains(contains) => contains.evaluate(data, ctx),
             FirstOrder::Defined(defined) => defined.evaluate(data, ctx),
@@ -53,6 +215,8 @@ impl PredicateImpl for FirstOrder {
             FirstOrder::Matches(ty) => ty.evaluate(data, ctx),
             FirstOrder::Less(ty) => ty.evaluate(data, ctx),
             FirstOrder::More(ty) => ty.evaluate(data, ctx),
+            FirstOrder::Contained(ty) => ty.evaluate(data, ctx),
+            FirstOrder::Intersects(ty) => ty.evaluate(data, ctx),
         }
     }
 }
@@ -93,9 +257,12 @@ mod tests {
                         },
                     ),
                 ),
+                quantifier: None,
                 ignore_case: false,
-                value: String(
-                    " is a ",
+                value: Value(
+                    String(
+                        " is a ",
+                    ),
                 ),
             },
         )
@@ -8,6 +8,9 @@ use serde::{ser::SerializeStruct, Serialize};
 use crate::json_path::JSONPath;
 use crate::predicate::context::PredicateContext;
 use crate::predicate::error::PredicateError;
+use crate::predicate::operand::Operand;
+use crate::predicate::outcome::Outcome;
+use crate::predicate::quantifier::Quantifier;
 use crate::predicate::PredicateImpl;
 
 use super::FirstOrder;
@@ -24,9 +27,17 @@ pub struct Test {
     /// Must be a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
     /// If the "path" member is not specified within the predicate object, it's value is assumed to be an empty string.
     pub path: Option<JSONPath>,
+    /// When present, `*` and `**` in the "path" member are wildcards and the
+    /// predicate must hold for the matched values as quantified.
+    #[builder(default)]
+    pub quantifier: Option<Quantifier>,
     #[builder(default)]
     pub ignore_case: bool,
-    pub value: serde_json::Value,
+    /// Either a literal value, a JSON Pointer to the value to compare with in
+    /// the evaluated document, written `{"$path": "/a/b"}`, or a variable
+    /// bound in the context, written `{"$var": "name"}`.
+    #[builder(setter(custom))]
+    pub value: Operand<serde_json::Value>,
 }
 
 impl Serialize for Test {
@@ -41,6 +52,9 @@ impl Serialize for Test {
             state.serialize_field("op", "test")?;
         }
         state.serialize_field("path", &self.path)?;
+        if let Some(quantifier) = &self.quantifier {
+            state.serialize_field("quantifier", quantifier)?;
+        }
         state.serialize_field("value", &self.value)?;
         state.end()
     }
@@ -55,6 +69,7 @@ impl<'de> Deserialize<'de> for Test {
         enum Field {
             op,
             path,
+            quantifier,
             value,
             __ignore,
         }
@@ -73,6 +88,7 @@ impl<'de> Deserialize<'de> for Test {
                 match value {
                     "op" => Ok(Field::op),
                     "path" => Ok(Field::path),
+                    "quantifier" => Ok(Field::quantifier),
                     "value" => Ok(Field::value),
                     _ => Ok(Field::__ignore),
                 }
@@ -106,7 +122,8 @@ impl<'de> Deserialize<'de> for Test {
                 A: MapAccess<'de>,
             {
                 let mut path: Option<Option<JSONPath>> = None;
-                let mut value: Option<serde_json::Value> = None;
+                let mut quantifier: Option<Option<Quantifier>> = None;
+                let mut value: Option<Operand<serde_json::Value>> = None;
                 let mut op: Option<String> = None;
 
                 while let Some(key) = MapAccess::next_key::<Field>(&mut map)? {
@@ -123,17 +140,27 @@ impl<'de> Deserialize<'de> for Test {
                             }
                             path = Some(MapAccess::next_value::<Option<JSONPath>>(&mut map)?);
                         }
+                        Field::quantifier => {
+                            if quantifier.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantifier"));
+                            }
+                            quantifier =
+                                Some(MapAccess::next_value::<Option<Quantifier>>(&mut map)?);
+                        }
                         Field::value => {
                             if value.is_some() {
                                 return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            value = Some(MapAccess::next_value::<serde_json::Value>(&mut map)?);
+                            value = Some(MapAccess::next_value::<Operand<serde_json::Value>>(
+                                &mut map,
+                            )?);
                         }
                         Field::__ignore => {}
                     }
                 }
 
                 let path = path.ok_or(serde::de::Error::missing_field("path"))?;
+                let quantifier = quantifier.flatten();
                 let value = value.ok_or(serde::de::Error::missing_field("value"))?;
                 let op = op.ok_or(serde::de::Error::missing_field("op"))?;
 
@@ -149,13 +176,14 @@ impl<'de> Deserialize<'de> for Test {
 
                 Ok(Test {
                     path,
+                    quantifier,
                     ignore_case,
                     value,
                 })
             }
         }
 
-        const FIELDS: &[&str] = &["path", "op", "value"];
+        const FIELDS: &[&str] = &["path", "quantifier", "op", "value"];
         Deserializer::deserialize_struct(
             deserializer,
             "Test",
@@ -168,25 +196,77 @@ impl<'de> Deserialize<'de> for Test {
     }
 }
 
+impl TestBuilder {
+    /// The literal "value" member of the predicate.
+    pub fn value<V: Into<Value>>(mut self, value: V) -> Self {
+        self.value = Some(Operand::Value(value.into()));
+        self
+    }
+
+    /// Compare with the value referenced by `path` in the evaluated
+    /// document instead of a literal value.
+    pub fn value_path(mut self, path: JSONPath) -> Self {
+        self.value = Some(Operand::Path(path));
+        self
+    }
+
+    /// Compare with the value bound to the variable `name` in the context
+    /// instead of a literal value.
+    pub fn value_var(mut self, name: impl Into<String>) -> Self {
+        self.value = Some(Operand::Var(name.into()));
+        self
+    }
+}
+
 impl From<Test> for FirstOrder {
     fn from(value: Test) -> Self {
         FirstOrder::Test(value)
     }
 }
 
-impl PredicateImpl for Test {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
-        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
-        let ptr = path.take();
-
-        let value = &self.value;
-        let context_value = ptr.get(data)?;
+impl Test {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        if self.ignore_case {
+            "test-"
+        } else {
+            "test"
+        }
+    }
 
+    /// Evaluate the predicate against the referenced element, `value` being
+    /// the resolved "value" member.
+    pub(crate) fn check(&self, context_value: &Value, value: &Value) -> Outcome {
         if self.ignore_case {
-            Ok(context_value.to_string().to_lowercase() == value.to_string().to_lowercase())
+            Outcome::from(
+                context_value.to_string().to_lowercase() == value.to_string().to_lowercase(),
+            )
         } else {
-            Ok(context_value == value)
+            Outcome::from(context_value == value)
+        }
+    }
+}
+
+impl PredicateImpl for Test {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
+        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
+        let value = match self.value.resolve(data, &ctx, self.op(), &path) {
+            Ok(value) => value,
+            Err(outcome) => return outcome,
+        };
+
+        if let Some(quantifier) = &self.quantifier {
+            return quantifier.evaluate(&path.tokens(), data, |context_value| {
+                self.check(context_value, &value)
+            });
         }
+
+        let context_value = match path.get(data) {
+            Ok(context_value) => context_value,
+            Err(err) => return PredicateError::new(err.into(), self.op(), path).into(),
+        };
+
+        self.check(context_value, &value)
     }
 }
 
@@ -206,8 +286,9 @@ mod tests {
 
         let end = Test {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
             ignore_case: false,
-            value: serde_json::Value::String("val".to_string()),
+            value: serde_json::Value::String("val".to_string()).into(),
         };
 
         assert_eq!(serde_json::to_value(end).unwrap(), end_expect);
@@ -223,8 +304,9 @@ mod tests {
 
         let end = Test {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
             ignore_case: false,
-            value: serde_json::Value::String("val".to_string()),
+            value: serde_json::Value::String("val".to_string()).into(),
         };
 
         let deser = Test::deserialize(end_expect).unwrap();
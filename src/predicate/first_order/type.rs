@@ -1,6 +1,7 @@
 use chrono::{DateTime, NaiveDate, NaiveTime};
 use derive_builder::Builder;
 use fluent_langneg::{LanguageIdentifier, LangugeIdentifierParserError};
+use iri_string::types::{IriAbsoluteStr, IriReferenceStr};
 use langtag::LanguageTag;
 use serde_json::Value;
 use std::marker::PhantomData;
@@ -12,6 +13,8 @@ use serde::{ser::SerializeStruct, Serialize};
 use crate::json_path::JSONPath;
 use crate::predicate::context::PredicateContext;
 use crate::predicate::error::PredicateError;
+use crate::predicate::outcome::Outcome;
+use crate::predicate::quantifier::Quantifier;
 use crate::predicate::PredicateImpl;
 
 use super::FirstOrder;
@@ -32,12 +35,18 @@ use super::FirstOrder;
 /// - "time"
 /// - "lang"
 /// - "lang-range"
+/// - "iri"
+/// - "absolute-iri"
 #[derive(Debug, Clone, PartialEq, Eq, Builder)]
 #[builder(pattern = "owned", setter(into, strip_option))]
 pub struct Type {
     /// Must be a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
     /// If the "path" member is not specified within the predicate object, it's value is assumed to be an empty string.
     pub path: Option<JSONPath>,
+    /// When present, `*` and `**` in the "path" member are wildcards and the
+    /// predicate must hold for the matched values as quantified.
+    #[builder(default)]
+    pub quantifier: Option<Quantifier>,
     pub value: String,
 }
 
@@ -49,6 +58,9 @@ impl Serialize for Type {
         let mut state = serializer.serialize_struct("Type", 3)?;
         state.serialize_field("op", "type")?;
         state.serialize_field("path", &self.path)?;
+        if let Some(quantifier) = &self.quantifier {
+            state.serialize_field("quantifier", quantifier)?;
+        }
         state.serialize_field("value", &self.value)?;
         state.end()
     }
@@ -71,6 +83,7 @@ impl<'de> Deserialize<'de> for Type {
         enum Field {
             op,
             path,
+            quantifier,
             value,
             __ignore,
         }
@@ -89,6 +102,7 @@ impl<'de> Deserialize<'de> for Type {
                 match value {
                     "op" => Ok(Field::op),
                     "path" => Ok(Field::path),
+                    "quantifier" => Ok(Field::quantifier),
                     "value" => Ok(Field::value),
                     _ => Ok(Field::__ignore),
                 }
@@ -122,6 +136,7 @@ impl<'de> Deserialize<'de> for Type {
                 A: MapAccess<'de>,
             {
                 let mut path: Option<Option<JSONPath>> = None;
+                let mut quantifier: Option<Option<Quantifier>> = None;
                 let mut value: Option<String> = None;
                 let mut op: Option<String> = None;
 
@@ -139,6 +154,13 @@ impl<'de> Deserialize<'de> for Type {
                             }
                             path = Some(MapAccess::next_value::<Option<JSONPath>>(&mut map)?);
                         }
+                        Field::quantifier => {
+                            if quantifier.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantifier"));
+                            }
+                            quantifier =
+                                Some(MapAccess::next_value::<Option<Quantifier>>(&mut map)?);
+                        }
                         Field::value => {
                             if value.is_some() {
                                 return Err(serde::de::Error::duplicate_field("value"));
@@ -150,6 +172,7 @@ impl<'de> Deserialize<'de> for Type {
                 }
 
                 let path = path.ok_or(serde::de::Error::missing_field("path"))?;
+                let quantifier = quantifier.flatten();
                 let value = value.ok_or(serde::de::Error::missing_field("value"))?;
                 let op = op.ok_or(serde::de::Error::missing_field("op"))?;
 
@@ -157,11 +180,15 @@ impl<'de> Deserialize<'de> for Type {
                     return Err(serde::de::Error::custom("`op` should be `type`"));
                 }
 
-                Ok(Type { path, value })
+                Ok(Type {
+                    path,
+                    quantifier,
+                    value,
+                })
             }
         }
 
-        const FIELDS: &[&str] = &["path", "op", "value"];
+        const FIELDS: &[&str] = &["path", "quantifier", "op", "value"];
         Deserializer::deserialize_struct(
             deserializer,
             "Type",
@@ -181,12 +208,33 @@ impl From<Type> for FirstOrder {
 }
 
 impl PredicateImpl for Type {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
-        let ptr = path.take();
 
+        if let Some(quantifier) = &self.quantifier {
+            return quantifier.evaluate(&path.tokens(), data, |context_value| {
+                self.check(Ok::<_, Outcome>(context_value))
+            });
+        }
+
+        self.check(
+            path.get(data)
+                .map_err(|err| PredicateError::new(err.into(), self.op(), path.clone())),
+        )
+    }
+}
+
+impl Type {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        "type"
+    }
+
+    /// Check the type of the referenced element, `context_value` being the
+    /// result of the resolution of the "path" member. The error is only
+    /// converted when the element is missing and the type isn't "undefined".
+    pub(crate) fn check<E: Into<Outcome>>(&self, context_value: Result<&Value, E>) -> Outcome {
         let value = &self.value;
-        let context_value = ptr.get(data);
 
         //   The "value" member MUST specify one of: "number", "string",
         //   "boolean", "object", "array", "null", "undefined", "date",
@@ -194,49 +242,62 @@ impl PredicateImpl for Type {
         //   "iri" or "absolute-iri".
         match (context_value, value) {
             (Err(err), value) => match value.as_str() {
-                "undefined" => Ok(true),
-                _ => Err(err.into()),
+                "undefined" => Outcome::True,
+                _ => err.into(),
             },
             (Ok(context), value) => match value.as_str() {
-                "number" => Ok(context.is_number()),
-                "string" => Ok(context.is_string()),
-                "boolean" => Ok(context.is_boolean()),
-                "object" => Ok(context.is_object()),
-                "array" => Ok(context.is_array()),
-                "null" => Ok(context.is_null()),
+                "number" => context.is_number().into(),
+                "string" => context.is_string().into(),
+                "boolean" => context.is_boolean().into(),
+                "object" => context.is_object().into(),
+                "array" => context.is_array().into(),
+                "null" => context.is_null().into(),
                 "date" => match context {
-                    Value::String(possible_date) => Ok(NaiveDate::from_str(possible_date).is_ok()),
-                    _ => Ok(false),
+                    Value::String(possible_date) => {
+                        NaiveDate::from_str(possible_date).is_ok().into()
+                    }
+                    _ => Outcome::False,
                 },
                 "time" => match context {
                     Value::String(possible_date) => {
                         let possible_time = NaiveTime::parse_from_str(possible_date, "%H:%M:%S%Z");
-                        Ok(possible_time.is_ok())
+                        possible_time.is_ok().into()
                     }
-                    _ => Ok(false),
+                    _ => Outcome::False,
                 },
                 "date-time" => match context {
                     Value::String(possible_date) => {
-                        Ok(DateTime::parse_from_rfc3339(possible_date).is_ok())
+                        DateTime::parse_from_rfc3339(possible_date).is_ok().into()
                     }
-                    _ => Ok(false),
+                    _ => Outcome::False,
                 },
                 "lang" => match context {
-                    Value::String(possible_lang) => Ok(LanguageTag::parse(possible_lang).is_ok()),
-                    _ => Ok(false),
+                    Value::String(possible_lang) => {
+                        LanguageTag::parse(possible_lang).is_ok().into()
+                    }
+                    _ => Outcome::False,
                 },
                 "lang-range" => match context {
                     Value::String(possible_lang_range) => {
-                        // TODO: Support err return
-                        let a = parse_lang_range(possible_lang_range);
-                        dbg!(&a);
-                        Ok(a.is_ok())
+                        parse_lang_range(possible_lang_range).is_ok().into()
+                    }
+                    _ => Outcome::False,
+                },
+                // An IRI reference as described by RFC3987, which can be
+                // relative and have a fragment.
+                "iri" => match context {
+                    Value::String(possible_iri) => {
+                        IriReferenceStr::new(possible_iri).is_ok().into()
                     }
-                    _ => Ok(false),
+                    _ => Outcome::False,
+                },
+                // An absolute IRI as described by RFC3987: a scheme and no
+                // fragment.
+                "absolute-iri" => match context {
+                    Value::String(possible_iri) => IriAbsoluteStr::new(possible_iri).is_ok().into(),
+                    _ => Outcome::False,
                 },
-                "iri" => Err(PredicateError::unimplemented()),
-                "absolute-iri" => Err(PredicateError::unimplemented()),
-                _ => Ok(context == value),
+                _ => Outcome::from(context == value),
             },
         }
     }
@@ -258,6 +319,7 @@ mod tests {
 
         let ty = Type {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
             value: "string".to_string(),
         };
 
@@ -274,6 +336,7 @@ mod tests {
 
         let ty = Type {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
             value: "string".to_string(),
         };
 
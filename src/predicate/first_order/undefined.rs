@@ -7,7 +7,8 @@ use serde_json::Value;
 
 use crate::context::PredicateContext;
 use crate::json_path::JSONPath;
-use crate::predicate::error::PredicateError;
+use crate::predicate::outcome::Outcome;
+use crate::predicate::quantifier::Quantifier;
 use crate::{FirstOrder, PredicateImpl};
 
 /// The "undefined" predicate evaluates as true if the referenced element does
@@ -18,6 +19,10 @@ pub struct Undefined {
     /// Must be a [JSON Pointer](https://tools.ietf.org/html/rfc6901)
     /// If the "path" member is not specified within the predicate object, it's value is assumed to be an empty string.
     pub path: Option<JSONPath>,
+    /// When present, `*` and `**` in the "path" member are wildcards and the
+    /// predicate must hold for the matched values as quantified.
+    #[builder(default)]
+    pub quantifier: Option<Quantifier>,
 }
 
 impl Serialize for Undefined {
@@ -28,6 +33,9 @@ impl Serialize for Undefined {
         let mut state = serializer.serialize_struct("Undefined", 2)?;
         state.serialize_field("op", "undefined")?;
         state.serialize_field("path", &self.path)?;
+        if let Some(quantifier) = &self.quantifier {
+            state.serialize_field("quantifier", quantifier)?;
+        }
         state.end()
     }
 }
@@ -41,6 +49,7 @@ impl<'de> Deserialize<'de> for Undefined {
         enum Field {
             op,
             path,
+            quantifier,
             __ignore,
         }
         struct FieldVisitor;
@@ -58,6 +67,7 @@ impl<'de> Deserialize<'de> for Undefined {
                 match value {
                     "op" => Ok(Field::op),
                     "path" => Ok(Field::path),
+                    "quantifier" => Ok(Field::quantifier),
                     _ => Ok(Field::__ignore),
                 }
             }
@@ -90,6 +100,7 @@ impl<'de> Deserialize<'de> for Undefined {
                 A: MapAccess<'de>,
             {
                 let mut path: Option<Option<JSONPath>> = None;
+                let mut quantifier: Option<Option<Quantifier>> = None;
                 let mut op: Option<String> = None;
 
                 while let Some(key) = MapAccess::next_key::<Field>(&mut map)? {
@@ -106,22 +117,30 @@ impl<'de> Deserialize<'de> for Undefined {
                             }
                             path = Some(MapAccess::next_value::<Option<JSONPath>>(&mut map)?);
                         }
+                        Field::quantifier => {
+                            if quantifier.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quantifier"));
+                            }
+                            quantifier =
+                                Some(MapAccess::next_value::<Option<Quantifier>>(&mut map)?);
+                        }
                         Field::__ignore => {}
                     }
                 }
 
                 let path = path.ok_or(serde::de::Error::missing_field("path"))?;
+                let quantifier = quantifier.flatten();
                 let op = op.ok_or(serde::de::Error::missing_field("op"))?;
 
                 if op != "undefined" {
                     return Err(serde::de::Error::custom("`op` should be `undefined`"));
                 }
 
-                Ok(Undefined { path })
+                Ok(Undefined { path, quantifier })
             }
         }
 
-        const FIELDS: &[&str] = &["path", "op"];
+        const FIELDS: &[&str] = &["path", "quantifier", "op"];
         Deserializer::deserialize_struct(
             deserializer,
             "Undefined",
@@ -140,12 +159,23 @@ impl From<Undefined> for FirstOrder {
     }
 }
 
+impl Undefined {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        "undefined"
+    }
+}
+
 impl PredicateImpl for Undefined {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
-        let ptr = path.take();
 
-        Ok(ptr.get(data).is_err())
+        // No value matched by a quantified path is undefined.
+        if let Some(quantifier) = &self.quantifier {
+            return quantifier.evaluate(&path.tokens(), data, |_| Outcome::False);
+        }
+
+        path.get(data).is_err().into()
     }
 }
 
@@ -164,6 +194,7 @@ mod tests {
 
         let undefined = Undefined {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
         };
 
         assert_eq!(serde_json::to_value(undefined).unwrap(), undefined_expect);
@@ -178,6 +209,7 @@ mod tests {
 
         let undefined = Undefined {
             path: Some(JSONPath::new("/a/b").unwrap()),
+            quantifier: None,
         };
 
         let deser = Undefined::deserialize(undefined_expect).unwrap();
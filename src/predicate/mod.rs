@@ -1,15 +1,32 @@
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
 pub mod first_order;
 use first_order::FirstOrder;
 use serde_json::Value;
 
-use self::{context::PredicateContext, error::PredicateError, second_order::SecondOrder};
+use crate::json_path::JSONPath;
 
+use self::{
+    context::PredicateContext, outcome::Outcome, registry::Extension, second_order::SecondOrder,
+};
+
+pub mod analysis;
+pub mod compiled;
 pub mod context;
+mod deserialize;
+mod example;
+pub mod explain;
+pub mod operand;
+pub mod outcome;
+pub mod quantifier;
+pub mod registry;
 pub mod second_order;
+pub mod set;
+mod simplify;
+pub mod syntax;
 
-mod error;
+pub(crate) mod convert;
+pub(crate) mod error;
 
 /// A Predicate is an Object whose members describe a testable
 /// condition that evaluates as either true or false.
@@ -72,18 +89,42 @@ mod error;
 /// {"path": "/a/b/c", "op": "contains", "value": "ABC"}
 /// {"value": "ABC", "path": "/a/b/c", "op": "contains"}
 /// ```
-#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum Predicate {
     FirstOrder(FirstOrder),
     SecondOrder(SecondOrder),
+    /// An operation added by the application, see
+    /// [`OperatorRegistry`](registry::OperatorRegistry).
+    Extension(Extension),
+}
+
+impl Predicate {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        match self {
+            Predicate::FirstOrder(fo) => fo.op(),
+            Predicate::SecondOrder(so) => so.op(),
+            Predicate::Extension(ext) => ext.op(),
+        }
+    }
+
+    /// The "path" member of the predicate.
+    pub fn path(&self) -> Option<&JSONPath> {
+        match self {
+            Predicate::FirstOrder(fo) => fo.path(),
+            Predicate::SecondOrder(so) => so.path(),
+            Predicate::Extension(ext) => ext.path(),
+        }
+    }
 }
 
 impl PredicateImpl for Predicate {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         match self {
             Predicate::FirstOrder(fo) => fo.evaluate(data, ctx),
             Predicate::SecondOrder(fo) => fo.evaluate(data, ctx),
+            Predicate::Extension(ext) => ext.evaluate(data, ctx),
         }
     }
 }
@@ -91,13 +132,13 @@ impl PredicateImpl for Predicate {
 pub trait PredicateImpl {
     /// Evaluate the predicate against the provided JSON
     ///
-    /// The error result means the predicate couldn't be evaluated properly so
-    /// you have to assume it's a false result and you'll also have more information about why it
-    /// didn't validate.
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError>;
+    /// The [`Outcome::Error`] result means the predicate couldn't be evaluated
+    /// properly so you have to assume it's a false result and you'll also have
+    /// more information about why it didn't validate.
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome;
 
     /// Evaluate the predicate against the provided JSON
     fn test(&self, data: &Value, ctx: PredicateContext) -> bool {
-        self.evaluate(data, ctx).unwrap_or(false)
+        self.evaluate(data, ctx).is_true()
     }
 }
@@ -0,0 +1,297 @@
+use std::borrow::Cow;
+
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+use serde::ser::SerializeMap;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::comparable::Comparable;
+use crate::json_path::JSONPath;
+
+use super::context::PredicateContext;
+use super::deserialize::ValueDeserializer;
+use super::error::{PredicateError, PredicateErrorKind};
+use super::outcome::Outcome;
+
+/// The "value" member of the "test", "less", "more", "in", "contains",
+/// "start" and "end" predicates.
+///
+/// Instead of a literal value, the member can reference another location of
+/// the evaluated document with a JSON Pointer, resolved from the same
+/// [`PredicateContext`] as the "path" member, or a variable bound in the
+/// context:
+///
+/// ```json
+/// {"op": "more", "path": "/endDate", "value": {"$path": "/startDate"}}
+/// {"op": "test", "path": "/owner", "value": {"$var": "userId"}}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operand<T> {
+    /// A literal value.
+    Value(T),
+    /// The value referenced by a JSON Pointer in the evaluated document.
+    Path(JSONPath),
+    /// The value bound to a variable in the [`PredicateContext`].
+    Var(String),
+}
+
+/// What an [`Operand`] which isn't a literal value references.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Reference<'a> {
+    Path(&'a JSONPath),
+    Var(&'a str),
+}
+
+/// The values an [`Operand`] can hold, converted from the referenced
+/// element when the operand is a [`Operand::Path`].
+pub(crate) trait OperandValue: Clone {
+    /// `None` when the referenced element isn't of the expected type.
+    fn from_json(value: &Value) -> Option<Cow<'_, Self>>;
+}
+
+impl OperandValue for Value {
+    fn from_json(value: &Value) -> Option<Cow<'_, Self>> {
+        Some(Cow::Borrowed(value))
+    }
+}
+
+impl OperandValue for Vec<Value> {
+    fn from_json(value: &Value) -> Option<Cow<'_, Self>> {
+        value.as_array().map(Cow::Borrowed)
+    }
+}
+
+impl OperandValue for Comparable {
+    fn from_json(value: &Value) -> Option<Cow<'_, Self>> {
+        Comparable::deserialize(value).ok().map(Cow::Owned)
+    }
+}
+
+impl<T> Operand<T> {
+    /// The literal value, `None` when the operand references another
+    /// location.
+    pub fn literal(&self) -> Option<&T> {
+        match self {
+            Operand::Value(value) => Some(value),
+            Operand::Path(_) | Operand::Var(_) => None,
+        }
+    }
+
+    /// The JSON Pointer of the referenced location, `None` for a literal
+    /// value.
+    pub fn path(&self) -> Option<&JSONPath> {
+        match self {
+            Operand::Path(path) => Some(path),
+            Operand::Value(_) | Operand::Var(_) => None,
+        }
+    }
+
+    /// The JSON Pointer of the referenced location, to rewrite it.
+    pub(crate) fn path_mut(&mut self) -> Option<&mut JSONPath> {
+        match self {
+            Operand::Path(path) => Some(path),
+            Operand::Value(_) | Operand::Var(_) => None,
+        }
+    }
+
+    /// The name of the referenced variable, `None` for a literal value or a
+    /// JSON Pointer.
+    pub fn var(&self) -> Option<&str> {
+        match self {
+            Operand::Var(name) => Some(name),
+            Operand::Value(_) | Operand::Path(_) => None,
+        }
+    }
+
+    /// The literal value or the value of the referenced variable, for a
+    /// translation which doesn't see the evaluated document. `None` when the
+    /// operand references a location, or the variable isn't bound or isn't of
+    /// the expected type.
+    pub(crate) fn bound<'a>(&'a self, ctx: &'a PredicateContext) -> Option<Cow<'a, T>>
+    where
+        T: OperandValue,
+    {
+        match self {
+            Operand::Value(value) => Some(Cow::Borrowed(value)),
+            Operand::Var(name) => ctx.variable(name).and_then(T::from_json),
+            Operand::Path(_) => None,
+        }
+    }
+
+    /// What the operand references, `None` for a literal value.
+    pub(crate) fn reference(&self) -> Option<Reference<'_>> {
+        match self {
+            Operand::Value(_) => None,
+            Operand::Path(path) => Some(Reference::Path(path)),
+            Operand::Var(name) => Some(Reference::Var(name)),
+        }
+    }
+
+    /// The value to compare the referenced element with, an error being
+    /// reported for `op` when the operand references a location or a variable
+    /// which doesn't exist or isn't of the expected type.
+    ///
+    /// Errors about a variable are reported at `path`, the absolute path of
+    /// the predicate.
+    pub(crate) fn resolve<'a>(
+        &'a self,
+        data: &'a Value,
+        ctx: &'a PredicateContext,
+        op: &'static str,
+        path: &JSONPath,
+    ) -> Result<Cow<'a, T>, Outcome>
+    where
+        T: OperandValue,
+    {
+        let (value, path) = match self {
+            Operand::Value(value) => return Ok(Cow::Borrowed(value)),
+            Operand::Path(operand) => {
+                let operand = ctx
+                    .final_path(&Some(operand.clone()))
+                    .unwrap_or(JSONPath::empty());
+                match operand.get(data) {
+                    Ok(value) => (value, operand),
+                    Err(err) => return Err(PredicateError::new(err.into(), op, operand).into()),
+                }
+            }
+            Operand::Var(name) => match ctx.variable(name) {
+                Some(value) => (value, path.clone()),
+                None => {
+                    let kind = PredicateErrorKind::UndefinedVariable { name: name.clone() };
+                    return Err(PredicateError::new(kind, op, path.clone()).into());
+                }
+            },
+        };
+
+        T::from_json(value)
+            .ok_or_else(|| PredicateError::new(PredicateErrorKind::IncorrectType, op, path).into())
+    }
+}
+
+impl<T> From<T> for Operand<T> {
+    fn from(value: T) -> Self {
+        Operand::Value(value)
+    }
+}
+
+impl<T: Serialize> Serialize for Operand<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Operand::Value(value) => value.serialize(serializer),
+            Operand::Path(path) => {
+                let mut state = serializer.serialize_map(Some(1))?;
+                state.serialize_entry("$path", path)?;
+                state.end()
+            }
+            Operand::Var(name) => {
+                let mut state = serializer.serialize_map(Some(1))?;
+                state.serialize_entry("$var", name)?;
+                state.end()
+            }
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Operand<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        // An object whose only member is "$path" references another location,
+        // and one whose only member is "$var" a variable.
+        if let Value::Object(map) = &value {
+            if let (1, Some(path)) = (map.len(), map.get("$path")) {
+                return JSONPath::deserialize(ValueDeserializer::new(path)).map(Operand::Path);
+            }
+            if let (1, Some(name)) = (map.len(), map.get("$var")) {
+                return String::deserialize(ValueDeserializer::new(name)).map(Operand::Var);
+            }
+        }
+
+        T::deserialize(ValueDeserializer::new(&value)).map(Operand::Value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use serde::Deserialize;
+    use serde_json::Value;
+
+    use crate::context::PredicateContext;
+    use crate::json_path::JSONPath;
+    use crate::predicate::operand::Operand;
+    use crate::Comparable;
+
+    #[test]
+    fn deser_test() {
+        let path = Operand::<Value>::deserialize(serde_json::json!({ "$path": "/a/b" })).unwrap();
+        assert_eq!(path, Operand::Path(JSONPath::new("/a/b").unwrap()));
+        assert_eq!(
+            serde_json::to_value(path).unwrap(),
+            serde_json::json!({ "$path": "/a/b" })
+        );
+
+        let value =
+            Operand::<Value>::deserialize(serde_json::json!({ "$path": "/a", "b": 1 })).unwrap();
+        assert_eq!(
+            value,
+            Operand::Value(serde_json::json!({ "$path": "/a", "b": 1 }))
+        );
+
+        assert!(Operand::<Comparable>::deserialize(serde_json::json!("nope")).is_err());
+        assert!(Operand::<Vec<Value>>::deserialize(serde_json::json!({ "$path": 1 })).is_err());
+
+        let var = Operand::<Comparable>::deserialize(serde_json::json!({ "$var": "now" })).unwrap();
+        assert_eq!(var, Operand::Var("now".to_string()));
+        assert_eq!(
+            serde_json::to_value(var).unwrap(),
+            serde_json::json!({ "$var": "now" })
+        );
+    }
+
+    #[test]
+    fn resolve_test() {
+        let data = serde_json::json!({ "a": { "b": [1, 2] } });
+        let ctx = PredicateContext::new("/a".to_string())
+            .unwrap()
+            .with_variable("n", 1);
+        let path = JSONPath::new("/a").unwrap();
+
+        let array = Operand::<Vec<Value>>::Path(JSONPath::new("/b").unwrap());
+        assert!(matches!(
+            array.resolve(&data, &ctx, "in", &path),
+            Ok(Cow::Borrowed(values)) if values.len() == 2
+        ));
+
+        let comparable = Operand::<Comparable>::Path(JSONPath::new("/b").unwrap());
+        assert!(comparable
+            .resolve(&data, &ctx, "less", &path)
+            .unwrap_err()
+            .is_error());
+
+        let missing = Operand::<Value>::Path(JSONPath::new("/c").unwrap());
+        assert!(missing
+            .resolve(&data, &ctx, "test", &path)
+            .unwrap_err()
+            .is_error());
+
+        let var = Operand::<Comparable>::Var("n".to_string());
+        assert_eq!(
+            var.resolve(&data, &ctx, "less", &path).unwrap().as_ref(),
+            &Comparable::from(1)
+        );
+
+        let undefined = Operand::<Value>::Var("m".to_string());
+        assert!(undefined
+            .resolve(&data, &ctx, "test", &path)
+            .unwrap_err()
+            .is_error());
+    }
+}
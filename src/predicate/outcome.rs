@@ -0,0 +1,180 @@
+use super::error::PredicateError;
+
+/// The result of the evaluation of a predicate against a JSON document.
+///
+/// Unlike a plain boolean, it distinguishes a document which doesn't satisfy
+/// the predicate ([`Outcome::False`]) from a predicate which couldn't be
+/// evaluated against the document ([`Outcome::Error`]), e.g. because it
+/// references a value that does not exist.
+///
+/// As described by the specification, an [`Outcome::Error`] MUST be handled
+/// as if a boolean value of "false" was returned, which is what
+/// [`PredicateImpl::test`](super::PredicateImpl::test) does.
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    /// The document satisfies the predicate.
+    True,
+    /// The document doesn't satisfy the predicate.
+    False,
+    /// The predicate couldn't be evaluated against the document.
+    Error(PredicateError),
+}
+
+impl Outcome {
+    pub fn is_true(&self) -> bool {
+        matches!(self, Outcome::True)
+    }
+
+    pub fn is_false(&self) -> bool {
+        matches!(self, Outcome::False)
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self, Outcome::Error(_))
+    }
+
+    /// Converts the outcome into an `Option<PredicateError>`, discarding the
+    /// boolean result if any.
+    pub fn err(self) -> Option<PredicateError> {
+        match self {
+            Outcome::Error(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    pub fn into_result(self) -> Result<bool, PredicateError> {
+        self.into()
+    }
+
+    /// The "and" combination: "false" if any outcome is "false", otherwise the
+    /// first error if any, otherwise "true".
+    ///
+    /// Stops at the first "false" outcome.
+    pub(crate) fn all<I: IntoIterator<Item = Outcome>>(outcomes: I) -> Outcome {
+        let mut error = None;
+
+        for outcome in outcomes {
+            match outcome {
+                Outcome::True => {}
+                Outcome::False => return Outcome::False,
+                Outcome::Error(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+
+        error.map_or(Outcome::True, Outcome::Error)
+    }
+
+    /// The "or" combination: "true" if any outcome is "true", otherwise the
+    /// first error if any, otherwise "false".
+    ///
+    /// Stops at the first "true" outcome, an empty set of outcomes is "true".
+    pub(crate) fn any<I: IntoIterator<Item = Outcome>>(outcomes: I) -> Outcome {
+        let mut error = None;
+        let mut empty = true;
+
+        for outcome in outcomes {
+            empty = false;
+            match outcome {
+                Outcome::True => return Outcome::True,
+                Outcome::False => {}
+                Outcome::Error(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+
+        match error {
+            Some(err) => Outcome::Error(err),
+            None if empty => Outcome::True,
+            None => Outcome::False,
+        }
+    }
+
+    /// The "not" combination: "false" if any outcome is "true", otherwise the
+    /// first error if any, otherwise "true".
+    ///
+    /// Stops at the first "true" outcome.
+    pub(crate) fn none<I: IntoIterator<Item = Outcome>>(outcomes: I) -> Outcome {
+        Outcome::all(outcomes.into_iter().map(std::ops::Not::not))
+    }
+}
+
+impl std::ops::Not for Outcome {
+    type Output = Outcome;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Outcome::True => Outcome::False,
+            Outcome::False => Outcome::True,
+            Outcome::Error(err) => Outcome::Error(err),
+        }
+    }
+}
+
+impl From<bool> for Outcome {
+    fn from(value: bool) -> Self {
+        if value {
+            Outcome::True
+        } else {
+            Outcome::False
+        }
+    }
+}
+
+impl From<PredicateError> for Outcome {
+    fn from(value: PredicateError) -> Self {
+        Outcome::Error(value)
+    }
+}
+
+impl From<Result<bool, PredicateError>> for Outcome {
+    fn from(value: Result<bool, PredicateError>) -> Self {
+        match value {
+            Ok(value) => value.into(),
+            Err(err) => err.into(),
+        }
+    }
+}
+
+impl From<Outcome> for Result<bool, PredicateError> {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::True => Ok(true),
+            Outcome::False => Ok(false),
+            Outcome::Error(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json_path::JSONPath;
+    use crate::predicate::error::{PredicateError, PredicateErrorKind};
+    use crate::predicate::outcome::Outcome;
+
+    #[test]
+    fn combination_test() {
+        let error = || {
+            Outcome::Error(PredicateError::new(
+                PredicateErrorKind::IncorrectType,
+                "matches-",
+                JSONPath::empty(),
+            ))
+        };
+
+        assert!(Outcome::all([Outcome::True, Outcome::True]).is_true());
+        assert!(Outcome::all([error(), Outcome::False]).is_false());
+        assert!(Outcome::all([Outcome::True, error()]).is_error());
+
+        assert!(Outcome::any([Outcome::False, Outcome::True]).is_true());
+        assert!(Outcome::any([error(), Outcome::True]).is_true());
+        assert!(Outcome::any([Outcome::False, error()]).is_error());
+        assert!(Outcome::any([Outcome::False, Outcome::False]).is_false());
+
+        assert!(Outcome::none([Outcome::False, Outcome::False]).is_true());
+        assert!(Outcome::none([error(), Outcome::True]).is_false());
+        assert!(Outcome::none([Outcome::False, error()]).is_error());
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::ControlFlow;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::json_path::visit_matches;
+
+use super::error::PredicateError;
+use super::outcome::Outcome;
+
+/// The "quantifier" member of a First Order predicate.
+///
+/// When it's present, `*` in the "path" member matches every element of an
+/// array or member of an object and `**` any number of levels, including
+/// none, and the quantifier tells how many of the matched values must
+/// satisfy the predicate.
+///
+/// ```json
+/// {"op": "test", "path": "/items/*/status", "value": "shipped", "quantifier": "any"}
+/// ```
+///
+/// It's serialized as `"any"`, `"all"`, `"none"` or, for a count range, as
+/// `{"min": 1, "max": 3}`, both bounds being inclusive and optional.
+///
+/// As only existing values are matched, a "defined" predicate holds for
+/// every match and an "undefined" one for none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantifier {
+    /// At least one matched value satisfies the predicate.
+    Any,
+    /// Every matched value satisfies the predicate, which holds when nothing
+    /// matches.
+    All,
+    /// No matched value satisfies the predicate.
+    None,
+    /// The number of matched values satisfying the predicate is within the
+    /// range.
+    Count { min: usize, max: Option<usize> },
+}
+
+impl Quantifier {
+    /// Evaluate `f` against the values matched by the reference tokens,
+    /// stopping as soon as the outcome is known.
+    ///
+    /// Like [`Outcome::all`] and [`Outcome::any`], an error is only reported
+    /// when it could change the outcome.
+    pub(crate) fn evaluate<'json, S>(
+        &self,
+        tokens: &[S],
+        data: &'json Value,
+        mut f: impl FnMut(&'json Value) -> Outcome,
+    ) -> Outcome
+    where
+        S: AsRef<str>,
+    {
+        let mut satisfied = 0;
+        let mut unsatisfied = 0;
+        let mut error: Option<PredicateError> = None;
+
+        let _ = visit_matches(tokens, data, &mut |value| {
+            match f(value) {
+                Outcome::True => satisfied += 1,
+                Outcome::False => unsatisfied += 1,
+                Outcome::Error(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+
+            if self.decided(satisfied, unsatisfied).is_some() {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+
+        match (self.decided(satisfied, unsatisfied), error) {
+            (Some(decided), _) => decided.into(),
+            (None, Some(err)) => err.into(),
+            (None, None) => match *self {
+                Quantifier::Any => Outcome::False,
+                Quantifier::All | Quantifier::None => Outcome::True,
+                Quantifier::Count { min, .. } => Outcome::from(satisfied >= min),
+            },
+        }
+    }
+
+    /// The outcome if the remaining matches can't change it.
+    fn decided(&self, satisfied: usize, unsatisfied: usize) -> Option<bool> {
+        match *self {
+            Quantifier::Any if satisfied > 0 => Some(true),
+            Quantifier::None if satisfied > 0 => Some(false),
+            Quantifier::All if unsatisfied > 0 => Some(false),
+            Quantifier::Count { max: Some(max), .. } if satisfied > max => Some(false),
+            Quantifier::Count { min, max: None } if satisfied >= min => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Quantifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Quantifier::Any => f.write_str("any"),
+            Quantifier::All => f.write_str("all"),
+            Quantifier::None => f.write_str("none"),
+            Quantifier::Count { min, max: None } => write!(f, "count({min}..)"),
+            Quantifier::Count {
+                min,
+                max: Some(max),
+            } => write!(f, "count({min}..{max})"),
+        }
+    }
+}
+
+impl Serialize for Quantifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Quantifier::Any => serializer.serialize_str("any"),
+            Quantifier::All => serializer.serialize_str("all"),
+            Quantifier::None => serializer.serialize_str("none"),
+            Quantifier::Count { min, max } => {
+                let mut state = serializer.serialize_map(None)?;
+                state.serialize_entry("min", min)?;
+                if let Some(max) = max {
+                    state.serialize_entry("max", max)?;
+                }
+                state.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Quantifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct VisitorQuantifier<'de> {
+            marker: PhantomData<Quantifier>,
+            lifetime: PhantomData<&'de ()>,
+        }
+
+        impl<'de> Visitor<'de> for VisitorQuantifier<'de> {
+            type Value = Quantifier;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("\"any\", \"all\", \"none\" or a count range")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match v {
+                    "any" => Ok(Quantifier::Any),
+                    "all" => Ok(Quantifier::All),
+                    "none" => Ok(Quantifier::None),
+                    _ => Err(de::Error::invalid_value(de::Unexpected::Str(v), &self)),
+                }
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut min: Option<usize> = None;
+                let mut max: Option<Option<usize>> = None;
+
+                while let Some(key) = MapAccess::next_key::<String>(&mut map)? {
+                    match key.as_str() {
+                        "min" => {
+                            if min.is_some() {
+                                return Err(de::Error::duplicate_field("min"));
+                            }
+                            min = Some(MapAccess::next_value::<usize>(&mut map)?);
+                        }
+                        "max" => {
+                            if max.is_some() {
+                                return Err(de::Error::duplicate_field("max"));
+                            }
+                            max = Some(MapAccess::next_value::<Option<usize>>(&mut map)?);
+                        }
+                        _ => {
+                            MapAccess::next_value::<de::IgnoredAny>(&mut map)?;
+                        }
+                    }
+                }
+
+                let min = min.unwrap_or(0);
+                let max = max.flatten();
+                if max.is_some_and(|max| max < min) {
+                    return Err(de::Error::custom("`max` should be greater than `min`"));
+                }
+                Ok(Quantifier::Count { min, max })
+            }
+        }
+
+        Deserializer::deserialize_any(
+            deserializer,
+            VisitorQuantifier {
+                marker: PhantomData::<Quantifier>,
+                lifetime: PhantomData,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::json_path::JSONPath;
+    use crate::predicate::error::{PredicateError, PredicateErrorKind};
+    use crate::predicate::outcome::Outcome;
+    use crate::predicate::quantifier::Quantifier;
+
+    #[test]
+    fn deser_test() {
+        let any = Quantifier::deserialize(serde_json::json!("any")).unwrap();
+        assert_eq!(any, Quantifier::Any);
+
+        let count = Quantifier::deserialize(serde_json::json!({ "min": 1, "max": 3 })).unwrap();
+        assert_eq!(
+            count,
+            Quantifier::Count {
+                min: 1,
+                max: Some(3)
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(count).unwrap(),
+            serde_json::json!({ "min": 1, "max": 3 })
+        );
+
+        let at_least = Quantifier::deserialize(serde_json::json!({ "min": 2 })).unwrap();
+        assert_eq!(at_least, Quantifier::Count { min: 2, max: None });
+
+        assert!(Quantifier::deserialize(serde_json::json!("some")).is_err());
+        assert!(Quantifier::deserialize(serde_json::json!({ "min": 3, "max": 1 })).is_err());
+    }
+
+    #[test]
+    fn evaluate_test() {
+        let data = serde_json::json!({ "a": [1, 2, 3], "b": { "c": [{ "d": 4 }, { "d": 5 }] } });
+        let evaluate = |quantifier: Quantifier, pointer: &[&str], min: i64| {
+            quantifier.evaluate(pointer, &data, |value| {
+                Outcome::from(value.as_i64().is_some_and(|value| value >= min))
+            })
+        };
+
+        assert!(evaluate(Quantifier::Any, &["a", "*"], 3).is_true());
+        assert!(evaluate(Quantifier::All, &["a", "*"], 2).is_false());
+        assert!(evaluate(Quantifier::All, &["a", "*"], 1).is_true());
+        assert!(evaluate(Quantifier::None, &["a", "*"], 4).is_true());
+        assert!(evaluate(Quantifier::Any, &["nope", "*"], 0).is_false());
+        assert!(evaluate(Quantifier::All, &["nope", "*"], 0).is_true());
+        assert!(evaluate(Quantifier::All, &["**", "d"], 4).is_true());
+        assert!(evaluate(Quantifier::Any, &["**", "d"], 5).is_true());
+        let count = |min, max| Quantifier::Count { min, max };
+        assert!(evaluate(count(2, Some(2)), &["a", "*"], 2).is_true());
+        assert!(evaluate(count(0, Some(1)), &["a", "*"], 2).is_false());
+        assert!(evaluate(count(5, None), &["**"], 0).is_true());
+        assert!(evaluate(count(6, None), &["**"], 0).is_false());
+
+        let mut visited = 0;
+        let outcome = count(2, None).evaluate(&["a", "*"], &data, |_| {
+            visited += 1;
+            Outcome::True
+        });
+        assert!(outcome.is_true());
+        assert_eq!(visited, 2);
+
+        // The first match can't be evaluated.
+        let data = serde_json::json!({ "a": ["x", 1, 2] });
+        let evaluate = |quantifier: Quantifier, min: i64| {
+            quantifier.evaluate(&["a", "*"], &data, |value| match value.as_i64() {
+                Some(value) => Outcome::from(value >= min),
+                None => PredicateError::new(
+                    PredicateErrorKind::IncorrectType,
+                    "more",
+                    JSONPath::empty(),
+                )
+                .into(),
+            })
+        };
+
+        assert!(evaluate(Quantifier::Any, 1).is_true());
+        assert!(evaluate(count(1, None), 1).is_true());
+        assert!(evaluate(count(2, None), 1).is_true());
+        assert!(evaluate(Quantifier::None, 2).is_false());
+        assert!(evaluate(Quantifier::All, 3).is_false());
+        assert!(evaluate(Quantifier::Any, 3).is_error());
+        assert!(evaluate(Quantifier::All, 1).is_error());
+        assert!(evaluate(count(2, None), 2).is_error());
+        assert!(evaluate(count(0, Some(1)), 2).is_error());
+    }
+}
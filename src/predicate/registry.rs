@@ -0,0 +1,271 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::json_path::JSONPath;
+
+use super::context::PredicateContext;
+use super::deserialize::ValueDeserializer;
+use super::error::{DeserializeError, DeserializeErrorKind};
+use super::outcome::Outcome;
+use super::syntax::SyntaxError;
+use super::{Predicate, PredicateImpl};
+
+type Constructor = fn(&Value) -> Result<Arc<dyn PredicateImpl + Send + Sync>, DeserializeError>;
+
+thread_local! {
+    /// The registry resolving the extension operations of the predicates
+    /// being deserialized on this thread.
+    static CURRENT: RefCell<Option<OperatorRegistry>> = const { RefCell::new(None) };
+}
+
+/// The operations an application adds to the ones of the specification.
+///
+/// Each operation is registered under the name of its "op" member with a
+/// type deserialized from the predicate object and evaluated through its
+/// [`PredicateImpl`] implementation. Predicates deserialized or parsed with
+/// the registry can use them as any other operation, they are then
+/// [`Predicate::Extension`] predicates.
+///
+/// ```rust
+/// # use json_predicate::context::PredicateContext;
+/// # use json_predicate::json_path::JSONPath;
+/// # use json_predicate::{OperatorRegistry, Outcome, PredicateImpl};
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Even {
+///     path: Option<JSONPath>,
+/// }
+///
+/// impl PredicateImpl for Even {
+///     fn evaluate(&self, data: &serde_json::Value, ctx: PredicateContext) -> Outcome {
+///         let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
+///         let value = path.get(data).ok().and_then(|value| value.as_i64());
+///         value.is_some_and(|value| value % 2 == 0).into()
+///     }
+/// }
+///
+/// let mut registry = OperatorRegistry::new();
+/// registry.register::<Even>("even");
+///
+/// let predicate = registry.deserialize(serde_json::json!({
+///     "op": "not",
+///     "apply": [{ "op": "even", "path": "/a" }]
+/// }))?;
+/// assert!(predicate.test(&serde_json::json!({ "a": 3 }), PredicateContext::default()));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// The operations of the specification take precedence over the registered
+/// ones.
+///
+/// The registry only resolves the operations of the predicates deserialized
+/// or parsed through it, on the calling thread: a predicate deserialized
+/// with [`Predicate`]'s [`Deserialize`] implementation, e.g. with
+/// `serde_json::from_str`, can't use them unless it's nested in a call to
+/// [`OperatorRegistry::deserialize`].
+#[derive(Clone, Default)]
+pub struct OperatorRegistry {
+    operators: Arc<HashMap<&'static str, Constructor>>,
+}
+
+impl OperatorRegistry {
+    /// A registry without any operation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the operation `name`, its predicates being deserialized as
+    /// `P`. A previous operation with the same name is replaced.
+    pub fn register<P>(&mut self, name: &'static str) -> &mut Self
+    where
+        P: DeserializeOwned + PredicateImpl + Send + Sync + 'static,
+    {
+        Arc::make_mut(&mut self.operators).insert(name, |value| {
+            Ok(Arc::new(P::deserialize(ValueDeserializer::new(value))?))
+        });
+        self
+    }
+
+    /// Whether the operation `name` is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.operators.contains_key(name)
+    }
+
+    /// Deserialize a predicate which can use the registered operations.
+    pub fn deserialize<'de, D>(&self, deserializer: D) -> Result<Predicate, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.scope(|| Predicate::deserialize(deserializer))
+    }
+
+    /// Deserialize the predicate object `value`, which can use the
+    /// registered operations, with an error telling what was wrong and
+    /// where, as [`Predicate::from_value`].
+    pub fn from_value(&self, value: &Value) -> Result<Predicate, DeserializeError> {
+        self.scope(|| Predicate::from_value(value))
+    }
+
+    /// Parse a predicate written in the [text syntax](crate::syntax) which
+    /// can use the registered operations.
+    pub fn parse(&self, text: &str) -> Result<Predicate, SyntaxError> {
+        self.scope(|| text.parse())
+    }
+
+    /// Run `f` with this registry resolving the extension operations.
+    fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        /// Restores the previous registry, even if `f` panics.
+        struct Restore(Option<OperatorRegistry>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.with(|current| current.replace(Some(self.clone()))));
+        f()
+    }
+}
+
+impl Debug for OperatorRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.operators.keys()).finish()
+    }
+}
+
+/// A predicate whose operation was registered in an [`OperatorRegistry`].
+///
+/// It's serialized as the predicate object it was deserialized from.
+#[derive(Clone)]
+pub struct Extension {
+    op: &'static str,
+    path: Option<JSONPath>,
+    value: Value,
+    predicate: Arc<dyn PredicateImpl + Send + Sync>,
+}
+
+impl Extension {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        self.op
+    }
+
+    /// The "path" member of the predicate.
+    pub fn path(&self) -> Option<&JSONPath> {
+        self.path.as_ref()
+    }
+
+    /// The predicate object.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+}
+
+impl PredicateImpl for Extension {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
+        self.predicate.evaluate(data, ctx)
+    }
+}
+
+impl Debug for Extension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Extension")
+            .field("op", &self.op)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl PartialEq for Extension {
+    fn eq(&self, other: &Self) -> bool {
+        self.op == other.op && self.value == other.value
+    }
+}
+
+impl Eq for Extension {}
+
+impl Serialize for Extension {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl Extension {
+    /// Deserialize the predicate object `value` with the operation registered
+    /// in the registry in scope.
+    pub(crate) fn resolve(value: &Value) -> Result<Self, DeserializeError> {
+        let Some(op) = value.get("op").and_then(Value::as_str) else {
+            return Err(DeserializeError::missing_field("op"));
+        };
+
+        let operator = CURRENT.with(|current| {
+            let current = current.borrow();
+            let operators = &current.as_ref()?.operators;
+            Some(
+                operators
+                    .get_key_value(op)
+                    .map(|(op, constructor)| (*op, *constructor)),
+            )
+        });
+        let (op, constructor) = match operator {
+            Some(Some(operator)) => operator,
+            Some(None) => return Err(unknown(op, format_args!("unknown op `{op}`"))),
+            None => {
+                return Err(unknown(
+                    op,
+                    format_args!(
+                        "unknown op `{op}`, no OperatorRegistry is in scope to resolve it"
+                    ),
+                ))
+            }
+        };
+
+        let path = match value.get("path") {
+            None | Some(Value::Null) => None,
+            Some(path) => Some(
+                JSONPath::deserialize(ValueDeserializer::<DeserializeError>::new(path))
+                    .map_err(|err| err.at("/path"))?,
+            ),
+        };
+        let predicate = constructor(value)?;
+
+        Ok(Extension {
+            op,
+            path,
+            value: value.clone(),
+            predicate,
+        })
+    }
+}
+
+/// The operation `op` isn't registered.
+fn unknown(op: &str, message: impl std::fmt::Display) -> DeserializeError {
+    let kind = DeserializeErrorKind::UnknownOperation { op: op.to_string() };
+    DeserializeError::new(kind, message)
+}
+
+impl<'de> Deserialize<'de> for Extension {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        Extension::resolve(&value).map_err(D::Error::custom)
+    }
+}
+
+impl From<Extension> for Predicate {
+    fn from(value: Extension) -> Self {
+        Predicate::Extension(value)
+    }
+}
@@ -7,7 +7,7 @@ use serde::{ser::SerializeStruct, Serialize};
 
 use crate::json_path::JSONPath;
 use crate::predicate::context::PredicateContext;
-use crate::predicate::error::PredicateError;
+use crate::predicate::outcome::Outcome;
 use crate::predicate::PredicateImpl;
 use crate::Predicate;
 
@@ -157,21 +157,11 @@ impl From<And> for SecondOrder {
 }
 
 impl PredicateImpl for And {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         let path = ctx.final_path(&self.path);
-        let ctx = PredicateContext::from(path);
+        let ctx = ctx.scoped(path);
 
-        for ap in &self.apply {
-            let pred = ap.evaluate(data, ctx.clone());
-
-            if pred.is_ok() {
-                continue;
-            } else {
-                return pred;
-            }
-        }
-
-        Ok(true)
+        Outcome::all(self.apply.iter().map(|ap| ap.evaluate(data, ctx.clone())))
     }
 }
 
@@ -1,16 +1,17 @@
+use crate::json_path::JSONPath;
 use crate::Predicate;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use serde_json::Value;
 
 use self::{and::And, not::Not, or::Or};
 
-use super::{context::PredicateContext, error::PredicateError, PredicateImpl};
+use super::{context::PredicateContext, outcome::Outcome, PredicateImpl};
 
 pub mod and;
 pub mod not;
 pub mod or;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum SecondOrder {
     And(And),
@@ -18,8 +19,37 @@ pub enum SecondOrder {
     Not(Not),
 }
 
+impl SecondOrder {
+    /// The "op" member of the predicate.
+    pub fn op(&self) -> &'static str {
+        match self {
+            Self::And(_) => "and",
+            Self::Or(_) => "or",
+            Self::Not(_) => "not",
+        }
+    }
+
+    /// The "path" member of the predicate.
+    pub fn path(&self) -> Option<&JSONPath> {
+        match self {
+            Self::And(ty) => ty.path.as_ref(),
+            Self::Or(ty) => ty.path.as_ref(),
+            Self::Not(ty) => ty.path.as_ref(),
+        }
+    }
+
+    /// The subordinate predicates, the "apply" member of the predicate.
+    pub fn apply(&self) -> &[Predicate] {
+        match self {
+            Self::And(ty) => &ty.apply,
+            Self::Or(ty) => &ty.apply,
+            Self::Not(ty) => &ty.apply,
+        }
+    }
+}
+
 impl PredicateImpl for SecondOrder {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         match self {
             Self::And(ty) => ty.evaluate(data, ctx),
             Self::Or(ty) => ty.evaluate(data, ctx),
@@ -64,9 +94,12 @@ mod tests {
                         },
                     ),
                 ),
+                quantifier: None,
                 ignore_case: false,
-                value: String(
-                    " is a ",
+                value: Value(
+                    String(
+                        " is a ",
+                    ),
                 ),
             },
         )
@@ -7,7 +7,7 @@ use serde::{ser::SerializeStruct, Serialize};
 
 use crate::json_path::JSONPath;
 use crate::predicate::context::PredicateContext;
-use crate::predicate::error::PredicateError;
+use crate::predicate::outcome::Outcome;
 use crate::predicate::PredicateImpl;
 use crate::Predicate;
 
@@ -157,22 +157,13 @@ impl From<Not> for SecondOrder {
 }
 
 impl PredicateImpl for Not {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         let path = ctx.final_path(&self.path);
-        let ctx = PredicateContext::from(path);
+        let ctx = ctx.scoped(path);
 
         // The "not" predicate evaluates as "true" if all of it's contained set
         // of predicate operations evaluate as "false".
-
-        for ap in &self.apply {
-            let pred = ap.evaluate(data, ctx.clone());
-
-            if pred.is_ok() {
-                return Ok(false);
-            }
-        }
-
-        Ok(true)
+        Outcome::none(self.apply.iter().map(|ap| ap.evaluate(data, ctx.clone())))
     }
 }
 
@@ -7,7 +7,7 @@ use serde::{ser::SerializeStruct, Serialize};
 
 use crate::json_path::JSONPath;
 use crate::predicate::context::PredicateContext;
-use crate::predicate::error::PredicateError;
+use crate::predicate::outcome::Outcome;
 use crate::predicate::PredicateImpl;
 use crate::Predicate;
 
@@ -157,23 +157,11 @@ impl From<Or> for SecondOrder {
 }
 
 impl PredicateImpl for Or {
-    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Result<bool, PredicateError> {
+    fn evaluate(&self, data: &Value, ctx: PredicateContext) -> Outcome {
         let path = ctx.final_path(&self.path);
-        let ctx = PredicateContext::from(path);
+        let ctx = ctx.scoped(path);
 
-        let mut acc = true;
-
-        for ap in &self.apply {
-            let pred = ap.evaluate(data, ctx.clone());
-
-            if pred.is_ok() {
-                return Ok(true);
-            } else {
-                acc = false;
-            }
-        }
-
-        Ok(acc)
+        Outcome::any(self.apply.iter().map(|ap| ap.evaluate(data, ctx.clone())))
     }
 }
 
@@ -0,0 +1,103 @@
+use serde_json::Value;
+
+use super::compiled::CompiledPredicate;
+use super::context::PredicateContext;
+use super::outcome::Outcome;
+use super::Predicate;
+
+/// Named predicates evaluated together against a document, e.g. to route it
+/// or to find the rules it triggers.
+///
+/// Predicates are compiled once when they're inserted, see
+/// [`CompiledPredicate`], and identical predicates are only evaluated once
+/// per document whatever the number of names they're inserted with.
+///
+/// ```rust
+/// use json_predicate::{Predicate, PredicateSet};
+/// use json_predicate::context::PredicateContext;
+///
+/// let mut set = PredicateSet::new(PredicateContext::default());
+/// set.insert("adults", &r#"/age > 17"#.parse::<Predicate>()?)
+///     .insert("admins", &r#"/roles contains "admin""#.parse::<Predicate>()?);
+///
+/// let matched = set.matches(&serde_json::json!({ "age": 42, "roles": "user" }));
+/// assert_eq!(matched, vec!["adults"]);
+/// # Ok::<(), json_predicate::SyntaxError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PredicateSet {
+    ctx: PredicateContext,
+    /// The names, in insertion order, with the index of their predicate.
+    names: Vec<(String, usize)>,
+    predicates: Vec<(Predicate, CompiledPredicate)>,
+}
+
+impl PredicateSet {
+    /// An empty set, its predicates being compiled from `ctx`.
+    pub fn new(ctx: PredicateContext) -> Self {
+        Self {
+            ctx,
+            names: Vec::new(),
+            predicates: Vec::new(),
+        }
+    }
+
+    /// Insert the predicate `name`. A previous predicate with the same name is
+    /// replaced.
+    pub fn insert(&mut self, name: impl Into<String>, predicate: &Predicate) -> &mut Self {
+        let index = match self.predicates.iter().position(|(p, _)| p == predicate) {
+            Some(index) => index,
+            None => {
+                let compiled = CompiledPredicate::new(predicate, self.ctx.clone());
+                self.predicates.push((predicate.clone(), compiled));
+                self.predicates.len() - 1
+            }
+        };
+
+        let name = name.into();
+        match self.names.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, previous)) => *previous = index,
+            None => self.names.push((name, index)),
+        }
+        self
+    }
+
+    /// The number of names in the set.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether the set has no predicate.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Evaluate every predicate against the provided JSON, in insertion
+    /// order.
+    pub fn evaluate(&self, data: &Value) -> Vec<(&str, Outcome)> {
+        let mut outcomes: Vec<Option<Outcome>> = vec![None; self.predicates.len()];
+
+        self.names
+            .iter()
+            .map(|(name, index)| {
+                let outcome = outcomes[*index]
+                    .get_or_insert_with(|| self.predicates[*index].1.evaluate(data));
+                (name.as_str(), outcome.clone())
+            })
+            .collect()
+    }
+
+    /// The names of the predicates holding for the provided JSON, in insertion
+    /// order, an [`Outcome::Error`] being handled as "false".
+    pub fn matches(&self, data: &Value) -> Vec<&str> {
+        let mut holds: Vec<Option<bool>> = vec![None; self.predicates.len()];
+
+        self.names
+            .iter()
+            .filter(|(_, index)| {
+                *holds[*index].get_or_insert_with(|| self.predicates[*index].1.test(data))
+            })
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
@@ -0,0 +1,424 @@
+//! Simplification of predicates into an equivalent, smaller tree.
+
+use serde_json::Value;
+
+use crate::json_path::JSONPath;
+
+use super::first_order::{
+    defined::Defined, r#in::In, test::Test, undefined::Undefined, FirstOrder,
+};
+use super::operand::{Operand, Reference};
+use super::quantifier::Quantifier;
+use super::second_order::{and::And, not::Not, or::Or, SecondOrder};
+use super::Predicate;
+
+impl Predicate {
+    /// An equivalent predicate, evaluating to the same outcome against any
+    /// document, which is usually smaller and faster to evaluate.
+    ///
+    /// - Nested "and" and "or" predicates are flattened, subordinate
+    ///   predicates appearing twice are removed and a single subordinate
+    ///   predicate replaces its "and" or "or" predicate.
+    /// - The prefix shared by the "path" members of the subordinate predicates
+    ///   is moved to the "path" member of their Second Order predicate.
+    /// - "not" predicates are pushed towards the First Order ones with De
+    ///   Morgan's laws, a "not" of a "not" being folded, "defined" and
+    ///   "undefined" being swapped and the "not" predicates of an "and" being
+    ///   merged.
+    /// - "test" and "in" predicates on the same path inside an "or", or a
+    ///   "not", are collapsed into one "in" predicate.
+    ///
+    /// Predicates whose operation was registered in an
+    /// [`OperatorRegistry`](super::registry::OperatorRegistry) are kept as
+    /// they are, as well as the Second Order predicates whose "path" member
+    /// would have to be moved into them.
+    pub fn simplify(&self) -> Predicate {
+        match self {
+            Predicate::FirstOrder(_) | Predicate::Extension(_) => self.clone(),
+            Predicate::SecondOrder(second_order) => {
+                let path = second_order.path().cloned();
+                let apply = second_order.apply().iter().map(Predicate::simplify);
+                match second_order {
+                    SecondOrder::And(_) => and(path, apply.collect()),
+                    SecondOrder::Or(_) => or(path, apply.collect()),
+                    SecondOrder::Not(_) => not(path, apply.collect()),
+                }
+            }
+        }
+    }
+}
+
+/// `path` evaluated from `prefix`.
+fn join(prefix: &Option<JSONPath>, path: Option<JSONPath>) -> Option<JSONPath> {
+    match (prefix, path) {
+        (None, path) => path,
+        (Some(prefix), None) => Some(prefix.clone()),
+        (Some(prefix), Some(path)) => Some(prefix.clone() + path),
+    }
+}
+
+/// Whether the predicates can be evaluated from `prefix` instead of the
+/// location they're evaluated from, see [`rebase`].
+fn rebasable(apply: &[Predicate], prefix: &Option<JSONPath>) -> bool {
+    prefix.is_none() || !apply.iter().any(|p| matches!(p, Predicate::Extension(_)))
+}
+
+/// The predicate evaluating as `predicate` from the location `prefix` is
+/// relative to, which must be [`rebasable`].
+fn rebase(predicate: Predicate, prefix: &Option<JSONPath>) -> Predicate {
+    if prefix.is_none() {
+        return predicate;
+    }
+
+    match predicate {
+        Predicate::FirstOrder(mut first_order) => {
+            let path = first_order.path_mut();
+            *path = join(prefix, path.take());
+            if let (Some(prefix), Some(path)) = (prefix, first_order.value_path_mut()) {
+                *path = prefix.clone() + path.clone();
+            }
+            Predicate::FirstOrder(first_order)
+        }
+        Predicate::SecondOrder(second_order) => {
+            let (kind, path, apply) = split(second_order);
+            build(kind, join(prefix, path), apply)
+        }
+        Predicate::Extension(_) => predicate,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    And,
+    Or,
+    Not,
+}
+
+fn split(second_order: SecondOrder) -> (Kind, Option<JSONPath>, Vec<Predicate>) {
+    match second_order {
+        SecondOrder::And(And { path, apply }) => (Kind::And, path, apply),
+        SecondOrder::Or(Or { path, apply }) => (Kind::Or, path, apply),
+        SecondOrder::Not(Not { path, apply }) => (Kind::Not, path, apply),
+    }
+}
+
+fn build(kind: Kind, path: Option<JSONPath>, apply: Vec<Predicate>) -> Predicate {
+    let second_order = match kind {
+        Kind::And => SecondOrder::And(And { path, apply }),
+        Kind::Or => SecondOrder::Or(Or { path, apply }),
+        Kind::Not => SecondOrder::Not(Not { path, apply }),
+    };
+    Predicate::SecondOrder(second_order)
+}
+
+/// The subordinate predicates of `kind` in `apply` replaced by their own
+/// subordinate predicates, in `flattened`.
+///
+/// An empty "or" predicate isn't flattened as it evaluates as "true".
+fn flatten(kind: Kind, apply: Vec<Predicate>, flattened: &mut Vec<Predicate>) {
+    for predicate in apply {
+        let nested = match (&predicate, kind) {
+            (Predicate::SecondOrder(SecondOrder::And(and)), Kind::And) => {
+                rebasable(&and.apply, &and.path)
+            }
+            (Predicate::SecondOrder(SecondOrder::Or(or)), Kind::Or) => {
+                !or.apply.is_empty() && rebasable(&or.apply, &or.path)
+            }
+            _ => false,
+        };
+
+        match predicate {
+            Predicate::SecondOrder(second_order) if nested => {
+                let (_, path, apply) = split(second_order);
+                let apply = apply.into_iter().map(|p| rebase(p, &path)).collect();
+                flatten(kind, apply, flattened);
+            }
+            predicate => flattened.push(predicate),
+        }
+    }
+}
+
+/// The "and" predicate, simplified.
+fn and(path: Option<JSONPath>, apply: Vec<Predicate>) -> Predicate {
+    let mut flattened = Vec::new();
+    flatten(Kind::And, apply, &mut flattened);
+
+    // "and" of "not" predicates is a "not" of all their subordinate
+    // predicates.
+    let mut apply = Vec::new();
+    let mut negated: Option<(usize, Vec<Predicate>)> = None;
+    for predicate in flattened {
+        match predicate {
+            Predicate::SecondOrder(SecondOrder::Not(not)) if rebasable(&not.apply, &not.path) => {
+                let (_, children) = negated.get_or_insert_with(|| (apply.len(), Vec::new()));
+                children.extend(not.apply.into_iter().map(|p| rebase(p, &not.path)));
+            }
+            predicate => apply.push(predicate),
+        }
+    }
+    if let Some((index, children)) = negated {
+        apply.insert(index, self::not(None, children));
+    }
+
+    finish(Kind::And, path, apply)
+}
+
+/// The "or" predicate, simplified.
+fn or(path: Option<JSONPath>, apply: Vec<Predicate>) -> Predicate {
+    let mut flattened = Vec::new();
+    flatten(Kind::Or, apply, &mut flattened);
+
+    finish(Kind::Or, path, collapse_equalities(flattened))
+}
+
+/// The "not" predicate, simplified.
+fn not(path: Option<JSONPath>, apply: Vec<Predicate>) -> Predicate {
+    let nested = apply.iter().any(|p| match p {
+        Predicate::SecondOrder(second_order) => !second_order.apply().is_empty(),
+        _ => false,
+    });
+
+    // De Morgan's laws: "not" is the "and" of the negations of its
+    // subordinate predicates.
+    if nested {
+        return and(path, apply.into_iter().map(negate).collect());
+    }
+    if let [Predicate::FirstOrder(
+        FirstOrder::Defined(Defined {
+            quantifier: None, ..
+        })
+        | FirstOrder::Undefined(Undefined {
+            quantifier: None, ..
+        }),
+    )] = apply.as_slice()
+    {
+        let negated = negate(apply.into_iter().next().expect("one predicate"));
+        return rebase(negated, &path);
+    }
+
+    finish(Kind::Not, path, collapse_equalities(apply))
+}
+
+/// The negation of the simplified `predicate`.
+fn negate(predicate: Predicate) -> Predicate {
+    match predicate {
+        Predicate::FirstOrder(FirstOrder::Defined(Defined {
+            path,
+            quantifier: None,
+        })) => Predicate::FirstOrder(FirstOrder::Undefined(Undefined {
+            path,
+            quantifier: None,
+        })),
+        Predicate::FirstOrder(FirstOrder::Undefined(Undefined {
+            path,
+            quantifier: None,
+        })) => Predicate::FirstOrder(FirstOrder::Defined(Defined {
+            path,
+            quantifier: None,
+        })),
+        Predicate::SecondOrder(second_order) if !second_order.apply().is_empty() => {
+            match split(second_order) {
+                (Kind::And, path, apply) => or(path, apply.into_iter().map(negate).collect()),
+                (Kind::Or, path, apply) => and(path, apply.into_iter().map(negate).collect()),
+                (Kind::Not, path, apply) => or(path, apply),
+            }
+        }
+        predicate => build(Kind::Not, None, vec![predicate]),
+    }
+}
+
+/// Remove the duplicated predicates, replace a single "and" or "or"
+/// subordinate predicate by itself and hoist the shared path prefix.
+fn finish(kind: Kind, path: Option<JSONPath>, apply: Vec<Predicate>) -> Predicate {
+    let mut unique: Vec<Predicate> = Vec::with_capacity(apply.len());
+    for predicate in apply {
+        if !unique.contains(&predicate) {
+            unique.push(predicate);
+        }
+    }
+
+    if !matches!(kind, Kind::Not) && unique.len() == 1 && rebasable(&unique, &path) {
+        return rebase(unique.remove(0), &path);
+    }
+
+    let (path, apply) = hoist(path, unique);
+    build(kind, path, apply)
+}
+
+/// The "test" and "in" predicates on the same path with literal values,
+/// which are true when any of them is, collapsed into one "in" predicate.
+fn collapse_equalities(apply: Vec<Predicate>) -> Vec<Predicate> {
+    let mut collapsed: Vec<Predicate> = Vec::with_capacity(apply.len());
+    // The indexes in `collapsed` of the "in" predicates collecting values.
+    let mut collecting: Vec<usize> = Vec::new();
+
+    for predicate in apply {
+        let Some(r#in) = equality(&predicate) else {
+            collapsed.push(predicate);
+            continue;
+        };
+
+        let found = collecting.iter().copied().find(|&index| {
+            matches!(
+                &collapsed[index],
+                Predicate::FirstOrder(FirstOrder::In(other))
+                    if other.path == r#in.path
+                        && other.quantifier == r#in.quantifier
+                        && other.ignore_case == r#in.ignore_case
+            )
+        });
+        match (found, r#in.value) {
+            (Some(index), Operand::Value(values)) => {
+                if let Predicate::FirstOrder(FirstOrder::In(In {
+                    value: Operand::Value(existing),
+                    ..
+                })) = &mut collapsed[index]
+                {
+                    existing.extend(values);
+                    *existing = dedup(std::mem::take(existing));
+                }
+            }
+            (_, value) => {
+                collecting.push(collapsed.len());
+                collapsed.push(Predicate::FirstOrder(FirstOrder::In(In { value, ..r#in })));
+            }
+        }
+    }
+
+    // A single value is tested as it was.
+    for index in collecting {
+        if let Predicate::FirstOrder(FirstOrder::In(r#in)) = &collapsed[index] {
+            if let Operand::Value(values) = &r#in.value {
+                if let [value] = values.as_slice() {
+                    collapsed[index] = Predicate::FirstOrder(FirstOrder::Test(Test {
+                        path: r#in.path.clone(),
+                        quantifier: r#in.quantifier,
+                        ignore_case: r#in.ignore_case,
+                        value: Operand::Value(value.clone()),
+                    }));
+                }
+            }
+        }
+    }
+
+    collapsed
+}
+
+/// The "in" predicate equivalent to the "test" or "in" predicate with a
+/// literal value and, at most, an "any" quantifier, which distributes over
+/// the values.
+fn equality(predicate: &Predicate) -> Option<In> {
+    let r#in = match predicate {
+        Predicate::FirstOrder(FirstOrder::Test(Test {
+            path,
+            quantifier,
+            ignore_case,
+            value: Operand::Value(value),
+        })) => In {
+            path: path.clone(),
+            quantifier: *quantifier,
+            ignore_case: *ignore_case,
+            value: Operand::Value(vec![value.clone()]),
+        },
+        Predicate::FirstOrder(FirstOrder::In(
+            r#in @ In {
+                value: Operand::Value(values),
+                ..
+            },
+        )) => In {
+            value: Operand::Value(dedup(values.clone())),
+            ..r#in.clone()
+        },
+        _ => return None,
+    };
+
+    matches!(r#in.quantifier, None | Some(Quantifier::Any)).then_some(r#in)
+}
+
+fn dedup(values: Vec<Value>) -> Vec<Value> {
+    let mut unique = Vec::with_capacity(values.len());
+    for value in values {
+        if !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+    unique
+}
+
+/// The reference tokens the location of `predicate` is relative to, `None`
+/// when it can't be moved.
+fn anchors(predicate: &Predicate) -> Option<Vec<Vec<String>>> {
+    match predicate {
+        Predicate::FirstOrder(first_order) => {
+            let mut anchors = vec![first_order.path().map(JSONPath::tokens).unwrap_or_default()];
+            if let Some(Reference::Path(path)) = first_order.value_reference() {
+                anchors.push(path.tokens());
+            }
+            Some(anchors)
+        }
+        Predicate::SecondOrder(second_order) => Some(vec![second_order
+            .path()
+            .map(JSONPath::tokens)
+            .unwrap_or_default()]),
+        Predicate::Extension(_) => None,
+    }
+}
+
+/// Move the prefix shared by the locations of the predicates of `apply` to
+/// `path`.
+fn hoist(path: Option<JSONPath>, apply: Vec<Predicate>) -> (Option<JSONPath>, Vec<Predicate>) {
+    if apply.len() < 2 {
+        return (path, apply);
+    }
+
+    let mut prefix: Option<Vec<String>> = None;
+    for predicate in &apply {
+        let Some(anchors) = anchors(predicate) else {
+            return (path, apply);
+        };
+        for tokens in anchors {
+            // Every location keeps at least one reference token.
+            let tokens = &tokens[..tokens.len().saturating_sub(1)];
+            let prefix = prefix.get_or_insert_with(|| tokens.to_vec());
+            let shared = prefix
+                .iter()
+                .zip(tokens)
+                .take_while(|(a, b)| a == b)
+                .count();
+            prefix.truncate(shared);
+        }
+    }
+
+    // A wildcard has to stay in the path of the quantified predicate.
+    let mut prefix = prefix.unwrap_or_default();
+    if let Some(wildcard) = prefix.iter().position(|t| t == "*" || t == "**") {
+        prefix.truncate(wildcard);
+    }
+    if prefix.is_empty() {
+        return (path, apply);
+    }
+
+    let strip = |path: &JSONPath| JSONPath::from_tokens(path.tokens()[prefix.len()..].to_vec());
+    let apply = apply
+        .into_iter()
+        .map(|predicate| match predicate {
+            Predicate::FirstOrder(mut first_order) => {
+                let path = first_order.path_mut();
+                *path = path.as_ref().map(strip);
+                if let Some(path) = first_order.value_path_mut() {
+                    *path = strip(path);
+                }
+                Predicate::FirstOrder(first_order)
+            }
+            Predicate::SecondOrder(second_order) => {
+                let (kind, path, apply) = split(second_order);
+                let path = path.as_ref().map(strip);
+                build(kind, path, apply)
+            }
+            predicate => predicate,
+        })
+        .collect();
+
+    let path = join(&path, Some(JSONPath::from_tokens(prefix)));
+    (path, apply)
+}
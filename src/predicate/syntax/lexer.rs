@@ -0,0 +1,256 @@
+use serde_json::Number;
+
+use super::SyntaxError;
+
+/// The characters which end a bare JSON Pointer, a pointer containing one of
+/// them has to be written as a string.
+pub(super) const POINTER_DELIMITERS: &[char] =
+    &['(', ')', '[', ']', '{', '}', ',', ':', '"', '=', '<', '>'];
+
+/// Whether the character can be part of a bare variable name, a variable
+/// named otherwise has to be written `{"$var": "..."}`.
+pub(super) fn is_variable_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Token {
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    Comma,
+    Colon,
+    /// `..`, in a count range.
+    DotDot,
+    /// `@`, a missing "path" member.
+    At,
+    /// `==`
+    Equal,
+    /// `<`
+    Less,
+    /// `>`
+    More,
+    /// A bare JSON Pointer, e.g. `/a/b/c`.
+    Pointer(String),
+    /// A variable, e.g. `$userId`, without the `$`.
+    Variable(String),
+    /// A JSON string, unescaped.
+    String(String),
+    Number(Number),
+    /// A keyword, e.g. `and`, `starts-with-` or `true`.
+    Word(String),
+    Eof,
+}
+
+impl Token {
+    /// How the token is described in error messages.
+    pub(super) fn describe(&self) -> String {
+        match self {
+            Token::LParen => "\"(\"".to_string(),
+            Token::RParen => "\")\"".to_string(),
+            Token::LBracket => "\"[\"".to_string(),
+            Token::RBracket => "\"]\"".to_string(),
+            Token::LBrace => "\"{\"".to_string(),
+            Token::RBrace => "\"}\"".to_string(),
+            Token::Comma => "\",\"".to_string(),
+            Token::Colon => "\":\"".to_string(),
+            Token::DotDot => "\"..\"".to_string(),
+            Token::At => "\"@\"".to_string(),
+            Token::Equal => "\"==\"".to_string(),
+            Token::Less => "\"<\"".to_string(),
+            Token::More => "\">\"".to_string(),
+            Token::Pointer(pointer) => format!("pointer \"{pointer}\""),
+            Token::Variable(name) => format!("variable \"${name}\""),
+            Token::String(_) => "a string".to_string(),
+            Token::Number(number) => format!("number {number}"),
+            Token::Word(word) => format!("\"{word}\""),
+            Token::Eof => "the end of the input".to_string(),
+        }
+    }
+}
+
+/// A token and the position, 1-based, where it starts.
+#[derive(Debug, Clone)]
+pub(super) struct Spanned {
+    pub token: Token,
+    pub line: usize,
+    pub column: usize,
+}
+
+pub(super) struct Lexer<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn tokenize(mut self) -> Result<Vec<Spanned>, SyntaxError> {
+        let mut tokens = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let (line, column) = (self.line, self.column);
+            let token = self.token()?;
+            let eof = token == Token::Eof;
+            tokens.push(Spanned {
+                token,
+                line,
+                column,
+            });
+            if eof {
+                return Ok(tokens);
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    /// Consume the characters matching `predicate` and return them.
+    fn bump_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.offset;
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+        &self.input[start..self.offset]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.bump_while(char::is_whitespace);
+    }
+
+    fn error(&self, message: impl Into<String>, line: usize, column: usize) -> SyntaxError {
+        SyntaxError::new(message, line, column)
+    }
+
+    fn token(&mut self) -> Result<Token, SyntaxError> {
+        let (line, column) = (self.line, self.column);
+        let Some(c) = self.peek() else {
+            return Ok(Token::Eof);
+        };
+
+        let punctuation = match c {
+            '(' => Some(Token::LParen),
+            ')' => Some(Token::RParen),
+            '[' => Some(Token::LBracket),
+            ']' => Some(Token::RBracket),
+            '{' => Some(Token::LBrace),
+            '}' => Some(Token::RBrace),
+            ',' => Some(Token::Comma),
+            ':' => Some(Token::Colon),
+            '@' => Some(Token::At),
+            '<' => Some(Token::Less),
+            '>' => Some(Token::More),
+            _ => None,
+        };
+        if let Some(token) = punctuation {
+            self.bump();
+            return Ok(token);
+        }
+
+        match c {
+            '.' => {
+                self.bump();
+                if self.bump() == Some('.') {
+                    Ok(Token::DotDot)
+                } else {
+                    Err(self.error("expected \"..\"", line, column))
+                }
+            }
+            '=' => {
+                self.bump();
+                if self.bump() == Some('=') {
+                    Ok(Token::Equal)
+                } else {
+                    Err(self.error("expected \"==\"", line, column))
+                }
+            }
+            '/' => {
+                let pointer =
+                    self.bump_while(|c| !c.is_whitespace() && !POINTER_DELIMITERS.contains(&c));
+                Ok(Token::Pointer(pointer.to_string()))
+            }
+            '$' => {
+                self.bump();
+                let name = self.bump_while(is_variable_char);
+                if name.is_empty() {
+                    Err(self.error("expected a variable name", line, column))
+                } else {
+                    Ok(Token::Variable(name.to_string()))
+                }
+            }
+            '"' => self.string(line, column),
+            '-' | '0'..='9' => {
+                let number = self.number();
+                serde_json::from_str::<Number>(number)
+                    .map(Token::Number)
+                    .map_err(|_| self.error(format!("invalid number \"{number}\""), line, column))
+            }
+            c if c.is_alphabetic() => {
+                let word = self.bump_while(|c| c.is_alphanumeric() || matches!(c, '-' | '_'));
+                Ok(Token::Word(word.to_string()))
+            }
+            c => Err(self.error(format!("unexpected character {c:?}"), line, column)),
+        }
+    }
+
+    /// The characters of a number, a `.` is only part of it when followed by
+    /// a digit so that `1..2` is a count range.
+    fn number(&mut self) -> &'a str {
+        let start = self.offset;
+        loop {
+            let mut chars = self.input[self.offset..].chars();
+            match (chars.next(), chars.next()) {
+                (Some('.'), Some(c)) if c.is_ascii_digit() => {}
+                (Some(c), _) if c.is_ascii_digit() || matches!(c, 'e' | 'E' | '+' | '-') => {}
+                _ => break,
+            }
+            self.bump();
+        }
+        &self.input[start..self.offset]
+    }
+
+    fn string(&mut self, line: usize, column: usize) -> Result<Token, SyntaxError> {
+        let start = self.offset;
+        self.bump();
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => {
+                    self.bump();
+                }
+                Some(_) => {}
+                None => return Err(self.error("unterminated string", line, column)),
+            }
+        }
+
+        serde_json::from_str::<String>(&self.input[start..self.offset])
+            .map(Token::String)
+            .map_err(|_| self.error("invalid string", line, column))
+    }
+}
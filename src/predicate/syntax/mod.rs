@@ -0,0 +1,115 @@
+//! A human-readable text syntax for predicates.
+//!
+//! ```text
+//! /a/b/c starts-with "ABC" and type(/x) == "string"
+//! ```
+//!
+//! A [`Predicate`] is parsed from this syntax with [`str::parse`] and
+//! rendered back to it with [`Display`], `{:#}` spreading the subordinate
+//! predicates over several lines. Rendering a predicate and parsing it back
+//! gives the same predicate.
+//!
+//! ## First order predicates
+//!
+//! Paths are JSON Pointers, written as strings when they contain whitespace
+//! or one of `()[]{},:"=<>`, e.g. `"/a b"`, and `@` stands for a missing
+//! "path" member. Values are JSON.
+//!
+//! | Predicate                    | Syntax                                 |
+//! |------------------------------|----------------------------------------|
+//! | "contains", "contains-"      | `/a contains "b"`, `/a contains- "b"`  |
+//! | "start", "start-"            | `/a starts-with "b"`, `starts-with-`   |
+//! | "end", "end-"                | `/a ends-with "b"`, `ends-with-`       |
+//! | "test", "test-"              | `/a == "b"`, `/a equals "b"`, `equals-`|
+//! | "in", "in-"                  | `/a in ["b", "c"]`, `in-`              |
+//! | "matches", "matches-"        | `/a matches "^b"`, `matches-`          |
+//! | "contained", "contained-"    | `/a contained ["b", "c"]`, `contained-`|
+//! | "intersects", "intersects-"  | `/a intersects ["b"]`, `intersects-`   |
+//! | "less"                       | `/a < 1`                               |
+//! | "more"                       | `/a > "2010-10-10"`                    |
+//! | "type"                       | `type(/a) == "string"`                 |
+//! | "defined"                    | `defined(/a)`                          |
+//! | "undefined"                  | `undefined(/a)`                        |
+//!
+//! A bare pointer in place of the value references another location of the
+//! document: `/endDate > /startDate`, `{"$path": "/a b"}` being used when the
+//! pointer can't be written bare. A variable is written `$userId`, or
+//! `{"$var": "user id"}` when its name isn't made of letters, digits and `_`.
+//!
+//! A "quantifier" member is written before the predicate as `any`, `all`,
+//! `none`, `count(1..3)` or `count(1..)`:
+//! `any /items/*/status == "shipped"`, `count(2..) defined(/**/id)`.
+//!
+//! Any predicate can also be written as its JSON object, which is how the
+//! operations of an [`OperatorRegistry`](crate::OperatorRegistry) are
+//! written: `/a == 1 and {"op": "even", "path": "/b"}`.
+//!
+//! ## Second order predicates
+//!
+//! `not` binds tighter than `and`, which binds tighter than `or`, and
+//! parentheses group predicates: `not /a == 1 and (/b == 2 or /c == 3)`.
+//!
+//! The function form `and(p1, p2)`, `or(p1)` or `not(p1, p2)` takes any
+//! number of predicates and can be given a "path" member:
+//! `/objA: and(/num2 > 1, /stringX == "X")`.
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Predicate;
+
+use self::parser::Parser;
+use self::printer::Printer;
+
+mod lexer;
+mod parser;
+mod printer;
+
+/// The error returned when a predicate couldn't be parsed from the text
+/// syntax, with the position, 1-based, where it occurred.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{message} at line {line}, column {column}")]
+pub struct SyntaxError {
+    message: String,
+    line: usize,
+    column: usize,
+}
+
+impl SyntaxError {
+    pub(crate) fn new(message: impl Into<String>, line: usize, column: usize) -> Self {
+        Self {
+            message: message.into(),
+            line,
+            column,
+        }
+    }
+
+    /// What was wrong.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The line, starting at 1, where the error occurred.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column, in characters and starting at 1, where the error
+    /// occurred.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl FromStr for Predicate {
+    type Err = SyntaxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s)?.parse()
+    }
+}
+
+impl Display for Predicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Printer::new(f).predicate(self)
+    }
+}
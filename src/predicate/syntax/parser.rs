@@ -0,0 +1,515 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::comparable::Comparable;
+use crate::json_path::JSONPath;
+use crate::predicate::first_order::{
+    contained::Contained, contains::Contains, defined::Defined, end::End, intersects::Intersects,
+    less::Less, matches::Matches, more::More, r#in::In, r#type::Type, start::Start, test::Test,
+    undefined::Undefined, FirstOrder,
+};
+use crate::predicate::operand::Operand;
+use crate::predicate::quantifier::Quantifier;
+use crate::predicate::second_order::{and::And, not::Not, or::Or, SecondOrder};
+use crate::regex::Regex;
+use crate::Predicate;
+
+use super::lexer::{Lexer, Spanned, Token};
+use super::SyntaxError;
+
+/// The operators of the first order predicates written
+/// `<path> <operator> <value>`.
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Contains,
+    Start,
+    End,
+    Test,
+    In,
+    Matches,
+    Contained,
+    Intersects,
+    Less,
+    More,
+}
+
+impl Operator {
+    /// The operator and whether it ignores the case.
+    fn from_token(token: &Token) -> Option<(Operator, bool)> {
+        let word = match token {
+            Token::Equal => return Some((Operator::Test, false)),
+            Token::Less => return Some((Operator::Less, false)),
+            Token::More => return Some((Operator::More, false)),
+            Token::Word(word) => word.as_str(),
+            _ => return None,
+        };
+
+        let (word, ignore_case) = match word.strip_suffix('-') {
+            Some(word) => (word, true),
+            None => (word, false),
+        };
+        let operator = match word {
+            "contains" => Operator::Contains,
+            "starts-with" => Operator::Start,
+            "ends-with" => Operator::End,
+            "equals" => Operator::Test,
+            "in" => Operator::In,
+            "matches" => Operator::Matches,
+            "contained" => Operator::Contained,
+            "intersects" => Operator::Intersects,
+            _ => return None,
+        };
+        Some((operator, ignore_case))
+    }
+}
+
+pub(super) struct Parser {
+    tokens: Vec<Spanned>,
+    position: usize,
+}
+
+impl Parser {
+    pub fn new(input: &str) -> Result<Self, SyntaxError> {
+        Ok(Self {
+            tokens: Lexer::new(input).tokenize()?,
+            position: 0,
+        })
+    }
+
+    /// Parse the whole input as a single predicate.
+    pub fn parse(mut self) -> Result<Predicate, SyntaxError> {
+        let predicate = self.expression()?;
+        match self.peek() {
+            Token::Eof => Ok(predicate),
+            _ => Err(self.unexpected("\"and\", \"or\" or the end of the input")),
+        }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position].token
+    }
+
+    fn peek_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Token::Word(w) if w == word)
+    }
+
+    fn next(&mut self) -> Spanned {
+        let token = self.tokens[self.position].clone();
+        if token.token != Token::Eof {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn error_at(&self, spanned: &Spanned, message: impl Into<String>) -> SyntaxError {
+        SyntaxError::new(message, spanned.line, spanned.column)
+    }
+
+    fn unexpected(&self, expected: &str) -> SyntaxError {
+        let spanned = &self.tokens[self.position];
+        self.error_at(
+            spanned,
+            format!("expected {expected}, found {}", spanned.token.describe()),
+        )
+    }
+
+    fn expect(&mut self, token: Token) -> Result<(), SyntaxError> {
+        if *self.peek() == token {
+            self.next();
+            Ok(())
+        } else {
+            Err(self.unexpected(&token.describe()))
+        }
+    }
+
+    /// `<and> ("or" <and>)*`
+    fn expression(&mut self) -> Result<Predicate, SyntaxError> {
+        let mut apply = vec![self.conjunction()?];
+        while self.peek_word("or") {
+            self.next();
+            apply.push(self.conjunction()?);
+        }
+
+        Ok(if apply.len() == 1 {
+            apply.remove(0)
+        } else {
+            SecondOrder::Or(Or { path: None, apply }).into()
+        })
+    }
+
+    /// `<unary> ("and" <unary>)*`
+    fn conjunction(&mut self) -> Result<Predicate, SyntaxError> {
+        let mut apply = vec![self.unary()?];
+        while self.peek_word("and") {
+            self.next();
+            apply.push(self.unary()?);
+        }
+
+        Ok(if apply.len() == 1 {
+            apply.remove(0)
+        } else {
+            SecondOrder::And(And { path: None, apply }).into()
+        })
+    }
+
+    /// `"not" "(" <arguments> ")" | "not" <unary> | <primary>`
+    fn unary(&mut self) -> Result<Predicate, SyntaxError> {
+        if !self.peek_word("not") {
+            return self.primary();
+        }
+
+        self.next();
+        let apply = if *self.peek() == Token::LParen {
+            self.arguments()?
+        } else {
+            vec![self.unary()?]
+        };
+        Ok(SecondOrder::Not(Not { path: None, apply }).into())
+    }
+
+    /// `"(" (<expression> ("," <expression>)*)? ")"`
+    fn arguments(&mut self) -> Result<Vec<Predicate>, SyntaxError> {
+        self.expect(Token::LParen)?;
+        let mut apply = Vec::new();
+        if *self.peek() != Token::RParen {
+            apply.push(self.expression()?);
+            while *self.peek() == Token::Comma {
+                self.next();
+                apply.push(self.expression()?);
+            }
+        }
+        self.expect(Token::RParen)?;
+        Ok(apply)
+    }
+
+    fn primary(&mut self) -> Result<Predicate, SyntaxError> {
+        match self.peek() {
+            Token::LParen => {
+                self.next();
+                let predicate = self.expression()?;
+                self.expect(Token::RParen)?;
+                Ok(predicate)
+            }
+            Token::LBrace => {
+                let spanned = self.tokens[self.position].clone();
+                let value = self.value()?;
+                Predicate::deserialize(value)
+                    .map_err(|err| self.error_at(&spanned, err.to_string()))
+            }
+            Token::Word(word) if matches!(word.as_str(), "and" | "or") => self.second_order(None),
+            Token::Word(word) if matches!(word.as_str(), "any" | "all" | "none" | "count") => {
+                let quantifier = self.quantifier()?;
+                self.first_order(Some(quantifier))
+            }
+            Token::Pointer(_) | Token::String(_) | Token::At => {
+                let path = self.path()?;
+                if *self.peek() == Token::Colon {
+                    self.next();
+                    self.second_order(path)
+                } else {
+                    self.comparison(path, None)
+                }
+            }
+            _ => self.first_order(None),
+        }
+    }
+
+    /// `"any" | "all" | "none" | "count" "(" <number> ".." <number>? ")"`
+    fn quantifier(&mut self) -> Result<Quantifier, SyntaxError> {
+        let spanned = self.next();
+        let Token::Word(word) = &spanned.token else {
+            return Err(self.error_at(&spanned, "expected a quantifier"));
+        };
+        match word.as_str() {
+            "any" => return Ok(Quantifier::Any),
+            "all" => return Ok(Quantifier::All),
+            "none" => return Ok(Quantifier::None),
+            _ => {}
+        }
+
+        self.expect(Token::LParen)?;
+        let min = self.count()?;
+        self.expect(Token::DotDot)?;
+        let max = match self.peek() {
+            Token::Number(_) => Some(self.count()?),
+            _ => None,
+        };
+        self.expect(Token::RParen)?;
+
+        if max.is_some_and(|max| max < min) {
+            return Err(self.error_at(&spanned, "the count range is empty"));
+        }
+        Ok(Quantifier::Count { min, max })
+    }
+
+    fn count(&mut self) -> Result<usize, SyntaxError> {
+        let spanned = self.next();
+        match &spanned.token {
+            Token::Number(number) => number
+                .as_u64()
+                .and_then(|number| usize::try_from(number).ok())
+                .ok_or_else(|| self.error_at(&spanned, "expected a count")),
+            token => Err(self.error_at(
+                &spanned,
+                format!("expected a count, found {}", token.describe()),
+            )),
+        }
+    }
+
+    /// `"defined" "(" <path> ")" | "undefined" "(" <path> ")"
+    /// | "type" "(" <path> ")" "==" <string> | <path> <operator> <value>`
+    fn first_order(&mut self, quantifier: Option<Quantifier>) -> Result<Predicate, SyntaxError> {
+        match self.peek() {
+            Token::Word(word) if matches!(word.as_str(), "defined" | "undefined") => {
+                let defined = word == "defined";
+                self.next();
+                self.expect(Token::LParen)?;
+                let path = self.path()?;
+                self.expect(Token::RParen)?;
+                Ok(if defined {
+                    FirstOrder::Defined(Defined { path, quantifier }).into()
+                } else {
+                    FirstOrder::Undefined(Undefined { path, quantifier }).into()
+                })
+            }
+            Token::Word(word) if word == "type" => {
+                self.next();
+                self.expect(Token::LParen)?;
+                let path = self.path()?;
+                self.expect(Token::RParen)?;
+                self.expect(Token::Equal)?;
+                let spanned = self.next();
+                match spanned.token {
+                    Token::String(value) => Ok(FirstOrder::Type(Type {
+                        path,
+                        quantifier,
+                        value,
+                    })
+                    .into()),
+                    _ => Err(self.error_at(&spanned, "expected a string")),
+                }
+            }
+            Token::Pointer(_) | Token::String(_) | Token::At => {
+                let path = self.path()?;
+                self.comparison(path, quantifier)
+            }
+            _ if quantifier.is_some() => Err(self.unexpected("a first order predicate")),
+            _ => Err(self.unexpected("a predicate")),
+        }
+    }
+
+    /// `("and" | "or" | "not") "(" <arguments> ")"`
+    fn second_order(&mut self, path: Option<JSONPath>) -> Result<Predicate, SyntaxError> {
+        let op = match self.peek() {
+            Token::Word(word) if matches!(word.as_str(), "and" | "or" | "not") => word.clone(),
+            _ => return Err(self.unexpected("\"and\", \"or\" or \"not\"")),
+        };
+        self.next();
+        let apply = self.arguments()?;
+
+        Ok(match op.as_str() {
+            "and" => SecondOrder::And(And { path, apply }),
+            "or" => SecondOrder::Or(Or { path, apply }),
+            _ => SecondOrder::Not(Not { path, apply }),
+        }
+        .into())
+    }
+
+    /// `<operator> <value>`, following the path.
+    fn comparison(
+        &mut self,
+        path: Option<JSONPath>,
+        quantifier: Option<Quantifier>,
+    ) -> Result<Predicate, SyntaxError> {
+        let Some((operator, ignore_case)) = Operator::from_token(self.peek()) else {
+            return Err(self.unexpected("an operator"));
+        };
+        self.next();
+
+        let spanned = self.tokens[self.position].clone();
+        let operand = self.operand()?;
+        let first_order = match operator {
+            Operator::Contains => FirstOrder::Contains(Contains {
+                path,
+                quantifier,
+                ignore_case,
+                value: operand,
+            }),
+            Operator::Start => FirstOrder::Start(Start {
+                path,
+                quantifier,
+                ignore_case,
+                value: operand,
+            }),
+            Operator::End => FirstOrder::End(End {
+                path,
+                quantifier,
+                ignore_case,
+                value: operand,
+            }),
+            Operator::Test => FirstOrder::Test(Test {
+                path,
+                quantifier,
+                ignore_case,
+                value: operand,
+            }),
+            Operator::In => FirstOrder::In(In {
+                path,
+                quantifier,
+                ignore_case,
+                value: try_map(operand, |value| self.array(&spanned, value))?,
+            }),
+            Operator::Contained => FirstOrder::Contained(Contained {
+                path,
+                quantifier,
+                ignore_case,
+                value: self.array(&spanned, self.literal(&spanned, operand)?)?,
+            }),
+            Operator::Intersects => FirstOrder::Intersects(Intersects {
+                path,
+                quantifier,
+                ignore_case,
+                value: self.array(&spanned, self.literal(&spanned, operand)?)?,
+            }),
+            Operator::Matches => FirstOrder::Matches(Matches {
+                path,
+                quantifier,
+                ignore_case,
+                value: Regex::deserialize(self.literal(&spanned, operand)?)
+                    .map_err(|err| self.error_at(&spanned, err.to_string()))?,
+            }),
+            Operator::Less => FirstOrder::Less(Less {
+                path,
+                quantifier,
+                value: try_map(operand, |value| self.comparable(&spanned, value))?,
+            }),
+            Operator::More => FirstOrder::More(More {
+                path,
+                quantifier,
+                value: try_map(operand, |value| self.comparable(&spanned, value))?,
+            }),
+        };
+
+        Ok(first_order.into())
+    }
+
+    /// `<pointer> | <variable> | <value>`, a bare JSON Pointer referencing
+    /// another location of the document.
+    fn operand(&mut self) -> Result<Operand<Value>, SyntaxError> {
+        let spanned = self.tokens[self.position].clone();
+        if let Token::Pointer(pointer) = &spanned.token {
+            self.next();
+            return JSONPath::new(pointer)
+                .map(Operand::Path)
+                .map_err(|err| self.error_at(&spanned, format!("invalid JSON Pointer: {err}")));
+        }
+        if let Token::Variable(name) = &spanned.token {
+            self.next();
+            return Ok(Operand::Var(name.clone()));
+        }
+
+        let value = self.value()?;
+        Operand::deserialize(value).map_err(|err| self.error_at(&spanned, err.to_string()))
+    }
+
+    /// The value of an operand, for the operations which only take a literal
+    /// value.
+    fn literal(&self, spanned: &Spanned, operand: Operand<Value>) -> Result<Value, SyntaxError> {
+        match operand {
+            Operand::Value(value) => Ok(value),
+            Operand::Path(_) | Operand::Var(_) => Err(self.error_at(
+                spanned,
+                format!("expected a JSON value, found {}", spanned.token.describe()),
+            )),
+        }
+    }
+
+    fn array(&self, spanned: &Spanned, value: Value) -> Result<Vec<Value>, SyntaxError> {
+        match value {
+            Value::Array(values) => Ok(values),
+            _ => Err(self.error_at(spanned, "expected an array")),
+        }
+    }
+
+    fn comparable(&self, spanned: &Spanned, value: Value) -> Result<Comparable, SyntaxError> {
+        Comparable::deserialize(value).map_err(|err| self.error_at(spanned, err.to_string()))
+    }
+
+    /// `"@" | <pointer> | <string>`
+    fn path(&mut self) -> Result<Option<JSONPath>, SyntaxError> {
+        let spanned = self.next();
+        let pointer = match &spanned.token {
+            Token::At => return Ok(None),
+            Token::Pointer(pointer) | Token::String(pointer) => pointer,
+            token => {
+                return Err(self.error_at(
+                    &spanned,
+                    format!("expected a JSON Pointer, found {}", token.describe()),
+                ))
+            }
+        };
+
+        JSONPath::new(pointer)
+            .map(Some)
+            .map_err(|err| self.error_at(&spanned, format!("invalid JSON Pointer: {err}")))
+    }
+
+    /// A JSON value.
+    fn value(&mut self) -> Result<Value, SyntaxError> {
+        let spanned = self.next();
+        match spanned.token {
+            Token::String(value) => Ok(Value::String(value)),
+            Token::Number(value) => Ok(Value::Number(value)),
+            Token::Word(word) if word == "true" => Ok(Value::Bool(true)),
+            Token::Word(word) if word == "false" => Ok(Value::Bool(false)),
+            Token::Word(word) if word == "null" => Ok(Value::Null),
+            Token::LBracket => {
+                let mut values = Vec::new();
+                if *self.peek() != Token::RBracket {
+                    values.push(self.value()?);
+                    while *self.peek() == Token::Comma {
+                        self.next();
+                        values.push(self.value()?);
+                    }
+                }
+                self.expect(Token::RBracket)?;
+                Ok(Value::Array(values))
+            }
+            Token::LBrace => {
+                let mut map = Map::new();
+                if *self.peek() != Token::RBrace {
+                    loop {
+                        let key = self.next();
+                        let Token::String(key) = key.token else {
+                            return Err(self.error_at(&key, "expected a string"));
+                        };
+                        self.expect(Token::Colon)?;
+                        map.insert(key, self.value()?);
+                        if *self.peek() != Token::Comma {
+                            break;
+                        }
+                        self.next();
+                    }
+                }
+                self.expect(Token::RBrace)?;
+                Ok(Value::Object(map))
+            }
+            _ => Err(self.error_at(
+                &spanned,
+                format!("expected a JSON value, found {}", spanned.token.describe()),
+            )),
+        }
+    }
+}
+
+/// Convert the literal value of an operand.
+fn try_map<T>(
+    operand: Operand<Value>,
+    f: impl FnOnce(Value) -> Result<T, SyntaxError>,
+) -> Result<Operand<T>, SyntaxError> {
+    match operand {
+        Operand::Value(value) => f(value).map(Operand::Value),
+        Operand::Path(path) => Ok(Operand::Path(path)),
+        Operand::Var(name) => Ok(Operand::Var(name)),
+    }
+}
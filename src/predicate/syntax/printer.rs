@@ -0,0 +1,242 @@
+use std::fmt::{Formatter, Result, Write};
+
+use serde::Serialize;
+
+use crate::json_path::JSONPath;
+use crate::predicate::first_order::FirstOrder;
+use crate::predicate::operand::Operand;
+use crate::predicate::second_order::SecondOrder;
+use crate::Predicate;
+
+use super::lexer::{is_variable_char, POINTER_DELIMITERS};
+
+/// Renders a [`Predicate`] in the text syntax, on a single line or, with
+/// `pretty`, with one subordinate predicate per line.
+pub(super) struct Printer<'a, 'f> {
+    f: &'a mut Formatter<'f>,
+    pretty: bool,
+    depth: usize,
+}
+
+/// How a predicate is rendered, to know when it must be parenthesized.
+#[derive(PartialEq)]
+enum Form {
+    /// `a and b`
+    And,
+    /// `a or b`
+    Or,
+    /// `not a`
+    Not,
+    /// Anything which doesn't need parentheses.
+    Primary,
+}
+
+impl Form {
+    fn of(predicate: &Predicate) -> Form {
+        match predicate {
+            Predicate::FirstOrder(_) | Predicate::Extension(_) => Form::Primary,
+            Predicate::SecondOrder(second_order) => Form::of_second_order(second_order),
+        }
+    }
+
+    fn of_second_order(second_order: &SecondOrder) -> Form {
+        if second_order.path().is_some() {
+            return Form::Primary;
+        }
+        match (second_order, second_order.apply().len()) {
+            (SecondOrder::And(_), len) if len >= 2 => Form::And,
+            (SecondOrder::Or(_), len) if len >= 2 => Form::Or,
+            (SecondOrder::Not(_), 1) => Form::Not,
+            _ => Form::Primary,
+        }
+    }
+}
+
+impl<'a, 'f> Printer<'a, 'f> {
+    pub fn new(f: &'a mut Formatter<'f>) -> Self {
+        let pretty = f.alternate();
+        Self {
+            f,
+            pretty,
+            depth: 0,
+        }
+    }
+
+    pub fn predicate(&mut self, predicate: &Predicate) -> Result {
+        match predicate {
+            Predicate::FirstOrder(first_order) => self.first_order(first_order),
+            Predicate::SecondOrder(second_order) => self.second_order(second_order),
+            Predicate::Extension(extension) => self.f.write_str(&json(extension)?),
+        }
+    }
+
+    /// A line break in pretty mode, `separator` otherwise.
+    fn line(&mut self, separator: &str) -> Result {
+        if self.pretty {
+            self.f.write_char('\n')?;
+            for _ in 0..self.depth {
+                self.f.write_str("  ")?;
+            }
+            Ok(())
+        } else {
+            self.f.write_str(separator)
+        }
+    }
+
+    fn second_order(&mut self, second_order: &SecondOrder) -> Result {
+        let apply = second_order.apply();
+        let form = Form::of_second_order(second_order);
+        match form {
+            Form::And | Form::Or => {
+                for (idx, predicate) in apply.iter().enumerate() {
+                    if idx > 0 {
+                        self.line(" ")?;
+                        write!(self.f, "{} ", second_order.op())?;
+                    }
+                    // Only an "and" can be an operand of an "or" without
+                    // changing the structure of the predicate.
+                    let nested = Form::of(predicate);
+                    let parenthesize =
+                        nested == Form::Or || (nested == Form::And && form == Form::And);
+                    self.operand(predicate, parenthesize)?;
+                }
+                Ok(())
+            }
+            Form::Not => {
+                self.f.write_str("not ")?;
+                let nested = Form::of(&apply[0]);
+                self.operand(&apply[0], nested == Form::And || nested == Form::Or)
+            }
+            Form::Primary => {
+                if let Some(path) = second_order.path() {
+                    self.path(Some(path))?;
+                    self.f.write_str(": ")?;
+                }
+                write!(self.f, "{}(", second_order.op())?;
+                if !apply.is_empty() {
+                    self.depth += 1;
+                    self.line("")?;
+                    for (idx, predicate) in apply.iter().enumerate() {
+                        if idx > 0 {
+                            self.f.write_char(',')?;
+                            self.line(" ")?;
+                        }
+                        self.predicate(predicate)?;
+                    }
+                    self.depth -= 1;
+                    self.line("")?;
+                }
+                self.f.write_char(')')
+            }
+        }
+    }
+
+    fn operand(&mut self, predicate: &Predicate, parenthesize: bool) -> Result {
+        if !parenthesize {
+            return self.predicate(predicate);
+        }
+
+        self.f.write_char('(')?;
+        self.depth += 1;
+        self.line("")?;
+        self.predicate(predicate)?;
+        self.depth -= 1;
+        self.line("")?;
+        self.f.write_char(')')
+    }
+
+    fn first_order(&mut self, first_order: &FirstOrder) -> Result {
+        if let Some(quantifier) = first_order.quantifier() {
+            write!(self.f, "{quantifier} ")?;
+        }
+
+        let (operator, value) = match first_order {
+            FirstOrder::Defined(defined) => {
+                self.f.write_str("defined(")?;
+                self.path(defined.path.as_ref())?;
+                return self.f.write_char(')');
+            }
+            FirstOrder::Undefined(undefined) => {
+                self.f.write_str("undefined(")?;
+                self.path(undefined.path.as_ref())?;
+                return self.f.write_char(')');
+            }
+            FirstOrder::Type(ty) => {
+                self.f.write_str("type(")?;
+                self.path(ty.path.as_ref())?;
+                return write!(self.f, ") == {}", json(&ty.value)?);
+            }
+            FirstOrder::Contains(contains) if contains.ignore_case => {
+                ("contains-", operand(&contains.value)?)
+            }
+            FirstOrder::Contains(contains) => ("contains", operand(&contains.value)?),
+            FirstOrder::Start(start) if start.ignore_case => {
+                ("starts-with-", operand(&start.value)?)
+            }
+            FirstOrder::Start(start) => ("starts-with", operand(&start.value)?),
+            FirstOrder::End(end) if end.ignore_case => ("ends-with-", operand(&end.value)?),
+            FirstOrder::End(end) => ("ends-with", operand(&end.value)?),
+            FirstOrder::Test(test) if test.ignore_case => ("equals-", operand(&test.value)?),
+            FirstOrder::Test(test) => ("==", operand(&test.value)?),
+            FirstOrder::In(is_in) if is_in.ignore_case => ("in-", operand(&is_in.value)?),
+            FirstOrder::In(is_in) => ("in", operand(&is_in.value)?),
+            FirstOrder::Matches(matches) if matches.ignore_case => {
+                ("matches-", json(&matches.value)?)
+            }
+            FirstOrder::Matches(matches) => ("matches", json(&matches.value)?),
+            FirstOrder::Less(less) => ("<", operand(&less.value)?),
+            FirstOrder::More(more) => (">", operand(&more.value)?),
+            FirstOrder::Contained(contained) if contained.ignore_case => {
+                ("contained-", json(&contained.value)?)
+            }
+            FirstOrder::Contained(contained) => ("contained", json(&contained.value)?),
+            FirstOrder::Intersects(intersects) if intersects.ignore_case => {
+                ("intersects-", json(&intersects.value)?)
+            }
+            FirstOrder::Intersects(intersects) => ("intersects", json(&intersects.value)?),
+        };
+
+        self.path(first_order.path())?;
+        write!(self.f, " {operator} {value}")
+    }
+
+    /// `@` for a missing path, the pointer itself when it can be written
+    /// bare and a string otherwise.
+    fn path(&mut self, path: Option<&JSONPath>) -> Result {
+        let Some(path) = path else {
+            return self.f.write_char('@');
+        };
+
+        let pointer = path.to_string();
+        if is_bare(&pointer) {
+            self.f.write_str(&pointer)
+        } else {
+            self.f.write_str(&json(&pointer)?)
+        }
+    }
+}
+
+/// Whether the pointer can be written without quotes.
+fn is_bare(pointer: &str) -> bool {
+    !pointer.is_empty()
+        && !pointer
+            .chars()
+            .any(|c| c.is_whitespace() || POINTER_DELIMITERS.contains(&c))
+}
+
+/// The literal value as compact JSON, or the referenced pointer or variable,
+/// bare when it can be and as `{"$path": ...}` or `{"$var": ...}` otherwise.
+fn operand<T: Serialize>(operand: &Operand<T>) -> std::result::Result<String, std::fmt::Error> {
+    match operand {
+        Operand::Path(path) if is_bare(&path.to_string()) => Ok(path.to_string()),
+        Operand::Var(name) if !name.is_empty() && name.chars().all(is_variable_char) => {
+            Ok(format!("${name}"))
+        }
+        _ => json(operand),
+    }
+}
+
+/// The value as compact JSON.
+fn json<T: Serialize + ?Sized>(value: &T) -> std::result::Result<String, std::fmt::Error> {
+    serde_json::to_string(value).map_err(|_| std::fmt::Error)
+}
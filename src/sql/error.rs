@@ -0,0 +1,33 @@
+use crate::json_path::JSONPath;
+
+/// Error raised while translating a [`Predicate`](crate::Predicate) into SQL.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SqlError {
+    /// The predicate at `location` in the predicate document, e.g.
+    /// `/apply/1`, has no SQL equivalent for the dialect.
+    #[error("`{op}`{} can't be translated into SQL: {reason}", at(location))]
+    Unsupported {
+        op: String,
+        location: JSONPath,
+        reason: &'static str,
+    },
+}
+
+impl SqlError {
+    /// The location of the predicate which couldn't be translated, in the
+    /// predicate document.
+    pub fn location(&self) -> &JSONPath {
+        match self {
+            Self::Unsupported { location, .. } => location,
+        }
+    }
+}
+
+/// The location of a predicate, omitted for the root one.
+fn at(location: &JSONPath) -> String {
+    if location.tokens().is_empty() {
+        String::new()
+    } else {
+        format!(" at {location}")
+    }
+}
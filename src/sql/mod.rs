@@ -0,0 +1,716 @@
+//! Translation of predicates into SQL boolean expressions, to evaluate them
+//! in a database storing the documents in a JSON column.
+//!
+//! ```rust
+//! use json_predicate::context::PredicateContext;
+//! use json_predicate::sql::{Dialect, SqlParam};
+//! use json_predicate::Predicate;
+//!
+//! let predicate: Predicate = r#"/user/name starts-with "adm""#.parse()?;
+//! let sql = predicate.to_sql(Dialect::Postgres, "doc", PredicateContext::default())?;
+//!
+//! assert_eq!(
+//!     sql.sql,
+//!     "CASE WHEN (doc -> $1::text -> $2::text) IS NOT NULL \
+//!      THEN (jsonb_typeof((doc -> $1::text -> $2::text)) = 'string' \
+//!      AND (doc -> $1::text ->> $2::text) LIKE $3::text) END"
+//! );
+//! assert_eq!(
+//!     sql.params,
+//!     ["user", "name", "adm%"].map(|param| SqlParam::Text(param.to_string()))
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! The expression holds exactly when [`PredicateImpl::test`] does: a
+//! predicate which can't be evaluated, e.g. because it references a value
+//! that does not exist, is `NULL`, which `AND`, `OR` and `NOT` combine like
+//! the Second Order predicates combine an [`Outcome::Error`] and which a
+//! `WHERE` clause handles as "false".
+//!
+//! The "test", "in", "less", "more", "starts", "ends" and "contains"
+//! predicates, their case-insensitive variants, "defined", "undefined",
+//! "type" and the Second Order predicates are translated, variables being
+//! resolved from the [`PredicateContext`]. A quantifier, a "value" member
+//! referencing another location of the document, a date or time, the
+//! "matches", "contained" and "intersects" predicates, the "date", "time",
+//! "date-time", "lang", "lang-range", "iri" and "absolute-iri" types and the
+//! operations registered by the application are reported as
+//! [`SqlError::Unsupported`].
+//!
+//! A few differences remain:
+//!
+//! - case-insensitive comparisons rely on `ILIKE` and `LIKE`, SQLite only
+//!   folding ASCII letters;
+//! - PostgreSQL compares numbers by value, so `1` and `1.0` are equal;
+//! - SQLite matches object members by their key as written in the document,
+//!   and can't reference one whose key has a double quote or a backslash.
+//!
+//! [`PredicateImpl::test`]: crate::PredicateImpl::test
+//! [`Outcome::Error`]: crate::Outcome::Error
+use serde_json::{Number, Value};
+
+use crate::comparable::Comparable;
+use crate::json_path::JSONPath;
+use crate::predicate::context::PredicateContext;
+use crate::predicate::first_order::FirstOrder;
+use crate::predicate::operand::Reference;
+use crate::predicate::second_order::SecondOrder;
+use crate::predicate::Predicate;
+
+mod error;
+
+pub use error::SqlError;
+
+/// A predicate which can't be evaluated.
+const ERROR: &str = "CAST(NULL AS BOOLEAN)";
+
+/// The database an expression is written for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    /// PostgreSQL, the column being a `jsonb` one.
+    Postgres,
+    /// SQLite, the column holding JSON text read with its JSON functions.
+    Sqlite,
+}
+
+/// A parameterized SQL boolean expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sql {
+    /// The expression, parameters being referenced as `$1` with PostgreSQL and
+    /// `?1` with SQLite.
+    pub sql: String,
+    /// The parameters, the first one being `$1` or `?1`.
+    pub params: Vec<SqlParam>,
+}
+
+/// A parameter of an [`Sql`] expression, every one of them is
+/// [`SqlParam::Text`] with PostgreSQL.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlParam {
+    Text(String),
+    Integer(i64),
+    Real(f64),
+}
+
+/// The JSON types a "type" predicate can check in SQL.
+#[derive(Debug, Clone, Copy)]
+enum JsonType {
+    Number,
+    String,
+    Boolean,
+    Object,
+    Array,
+    Null,
+}
+
+/// Where a LIKE pattern is matched in a string.
+#[derive(Debug, Clone, Copy)]
+enum Affix {
+    Exact,
+    Start,
+    End,
+    Contains,
+}
+
+/// A SQLite JSON path.
+#[derive(Debug, Clone)]
+enum SqlitePath {
+    /// A path known ahead of time, bound as a parameter.
+    Static(String),
+    /// An expression computing the path, as the meaning of a reference token
+    /// depends on the document.
+    Dynamic(String),
+}
+
+impl Predicate {
+    /// Translate the predicate into a parameterized SQL boolean expression for
+    /// documents stored in `column`, paths being resolved from `ctx`. The
+    /// column is inserted in the expression as it is.
+    ///
+    /// See the [`sql`](crate::sql) module for what can be translated.
+    pub fn to_sql(
+        &self,
+        dialect: Dialect,
+        column: &str,
+        ctx: PredicateContext,
+    ) -> Result<Sql, SqlError> {
+        let mut translator = Translator {
+            dialect,
+            column,
+            params: Vec::new(),
+        };
+        let sql = translator.predicate(self, &ctx, &mut Vec::new())?;
+
+        Ok(Sql {
+            sql,
+            params: translator.params,
+        })
+    }
+}
+
+struct Translator<'a> {
+    dialect: Dialect,
+    column: &'a str,
+    params: Vec<SqlParam>,
+}
+
+impl Translator<'_> {
+    /// Translate `predicate`, at `location` in the predicate document.
+    fn predicate(
+        &mut self,
+        predicate: &Predicate,
+        ctx: &PredicateContext,
+        location: &mut Vec<String>,
+    ) -> Result<String, SqlError> {
+        let unsupported = |op: &str, location: &[String], reason| SqlError::Unsupported {
+            op: op.to_string(),
+            location: JSONPath::from_tokens(location.to_vec()),
+            reason,
+        };
+
+        match predicate {
+            Predicate::FirstOrder(first_order) => self
+                .first_order(first_order, ctx)
+                .map_err(|reason| unsupported(first_order.op(), location, reason)),
+            Predicate::Extension(extension) => Err(unsupported(
+                extension.op(),
+                location,
+                "the operation is registered by the application",
+            )),
+            Predicate::SecondOrder(second_order) => {
+                let ctx = ctx.scoped(ctx.final_path(&second_order.path().cloned()));
+                let mut apply = Vec::with_capacity(second_order.apply().len());
+                for (index, predicate) in second_order.apply().iter().enumerate() {
+                    location.extend(["apply".to_string(), index.to_string()]);
+                    let sql = self.predicate(predicate, &ctx, location);
+                    location.truncate(location.len() - 2);
+                    apply.push(sql?);
+                }
+
+                Ok(match second_order {
+                    SecondOrder::And(_) => join(apply, "AND", "TRUE"),
+                    SecondOrder::Or(_) => join(apply, "OR", "TRUE"),
+                    SecondOrder::Not(_) => {
+                        let apply = apply.into_iter().map(|sql| format!("NOT {sql}"));
+                        join(apply.collect(), "AND", "TRUE")
+                    }
+                })
+            }
+        }
+    }
+
+    fn first_order(
+        &mut self,
+        first_order: &FirstOrder,
+        ctx: &PredicateContext,
+    ) -> Result<String, &'static str> {
+        if first_order.quantifier().is_some() {
+            return Err("quantifiers aren't supported");
+        }
+        if let Some(Reference::Path(_)) = first_order.value_reference() {
+            return Err("a \"value\" member referencing another location isn't supported");
+        }
+
+        let path = ctx
+            .final_path(&first_order.path().cloned())
+            .unwrap_or(JSONPath::empty());
+        let tokens = path.tokens();
+
+        // Like when evaluating the predicate, a variable which isn't bound or
+        // isn't of the expected type is reported whatever the document.
+        macro_rules! literal {
+            ($operand:expr) => {
+                match $operand.bound(ctx) {
+                    Some(value) => value,
+                    None => return Ok(ERROR.to_string()),
+                }
+            };
+        }
+
+        let check = match first_order {
+            FirstOrder::Defined(_) => return self.defined(&tokens),
+            FirstOrder::Undefined(_) => return self.undefined(&tokens),
+            FirstOrder::Type(ty) => {
+                let ty = match ty.value.as_str() {
+                    "number" => JsonType::Number,
+                    "string" => JsonType::String,
+                    "boolean" => JsonType::Boolean,
+                    "object" => JsonType::Object,
+                    "array" => JsonType::Array,
+                    "null" => JsonType::Null,
+                    "date" | "time" | "date-time" | "lang" | "lang-range" | "iri"
+                    | "absolute-iri" => return Err("the type can't be checked in SQL"),
+                    // Any other type is compared with the referenced element, a
+                    // missing one being "undefined".
+                    other => {
+                        let equals = self.equals(&tokens, &Value::String(other.to_string()))?;
+                        if other != "undefined" {
+                            return self.when_defined(&tokens, equals);
+                        }
+                        return Ok(match tokens.is_empty() {
+                            true => equals,
+                            false => format!("({} OR {equals})", self.undefined(&tokens)?),
+                        });
+                    }
+                };
+                self.is_type(&tokens, ty)?
+            }
+            FirstOrder::Test(test) => {
+                let value = literal!(&test.value);
+                self.equals_with_case(&tokens, &value, test.ignore_case)?
+            }
+            FirstOrder::In(r#in) => {
+                let values = literal!(&r#in.value);
+                let checks = values
+                    .iter()
+                    .map(|value| self.equals_with_case(&tokens, value, r#in.ignore_case))
+                    .collect::<Result<_, _>>()?;
+                join(checks, "OR", "FALSE")
+            }
+            FirstOrder::Less(less) => {
+                let value = literal!(&less.value);
+                self.compare(&tokens, "<", &value)?
+            }
+            FirstOrder::More(more) => {
+                let value = literal!(&more.value);
+                self.compare(&tokens, ">", &value)?
+            }
+            FirstOrder::Start(start) => {
+                let value = literal!(&start.value);
+                self.like(&tokens, &value, Affix::Start, start.ignore_case)?
+            }
+            FirstOrder::End(end) => {
+                let value = literal!(&end.value);
+                self.like(&tokens, &value, Affix::End, end.ignore_case)?
+            }
+            FirstOrder::Contains(contains) => {
+                let value = literal!(&contains.value);
+                self.like(&tokens, &value, Affix::Contains, contains.ignore_case)?
+            }
+            FirstOrder::Matches(_) | FirstOrder::Contained(_) | FirstOrder::Intersects(_) => {
+                return Err("the operation isn't supported");
+            }
+        };
+
+        self.when_defined(&tokens, check)
+    }
+
+    /// `check`, which must be "false" or "true" when the referenced element
+    /// exists, or `NULL` when it doesn't.
+    fn when_defined(&mut self, tokens: &[String], check: String) -> Result<String, &'static str> {
+        if tokens.is_empty() {
+            return Ok(check);
+        }
+        Ok(format!(
+            "CASE WHEN {} IS NOT NULL THEN {check} END",
+            self.element(tokens)?
+        ))
+    }
+
+    fn defined(&mut self, tokens: &[String]) -> Result<String, &'static str> {
+        if tokens.is_empty() {
+            return Ok("TRUE".to_string());
+        }
+        Ok(format!("({} IS NOT NULL)", self.element(tokens)?))
+    }
+
+    fn undefined(&mut self, tokens: &[String]) -> Result<String, &'static str> {
+        if tokens.is_empty() {
+            return Ok("FALSE".to_string());
+        }
+        Ok(format!("({} IS NULL)", self.element(tokens)?))
+    }
+
+    /// An expression which is `NULL` when the referenced element doesn't
+    /// exist: the `jsonb` element with PostgreSQL, its type with SQLite.
+    fn element(&mut self, tokens: &[String]) -> Result<String, &'static str> {
+        match self.dialect {
+            Dialect::Postgres => Ok(self.jsonb(tokens, false)),
+            Dialect::Sqlite => {
+                let path = self.sqlite_path(tokens)?;
+                Ok(self.sqlite_type(&path))
+            }
+        }
+    }
+
+    fn is_type(&mut self, tokens: &[String], ty: JsonType) -> Result<String, &'static str> {
+        Ok(match self.dialect {
+            Dialect::Postgres => {
+                let name = match ty {
+                    JsonType::Number => "number",
+                    JsonType::String => "string",
+                    JsonType::Boolean => "boolean",
+                    JsonType::Object => "object",
+                    JsonType::Array => "array",
+                    JsonType::Null => "null",
+                };
+                format!("(jsonb_typeof({}) = '{name}')", self.jsonb(tokens, false))
+            }
+            Dialect::Sqlite => {
+                let names = match ty {
+                    JsonType::Number => "'integer', 'real'",
+                    JsonType::String => "'text'",
+                    JsonType::Boolean => "'true', 'false'",
+                    JsonType::Object => "'object'",
+                    JsonType::Array => "'array'",
+                    JsonType::Null => "'null'",
+                };
+                let path = self.sqlite_path(tokens)?;
+                format!("({} IN ({names}))", self.sqlite_type(&path))
+            }
+        })
+    }
+
+    fn equals_with_case(
+        &mut self,
+        tokens: &[String],
+        value: &Value,
+        ignore_case: bool,
+    ) -> Result<String, &'static str> {
+        match value {
+            Value::String(_) if ignore_case => self.like(tokens, value, Affix::Exact, true),
+            Value::Array(_) | Value::Object(_) if ignore_case => {
+                Err("a case-insensitive comparison with an array or an object isn't supported")
+            }
+            // The string representation of other values doesn't depend on the
+            // case.
+            value => self.equals(tokens, value),
+        }
+    }
+
+    fn equals(&mut self, tokens: &[String], value: &Value) -> Result<String, &'static str> {
+        match self.dialect {
+            Dialect::Postgres => {
+                let element = self.jsonb(tokens, false);
+                let value = self.param(SqlParam::Text(value.to_string()));
+                Ok(format!("({element} = {value}::jsonb)"))
+            }
+            Dialect::Sqlite => {
+                let path = self.sqlite_path(tokens)?;
+                self.sqlite_equals(&path, value)
+            }
+        }
+    }
+
+    /// Compare the referenced element, which may not exist, with `value` the
+    /// way [`Value`] does.
+    fn sqlite_equals(&mut self, path: &SqlitePath, value: &Value) -> Result<String, &'static str> {
+        let ty = self.sqlite_type(path);
+        let extract = |translator: &mut Self, param| {
+            let value = translator.sqlite_value(path);
+            let param = translator.param(param);
+            format!("{value} IS {param}")
+        };
+
+        Ok(match value {
+            Value::Null => format!("({ty} IS 'null')"),
+            Value::Bool(true) => format!("({ty} IS 'true')"),
+            Value::Bool(false) => format!("({ty} IS 'false')"),
+            Value::Number(number) => match (number.as_i64(), number.as_f64()) {
+                (Some(integer), _) => {
+                    let value = extract(self, SqlParam::Integer(integer));
+                    format!("({ty} IS 'integer' AND {value})")
+                }
+                _ if number.is_u64() => {
+                    return Err("SQLite doesn't support integers larger than a 64-bit signed one")
+                }
+                (None, Some(real)) => {
+                    let value = extract(self, SqlParam::Real(real));
+                    format!("({ty} IS 'real' AND {value})")
+                }
+                (None, None) => return Err("the number isn't supported"),
+            },
+            Value::String(string) => {
+                let value = extract(self, SqlParam::Text(string.clone()));
+                format!("({ty} IS 'text' AND {value})")
+            }
+            Value::Array(values) => {
+                let path_sql = self.sqlite_path_sql(path);
+                let mut checks = vec![
+                    format!("{ty} IS 'array'"),
+                    format!(
+                        "json_array_length({}, {path_sql}) IS {}",
+                        self.column,
+                        values.len()
+                    ),
+                ];
+                for (index, value) in values.iter().enumerate() {
+                    let element = self.sqlite_child(path, &format!("[{index}]"));
+                    checks.push(self.sqlite_equals(&element, value)?);
+                }
+                format!("({})", checks.join(" AND "))
+            }
+            Value::Object(members) => {
+                let path_sql = self.sqlite_path_sql(path);
+                let mut checks = vec![
+                    format!("{ty} IS 'object'"),
+                    format!(
+                        "(SELECT count(*) FROM json_each({}, {path_sql})) IS {}",
+                        self.column,
+                        members.len()
+                    ),
+                ];
+                for (key, value) in members {
+                    let member = self.sqlite_child(path, &sqlite_key(key)?);
+                    checks.push(self.sqlite_equals(&member, value)?);
+                }
+                format!("({})", checks.join(" AND "))
+            }
+        })
+    }
+
+    /// Compare the referenced element with `value` using `operator`, `<` or
+    /// `>`.
+    fn compare(
+        &mut self,
+        tokens: &[String],
+        operator: &str,
+        value: &Comparable,
+    ) -> Result<String, &'static str> {
+        let Comparable::Number(number) = value else {
+            return Err("dates and times can't be compared in SQL");
+        };
+
+        Ok(match self.dialect {
+            Dialect::Postgres => {
+                let element = self.jsonb(tokens, false);
+                let number = self.param(SqlParam::Text(number.to_string()));
+                format!(
+                    "(jsonb_typeof({element}) = 'number' AND {element} {operator} {number}::jsonb)"
+                )
+            }
+            Dialect::Sqlite => {
+                let path = self.sqlite_path(tokens)?;
+                let ty = self.sqlite_type(&path);
+                let element = self.sqlite_value(&path);
+                let number = self.param(number_param(number));
+                format!("({ty} IN ('integer', 'real') AND {element} {operator} {number})")
+            }
+        })
+    }
+
+    /// Match the referenced element with `value` as a LIKE pattern, a value
+    /// which isn't a string never matching.
+    fn like(
+        &mut self,
+        tokens: &[String],
+        value: &Value,
+        affix: Affix,
+        ignore_case: bool,
+    ) -> Result<String, &'static str> {
+        let Value::String(needle) = value else {
+            return Ok("FALSE".to_string());
+        };
+
+        Ok(match self.dialect {
+            Dialect::Postgres => {
+                let element = self.jsonb(tokens, false);
+                let text = self.jsonb(tokens, true);
+                let operator = if ignore_case { "ILIKE" } else { "LIKE" };
+                let pattern = self.param(SqlParam::Text(like_pattern(needle, affix)));
+                format!("(jsonb_typeof({element}) = 'string' AND {text} {operator} {pattern})")
+            }
+            Dialect::Sqlite => {
+                let path = self.sqlite_path(tokens)?;
+                let ty = self.sqlite_type(&path);
+                let text = self.sqlite_value(&path);
+                // LIKE is case-insensitive with SQLite, and GLOB isn't.
+                let matches = if ignore_case {
+                    let pattern = self.param(SqlParam::Text(like_pattern(needle, affix)));
+                    format!("{pattern} ESCAPE '\\'")
+                } else {
+                    self.param(SqlParam::Text(glob_pattern(needle, affix)))
+                };
+                let operator = if ignore_case { "LIKE" } else { "GLOB" };
+                format!("({ty} IS 'text' AND {text} {operator} {matches})")
+            }
+        })
+    }
+
+    /// The `jsonb` element referenced by `tokens`, or its text when `text` is
+    /// set.
+    ///
+    /// Object members are referenced with `->`, but a reference token which
+    /// can be an array index with `#>` as it's the only operator handling it
+    /// as such for arrays and as a key for objects.
+    fn jsonb(&mut self, tokens: &[String], text: bool) -> String {
+        if tokens.is_empty() {
+            return match text {
+                true => format!("({} #>> '{{}}')", self.column),
+                false => self.column.to_string(),
+            };
+        }
+
+        let mut sql = format!("({}", self.column);
+        let mut rest = tokens;
+        while let Some((token, tail)) = rest.split_first() {
+            let last = tail.is_empty();
+            if token.parse::<usize>().is_ok() {
+                let len = rest
+                    .iter()
+                    .position(|token| token.parse::<usize>().is_err())
+                    .unwrap_or(rest.len());
+                let (indices, tail) = rest.split_at(len);
+                let indices: Vec<_> = indices
+                    .iter()
+                    .map(|token| self.param(SqlParam::Text(token.clone())))
+                    .collect();
+                let operator = if text && tail.is_empty() { "#>>" } else { "#>" };
+                sql.push_str(&format!(" {operator} ARRAY[{}]", indices.join(", ")));
+                rest = tail;
+            } else {
+                let key = self.param(SqlParam::Text(token.clone()));
+                let operator = if text && last { "->>" } else { "->" };
+                sql.push_str(&format!(" {operator} {key}"));
+                rest = tail;
+            }
+        }
+        sql.push(')');
+        sql
+    }
+
+    fn sqlite_path(&mut self, tokens: &[String]) -> Result<SqlitePath, &'static str> {
+        let mut path = SqlitePath::Static("$".to_string());
+
+        for token in tokens {
+            let key = sqlite_key(token)?;
+            path = match token.parse::<usize>() {
+                // The token is an index for an array and a key for an object.
+                Ok(index) => {
+                    let parent = self.sqlite_type(&path);
+                    let array = self.sqlite_child(&path, &format!("[{index}]"));
+                    let array = self.sqlite_path_sql(&array);
+                    let object = self.sqlite_child(&path, &key);
+                    let object = self.sqlite_path_sql(&object);
+                    SqlitePath::Dynamic(format!(
+                        "CASE {parent} WHEN 'array' THEN {array} ELSE {object} END"
+                    ))
+                }
+                Err(_) => self.sqlite_child(&path, &key),
+            };
+        }
+
+        Ok(path)
+    }
+
+    /// The path of a child of `path`, `step` being its SQLite path step.
+    fn sqlite_child(&mut self, path: &SqlitePath, step: &str) -> SqlitePath {
+        match path {
+            SqlitePath::Static(path) => SqlitePath::Static(format!("{path}{step}")),
+            SqlitePath::Dynamic(path) => {
+                let step = self.param(SqlParam::Text(step.to_string()));
+                SqlitePath::Dynamic(format!("({path} || {step})"))
+            }
+        }
+    }
+
+    fn sqlite_path_sql(&mut self, path: &SqlitePath) -> String {
+        match path {
+            SqlitePath::Static(path) => self.param(SqlParam::Text(path.clone())),
+            SqlitePath::Dynamic(sql) => sql.clone(),
+        }
+    }
+
+    /// The type of the referenced element, `NULL` when it doesn't exist.
+    fn sqlite_type(&mut self, path: &SqlitePath) -> String {
+        let path = self.sqlite_path_sql(path);
+        format!("json_type({}, {path})", self.column)
+    }
+
+    fn sqlite_value(&mut self, path: &SqlitePath) -> String {
+        let path = self.sqlite_path_sql(path);
+        format!("json_extract({}, {path})", self.column)
+    }
+
+    /// The placeholder of `param`, which is only bound once.
+    fn param(&mut self, param: SqlParam) -> String {
+        let index = match self.params.iter().position(|bound| *bound == param) {
+            Some(index) => index,
+            None => {
+                self.params.push(param);
+                self.params.len() - 1
+            }
+        };
+
+        match self.dialect {
+            Dialect::Postgres => format!("${}::text", index + 1),
+            Dialect::Sqlite => format!("?{}", index + 1),
+        }
+    }
+}
+
+/// Combine the expressions with `operator`, `empty` being the value of an
+/// empty combination.
+fn join(mut sql: Vec<String>, operator: &str, empty: &str) -> String {
+    match sql.len() {
+        0 => empty.to_string(),
+        1 => sql.remove(0),
+        _ => format!("({})", sql.join(&format!(" {operator} "))),
+    }
+}
+
+fn number_param(number: &Number) -> SqlParam {
+    match number.as_i64() {
+        Some(integer) => SqlParam::Integer(integer),
+        None => SqlParam::Real(number.as_f64().unwrap_or_default()),
+    }
+}
+
+/// The SQLite path step of the object member `key`.
+fn sqlite_key(key: &str) -> Result<String, &'static str> {
+    if key.contains(['"', '\\']) || key.chars().any(char::is_control) {
+        return Err("SQLite can't reference a member whose key has a double quote, a backslash or a control character");
+    }
+    Ok(format!(".\"{key}\""))
+}
+
+/// A LIKE pattern escaped with `\`.
+fn like_pattern(needle: &str, affix: Affix) -> String {
+    let mut escaped = String::with_capacity(needle.len() + 2);
+    for c in needle.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    wrap(escaped, affix, "%")
+}
+
+/// A SQLite GLOB pattern, special characters being escaped as a set.
+fn glob_pattern(needle: &str, affix: Affix) -> String {
+    let mut escaped = String::with_capacity(needle.len() + 2);
+    for c in needle.chars() {
+        match c {
+            '*' | '?' | '[' => {
+                escaped.push('[');
+                escaped.push(c);
+                escaped.push(']');
+            }
+            c => escaped.push(c),
+        }
+    }
+    wrap(escaped, affix, "*")
+}
+
+fn wrap(pattern: String, affix: Affix, any: &str) -> String {
+    match affix {
+        Affix::Exact => pattern,
+        Affix::Start => format!("{pattern}{any}"),
+        Affix::End => format!("{any}{pattern}"),
+        Affix::Contains => format!("{any}{pattern}{any}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::{glob_pattern, like_pattern, Affix};
+
+    #[test]
+    fn pattern_test() {
+        assert_eq!(like_pattern("a%b_c\\", Affix::Start), "a\\%b\\_c\\\\%");
+        assert_eq!(like_pattern("", Affix::Contains), "%%");
+        assert_eq!(glob_pattern("a*b?[c]", Affix::End), "*a[*]b[?][[]c]");
+        assert_eq!(glob_pattern("abc", Affix::Exact), "abc");
+    }
+}
@@ -0,0 +1,106 @@
+use json_predicate::{Finding, FindingKind, Predicate};
+
+fn analyze(predicate: &str) -> Vec<String> {
+    let predicate: Predicate = predicate.parse().unwrap();
+    predicate.analyze().iter().map(Finding::to_string).collect()
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = "/a < 5 and /a > 10".parse().unwrap();
+    let findings = predicate.analyze();
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].kind, FindingKind::Contradiction);
+    assert_eq!(findings[0].location.to_string(), "");
+    insta::assert_snapshot!(findings[0].to_string(), @"contradiction: `less` at /apply/0 and `more` at /apply/1 can't both hold for /a");
+}
+
+#[test]
+pub fn detects_contradictions() {
+    insta::assert_debug_snapshot!(analyze("/b == 1 or (/a < 5 and /a > 10)"), @r###"
+    [
+        "contradiction at /apply/1: `less` at /apply/1/apply/0 and `more` at /apply/1/apply/1 can't both hold for /a",
+    ]
+    "###);
+    insta::assert_debug_snapshot!(analyze("defined(/a) and undefined(/a)"), @r###"
+    [
+        "contradiction: `defined` at /apply/0 and `undefined` at /apply/1 can't both hold for /a",
+    ]
+    "###);
+    insta::assert_debug_snapshot!(analyze(r#"/a: and(type(/b) == "number", /b starts-with "x")"#), @r###"
+    [
+        "contradiction: `type` at /apply/0 and `start` at /apply/1 can't both hold for /a/b",
+    ]
+    "###);
+    insta::assert_debug_snapshot!(analyze(r#"/a == 1 and /a == "1""#), @r###"
+    [
+        "contradiction: `test` at /apply/0 and `test` at /apply/1 can't both hold for /a",
+    ]
+    "###);
+    insta::assert_debug_snapshot!(analyze("/a in [1, 2] and /a > 3"), @r###"
+    [
+        "contradiction: `in` at /apply/0 and `more` at /apply/1 can't both hold for /a",
+    ]
+    "###);
+    insta::assert_debug_snapshot!(analyze("/a in []"), @r###"
+    [
+        "contradiction: `in` without any value is never true",
+    ]
+    "###);
+}
+
+#[test]
+pub fn detects_tautologies() {
+    insta::assert_debug_snapshot!(analyze("defined(/a) or undefined(/a)"), @r###"
+    [
+        "tautology: `defined` at /apply/0 and `undefined` at /apply/1 cover every document",
+    ]
+    "###);
+    insta::assert_debug_snapshot!(analyze("defined(/a) and /a > 3"), @r###"
+    [
+        "tautology at /apply/0: `defined` is implied by `more` at /apply/1",
+    ]
+    "###);
+    insta::assert_debug_snapshot!(analyze("not(defined(/a) and /a < 1)"), @"[]");
+    insta::assert_debug_snapshot!(analyze("not(/b == 1 or not(defined(/a) and /a < 1))"), @r###"
+    [
+        "tautology at /apply/0/apply/1/apply/0/apply/0: `defined` is implied by `less` at /apply/0/apply/1/apply/0/apply/1",
+    ]
+    "###);
+    insta::assert_debug_snapshot!(analyze("/a == 1 and or()"), @r###"
+    [
+        "tautology at /apply/1: `or` without any subordinate predicate is always true",
+    ]
+    "###);
+}
+
+#[test]
+pub fn reports_only_the_origin_of_a_finding() {
+    insta::assert_debug_snapshot!(analyze("not(/a < 5 and /a > 10) or /b == 1"), @r###"
+    [
+        "contradiction at /apply/0/apply/0: `less` at /apply/0/apply/0/apply/0 and `more` at /apply/0/apply/0/apply/1 can't both hold for /a",
+    ]
+    "###);
+}
+
+#[test]
+pub fn has_no_false_positive() {
+    let predicates = [
+        "/a < 10 and /a > 5",
+        "/a < 5 or /a > 10",
+        "/a < 5 and /b > 10",
+        "/a: and(/b < 5) and /b > 10",
+        r#"/a equals- "x" and /a == "X""#,
+        "any /a/* < 5 and any /a/* > 10",
+        "/a < /b and /a > 10",
+        "defined(/a) and not(undefined(/a))",
+        "/a == 1 and /a in [1, 2]",
+        "none /a/* in []",
+        "all /a/* in []",
+    ];
+
+    for predicate in predicates {
+        assert_eq!(analyze(predicate), Vec::<String>::new(), "{predicate}");
+    }
+}
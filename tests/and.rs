@@ -3,7 +3,7 @@
 use json_predicate::builder::AndBuilder;
 use json_predicate::context::PredicateContext;
 
-use json_predicate::{Predicate, PredicateImpl, SecondOrder};
+use json_predicate::{Predicate, PredicateErrorKind, PredicateImpl, SecondOrder};
 
 mod utils;
 use serde::Deserialize;
@@ -37,8 +37,7 @@ pub fn return_true_for_and_case_with_shallow_endpoint() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -61,8 +60,7 @@ pub fn return_true_for_and_case_with_deep_endpoint() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -86,8 +84,7 @@ pub fn returns_true_for_and_case_with_t_t_and_compound_paths() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -115,12 +112,11 @@ pub fn returns_true_for_and_case_with_t_t_t() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
-pub fn returns_err_for_and_case_with_t_f() {
+pub fn returns_false_for_and_case_with_t_f() {
     let predicate = Predicate::deserialize(serde_json::json!({
         "op": "and",
         "path": "/objA",
@@ -140,14 +136,74 @@ pub fn returns_err_for_and_case_with_t_f() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
-    insta::assert_debug_snapshot!(result.unwrap_err(), @r###"
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_false_for_and_case_with_t_f_values() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "and",
+        "path": "/objA",
+        "apply": [
+          {
+            "op": "defined",
+            "path": "/stringX"
+          },
+          {
+            "op": "contains",
+            "path": "/stringXYZ",
+            "value": "ABC"
+          }
+        ],
+    }));
+
+    assert!(predicate.is_ok());
+    let result = predicate
+        .unwrap()
+        .evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_err_for_and_case_with_t_err() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "and",
+        "path": "/objA",
+        "apply": [
+          {
+            "op": "defined",
+            "path": "/stringX"
+          },
+          {
+            "op": "contains",
+            "path": "/not_read",
+            "value": "ABC"
+          }
+        ],
+    }));
+
+    assert!(predicate.is_ok());
+    let result = predicate
+        .unwrap()
+        .evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_error());
+    insta::assert_debug_snapshot!(result.err().unwrap(), @r###"
     PredicateError {
         kind: JsonPointerIssue {
             issue: NoSuchKey(
                 "not_read",
             ),
         },
+        op: "contains",
+        path: JSONPath(
+            JsonPointer {
+                ref_toks: [
+                    "objA",
+                    "not_read",
+                ],
+                _phantom: PhantomData<alloc::string::String>,
+            },
+        ),
     }
     "###);
 }
@@ -164,6 +220,38 @@ pub fn returns_true_for_and_case_empty_array() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_err_with_absolute_path_and_op_for_nested_case() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "and",
+        "path": "/objA",
+        "apply": [
+          {
+            "op": "or",
+            "path": "/objB",
+            "apply": [
+              {
+                "op": "start-",
+                "path": "/not_read",
+                "value": "M"
+              }
+            ]
+          }
+        ],
+    }))
+    .unwrap();
+
+    let err = predicate
+        .evaluate(&ENTRY, PredicateContext::default())
+        .err()
+        .unwrap();
+    assert_eq!(err.op(), "start-");
+    assert_eq!(err.path().to_string(), "/objA/objB/not_read");
+    assert!(matches!(
+        err.kind(),
+        PredicateErrorKind::JsonPointerIssue { .. }
+    ));
 }
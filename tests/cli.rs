@@ -0,0 +1,146 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+/// Write `content` to a file named `name` in a temporary directory.
+fn fixture(name: &str, content: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_json-predicate"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // The process may exit before reading its whole input.
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+/// A predicate file, named after the test using it as tests run concurrently.
+fn predicate(test: &str) -> PathBuf {
+    fixture(
+        &format!("cli_{test}.json"),
+        r#"{ "op": "more", "path": "/num", "value": 1 }"#,
+    )
+}
+
+#[test]
+pub fn prints_matching_records_from_stdin() {
+    let predicate = predicate("prints_matching_records_from_stdin");
+    let output = run(
+        &["--print", predicate.to_str().unwrap()],
+        "{\"num\": 1}\n{\"num\": 2}\n{\"a\": [1, 2], \"num\": 3}\n",
+    );
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{\"num\":2}\n{\"a\":[1,2],\"num\":3}\n"
+    );
+}
+
+#[test]
+pub fn exits_with_one_when_no_record_matches() {
+    let predicate = predicate("exits_with_one_when_no_record_matches");
+    let output = run(&[predicate.to_str().unwrap()], "{\"num\": 1}\n{}\n");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+pub fn requires_every_record_to_match_with_all() {
+    let predicate = predicate("requires_every_record_to_match_with_all");
+    let first = fixture("cli_first.json", "{\n  \"num\": 2\n}\n");
+    let second = fixture("cli_second.json", "{\n  \"num\": 0\n}\n");
+
+    let any = run(
+        &[
+            predicate.to_str().unwrap(),
+            first.to_str().unwrap(),
+            second.to_str().unwrap(),
+        ],
+        "",
+    );
+    assert_eq!(any.status.code(), Some(0));
+
+    let all = run(
+        &[
+            "--all",
+            predicate.to_str().unwrap(),
+            first.to_str().unwrap(),
+            second.to_str().unwrap(),
+        ],
+        "",
+    );
+    assert_eq!(all.status.code(), Some(1));
+}
+
+#[test]
+pub fn evaluates_from_the_context() {
+    let predicate = predicate("evaluates_from_the_context");
+    let output = run(
+        &["--context", "/obj", predicate.to_str().unwrap(), "-"],
+        "{\"obj\": {\"num\": 2}}",
+    );
+
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+pub fn explains_evaluations_on_stderr() {
+    let predicate = predicate("explains_evaluations_on_stderr");
+    let output = run(&["--explain", predicate.to_str().unwrap()], "{}");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("<stdin>: record 1\nmore /num: error"));
+}
+
+#[test]
+pub fn exits_with_two_on_invalid_input() {
+    let predicate = predicate("exits_with_two_on_invalid_input");
+    let invalid = fixture("cli_invalid_predicate.json", r#"{ "op": "unknown" }"#);
+
+    let output = run(&[invalid.to_str().unwrap()], "{}");
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = run(&[predicate.to_str().unwrap()], "{\"num\": 2}\n{");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+pub fn binds_variables() {
+    let predicate = fixture(
+        "cli_binds_variables.json",
+        r#"{ "op": "test", "path": "/owner", "value": { "$var": "user" } }"#,
+    );
+    let record = "{\"owner\": \"alice\"}";
+
+    let output = run(
+        &["--var", "user=\"alice\"", predicate.to_str().unwrap()],
+        record,
+    );
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = run(
+        &["--var", "user=\"bob\"", predicate.to_str().unwrap()],
+        record,
+    );
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = run(
+        &["--var", "user=alice", predicate.to_str().unwrap()],
+        record,
+    );
+    assert_eq!(output.status.code(), Some(2));
+}
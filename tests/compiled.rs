@@ -0,0 +1,211 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use json_predicate::context::PredicateContext;
+use json_predicate::{CompiledPredicate, Outcome, Predicate, PredicateImpl};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+/// Counts the allocations made by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+fn predicates() -> Vec<Predicate> {
+    [
+        serde_json::json!({ "op": "contains", "path": "/stringABC", "value": "BC" }),
+        serde_json::json!({ "op": "contains-", "path": "/stringAbC_123", "value": "bc_1" }),
+        serde_json::json!({ "op": "contains-", "path": "/stringAbC_123", "value": "bd" }),
+        serde_json::json!({ "op": "defined", "path": "/objA/null2" }),
+        serde_json::json!({ "op": "undefined", "path": "/objA/objZZZ" }),
+        serde_json::json!({ "op": "start", "path": "/stringABC", "value": "AB" }),
+        serde_json::json!({ "op": "start-", "path": "/stringAbC_123", "value": "abc" }),
+        serde_json::json!({ "op": "end", "path": "/stringABC", "value": "C" }),
+        serde_json::json!({ "op": "end-", "path": "/objA/stringXyZ_789", "value": "xyz_789" }),
+        serde_json::json!({ "op": "end-", "path": "/objA/stringXyZ_789", "value": "xyz" }),
+        serde_json::json!({ "op": "type", "path": "/objA/date", "value": "date" }),
+        serde_json::json!({ "op": "type", "path": "/objA/objZZZ", "value": "undefined" }),
+        serde_json::json!({ "op": "in", "path": "/num1", "value": [3, 1] }),
+        serde_json::json!({ "op": "in-", "path": "/stringABC", "value": ["abc", "xyz"] }),
+        serde_json::json!({ "op": "test", "path": "/arrayA/1", "value": "b" }),
+        serde_json::json!({ "op": "test-", "path": "/arrayB/1", "value": { "FOO": "B" } }),
+        serde_json::json!({ "op": "test-", "path": "/arrayB/1", "value": { "FOO": "C" } }),
+        serde_json::json!({ "op": "matches", "path": "/stringABC", "value": "^A.C$" }),
+        serde_json::json!({ "op": "matches-", "path": "/stringABC", "value": "^a.c$" }),
+        serde_json::json!({ "op": "less", "path": "/objA/num2", "value": 3 }),
+        serde_json::json!({ "op": "more", "path": "/objA/num2", "value": 3 }),
+        serde_json::json!({ "op": "more", "path": "/objA/dateTimeFuture", "value": "2024-01-01T00:00:00Z" }),
+        serde_json::json!({ "op": "less", "path": "/objA/timeOffset", "value": "10:10:10Z" }),
+        serde_json::json!({ "op": "less", "path": "/objA/date", "value": "2010-10-10" }),
+        serde_json::json!({ "op": "contained", "path": "/arrayA", "value": ["c", "b", "a", "d"] }),
+        serde_json::json!({ "op": "contained-", "path": "/arrayA", "value": ["C", "B"] }),
+        serde_json::json!({ "op": "intersects", "path": "/arrayB", "value": [3, "z"] }),
+        serde_json::json!({ "op": "intersects-", "path": "/arrayB", "value": [{ "FOO": "B" }] }),
+        serde_json::json!({ "op": "contains", "path": "/objZZZ/stringX", "value": "X" }),
+        serde_json::json!({ "op": "test", "path": "/arrayA/5", "value": "b" }),
+        serde_json::json!({ "op": "test", "path": "/arrayA/*", "value": "b", "quantifier": "any" }),
+        serde_json::json!({ "op": "type", "path": "/arrayB/*", "value": "string", "quantifier": "all" }),
+        serde_json::json!({ "op": "defined", "path": "/**/num1", "quantifier": { "min": 3 } }),
+        serde_json::json!({ "op": "matches-", "path": "/**/stringX", "value": "^x$", "quantifier": "none" }),
+        serde_json::json!({ "op": "test", "path": "/objX/num1", "value": { "$path": "/num1" } }),
+        serde_json::json!({ "op": "in", "path": "/stringA", "value": { "$path": "/arrayA" } }),
+        serde_json::json!({ "op": "less", "path": "/num1", "value": { "$path": "/objA/num2" } }),
+        serde_json::json!({ "op": "less", "path": "/num1", "value": { "$path": "/stringA" } }),
+        serde_json::json!({ "op": "start", "path": "/arrayA/*", "value": { "$path": "/nope" }, "quantifier": "all" }),
+        serde_json::json!({ "op": "test", "path": "/**/num1", "value": { "$path": "/num1" }, "quantifier": "all" }),
+        serde_json::json!({ "op": "in", "path": "/objZZZ/*", "value": { "$path": "/num1" }, "quantifier": "all" }),
+        serde_json::json!({
+            "op": "and",
+            "path": "/objA",
+            "apply": [
+                { "op": "defined", "path": "/stringX" },
+                {
+                    "op": "or",
+                    "path": "/objB",
+                    "apply": [
+                        { "op": "test", "path": "/stringM", "value": "N" },
+                        { "op": "contains-", "path": "/stringM", "value": "m" }
+                    ]
+                },
+                { "op": "not", "apply": [{ "op": "less", "path": "/num2", "value": 1 }] }
+            ]
+        }),
+        serde_json::json!({
+            "op": "or",
+            "apply": [
+                { "op": "test", "path": "/stringA", "value": "B" },
+                { "op": "test", "path": "/objZZZ", "value": "B" }
+            ]
+        }),
+    ]
+    .into_iter()
+    .map(|predicate| Predicate::deserialize(predicate).unwrap())
+    .collect()
+}
+
+fn same_outcome(left: &Outcome, right: &Outcome) -> bool {
+    match (left, right) {
+        (Outcome::True, Outcome::True) | (Outcome::False, Outcome::False) => true,
+        (Outcome::Error(left), Outcome::Error(right)) => {
+            format!("{left:?}") == format!("{right:?}")
+        }
+        _ => false,
+    }
+}
+
+#[test]
+pub fn evaluates_like_the_predicate() {
+    for predicate in predicates() {
+        let compiled = CompiledPredicate::from(&predicate);
+
+        let expected = predicate.evaluate(&ENTRY, PredicateContext::default());
+        let result = compiled.evaluate(&ENTRY);
+        assert!(
+            same_outcome(&expected, &result),
+            "{predicate:?}: expected {expected:?}, got {result:?}"
+        );
+    }
+}
+
+#[test]
+pub fn folds_the_case_like_the_predicate() {
+    let document = serde_json::json!({ "a": "οδος", "b": "ΟΔΟΣ", "c": ["ΟΔΟΣ"] });
+    let predicates = [
+        serde_json::json!({ "op": "test-", "path": "/a", "value": "ΟΔΟΣ" }),
+        serde_json::json!({ "op": "test-", "path": "/b", "value": "οδος" }),
+        serde_json::json!({ "op": "contains-", "path": "/a", "value": "ΟΣ" }),
+        serde_json::json!({ "op": "contains-", "path": "/b", "value": "ος" }),
+        serde_json::json!({ "op": "start-", "path": "/b", "value": "οδος" }),
+        serde_json::json!({ "op": "end-", "path": "/b", "value": "ς" }),
+        serde_json::json!({ "op": "in-", "path": "/b", "value": ["οδος"] }),
+        serde_json::json!({ "op": "intersects-", "path": "/c", "value": ["οδος"] }),
+    ];
+
+    for predicate in predicates {
+        let predicate = Predicate::deserialize(predicate).unwrap();
+        let compiled = CompiledPredicate::from(&predicate);
+
+        assert!(predicate.test(&document, PredicateContext::default()));
+        assert!(compiled.test(&document), "{predicate:?}");
+    }
+}
+
+#[test]
+pub fn evaluates_with_the_compilation_context() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "test",
+        "path": "/num2",
+        "value": 2
+    }))
+    .unwrap();
+
+    let ctx = PredicateContext::new("/objA".to_string()).unwrap();
+    let compiled = CompiledPredicate::new(&predicate, ctx);
+    assert!(compiled.evaluate(&ENTRY).is_true());
+}
+
+#[test]
+pub fn evaluates_without_allocating() {
+    let data: &serde_json::Value = &ENTRY;
+
+    for predicate in predicates() {
+        let compiled = CompiledPredicate::from(&predicate);
+        if compiled.evaluate(data).is_error() {
+            continue;
+        }
+
+        let before = allocations();
+        let result = compiled.evaluate(data);
+        let after = allocations();
+
+        assert_eq!(before, after, "{predicate:?} allocated");
+        drop(result);
+    }
+}
+
+#[test]
+pub fn filters_documents() {
+    let predicate: Predicate = "/a > 1 and /a < 4".parse().unwrap();
+    let compiled = CompiledPredicate::from(&predicate);
+    let documents: Vec<serde_json::Value> = (0..6)
+        .map(|a| serde_json::json!({ "a": a }))
+        .chain([serde_json::json!({ "b": 2 })])
+        .collect();
+
+    let indices: Vec<usize> = compiled.matching(&documents).collect();
+    assert_eq!(indices, vec![2, 3]);
+
+    let filtered: Vec<serde_json::Value> = compiled.filter(documents).collect();
+    insta::assert_json_snapshot!(filtered, @r###"
+    [
+      {
+        "a": 2
+      },
+      {
+        "a": 3
+      }
+    ]
+    "###);
+}
@@ -0,0 +1,146 @@
+use json_predicate::builder::ContainedBuilder;
+use json_predicate::context::PredicateContext;
+use json_predicate::json_path::JSONPath;
+use json_predicate::{FirstOrder, Predicate, PredicateImpl};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = FirstOrder::from(
+        ContainedBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .ignore_case(false)
+            .value(vec!["a".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    insta::assert_json_snapshot!(predicate);
+}
+
+#[test]
+pub fn returns_true_when_every_element_is_in_supplied_array() {
+    let predicate: Predicate = FirstOrder::from(
+        ContainedBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec!["c".into(), "b".into(), "a".into(), "d".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_when_an_element_is_missing_from_supplied_array() {
+    let predicate: Predicate = FirstOrder::from(
+        ContainedBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec!["a".into(), "b".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_mixed_elements_in_supplied_array() {
+    let predicate: Predicate = FirstOrder::from(
+        ContainedBuilder::default()
+            .path(JSONPath::new("/arrayB").unwrap())
+            .value(vec![
+                3.into(),
+                serde_json::json!({ "foo": "b" }),
+                "a".into(),
+            ])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_for_elements_mismatched_only_by_case() {
+    let predicate: Predicate = FirstOrder::from(
+        ContainedBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec!["A".into(), "B".into(), "C".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_elements_mismatched_only_by_case_with_ignore_case() {
+    let predicate: Predicate = FirstOrder::from(
+        ContainedBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec!["A".into(), "B".into(), "C".into()])
+            .ignore_case(true)
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_for_non_array_value() {
+    let predicate: Predicate = FirstOrder::from(
+        ContainedBuilder::default()
+            .path(JSONPath::new("/stringA").unwrap())
+            .value(vec!["A".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_deserialized_ignore_case_predicate() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "contained-",
+        "path": "/arrayA",
+        "value": ["C", "B", "A"]
+    }))
+    .unwrap();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_err_for_undefined_value() {
+    let predicate: Predicate = FirstOrder::from(
+        ContainedBuilder::default()
+            .path(JSONPath::new("/objZZZ/objZZZZZZZZ").unwrap())
+            .value(vec!["a".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_error());
+}
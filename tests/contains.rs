@@ -36,8 +36,7 @@ pub fn return_true_contained_string() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -53,8 +52,7 @@ pub fn return_false_for_non_contained_string() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -70,8 +68,7 @@ pub fn return_true_for_contained_string() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -87,8 +84,7 @@ pub fn return_false_for_non_contained_string_deep() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -104,8 +100,7 @@ pub fn return_false_for_mismatching_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -121,8 +116,8 @@ pub fn return_err_for_undefined_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
-    insta::assert_display_snapshot!(result.unwrap_err(), @"The pointer pointed to a nonexistent key, pointed key: objZZZ");
+    assert!(result.is_error());
+    insta::assert_display_snapshot!(result.err().unwrap(), @r###""contains" predicate at "/objZZZ/objZZZZZZZZ": The pointer pointed to a nonexistent key, pointed key: objZZZ"###);
 }
 
 #[test]
@@ -138,8 +133,8 @@ pub fn return_false_for_undefined_value_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
-    insta::assert_display_snapshot!(result.unwrap_err(), @"The pointer pointed to a nonexistent key, pointed key: objZZZ");
+    assert!(result.is_error());
+    insta::assert_display_snapshot!(result.err().unwrap(), @r###""contains-" predicate at "/objZZZ/objZZZZZZZZ": The pointer pointed to a nonexistent key, pointed key: objZZZ"###);
 }
 
 #[test]
@@ -155,6 +150,5 @@ pub fn honors_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
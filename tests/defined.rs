@@ -32,8 +32,7 @@ pub fn returns_true_for_existing_key_shallow() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -47,12 +46,11 @@ pub fn returns_true_for_existing_key_with_null_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
-pub fn returns_err_for_non_existent_key_shallow() {
+pub fn returns_false_for_non_existent_key_shallow() {
     let predicate: Predicate = FirstOrder::from(
         DefinedBuilder::default()
             .path(JSONPath::new("/not_a_key").unwrap())
@@ -62,7 +60,7 @@ pub fn returns_err_for_non_existent_key_shallow() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -76,8 +74,7 @@ pub fn returns_true_for_existing_key_deep() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -91,12 +88,11 @@ pub fn returns_true_for_existing_key_with_null_value_deep() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
-pub fn returns_err_for_non_existent_key_deep() {
+pub fn returns_false_for_non_existent_key_deep() {
     let predicate: Predicate = FirstOrder::from(
         DefinedBuilder::default()
             .path(JSONPath::new("/objA/objB/not_a_key").unwrap())
@@ -106,5 +102,5 @@ pub fn returns_err_for_non_existent_key_deep() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_false());
 }
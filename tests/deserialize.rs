@@ -0,0 +1,144 @@
+use json_predicate::{DeserializeErrorKind, FirstOrder, OperatorRegistry, Predicate, SecondOrder};
+use serde::Deserialize;
+
+fn error(predicate: serde_json::Value) -> String {
+    Predicate::deserialize(predicate).unwrap_err().to_string()
+}
+
+#[test]
+pub fn names_the_unknown_op() {
+    let err = error(serde_json::json!({ "op": "contain", "path": "/a", "value": "b" }));
+    insta::assert_snapshot!(err, @"unknown op `contain`, no OperatorRegistry is in scope to resolve it");
+
+    let err = OperatorRegistry::new()
+        .deserialize(serde_json::json!({
+            "op": "or",
+            "apply": [{ "op": "even", "path": "/a" }]
+        }))
+        .unwrap_err()
+        .to_string();
+    insta::assert_snapshot!(err, @"unknown op `even` at /apply/0");
+}
+
+#[test]
+pub fn names_the_missing_member() {
+    let err = error(serde_json::json!({ "path": "/a", "value": "b" }));
+    insta::assert_snapshot!(err, @"op missing");
+
+    let err = error(serde_json::json!({
+        "op": "and",
+        "apply": [
+            { "op": "defined", "path": "/a" },
+            { "op": "contains", "path": "/a" }
+        ]
+    }));
+    insta::assert_snapshot!(err, @"value missing for op `contains` at /apply/1");
+
+    let err = error(serde_json::json!({ "op": "defined" }));
+    insta::assert_snapshot!(err, @"path missing for op `defined`");
+
+    let err = error(serde_json::json!({ "op": "not", "path": "/a" }));
+    insta::assert_snapshot!(err, @"apply missing for op `not`");
+}
+
+#[test]
+pub fn locates_the_member_of_the_wrong_type() {
+    let err = error(serde_json::json!({ "op": 1, "path": "/a" }));
+    insta::assert_snapshot!(err, @"invalid type: integer `1`, expected a string at /op");
+
+    let err = error(serde_json::json!({
+        "op": "or",
+        "apply": [{ "op": "not", "apply": [{ "op": "defined", "path": "a" }] }]
+    }));
+    insta::assert_snapshot!(err, @"An error caused by not having a leading slash on the JSON pointer. at /apply/0/apply/0/path");
+
+    let err = error(serde_json::json!({
+        "op": "test",
+        "path": "/a",
+        "value": 1,
+        "quantifier": "some"
+    }));
+    insta::assert_snapshot!(err, @r###"invalid value: string "some", expected "any", "all", "none" or a count range at /quantifier"###);
+
+    let err = error(serde_json::json!({ "op": "and", "apply": {} }));
+    insta::assert_snapshot!(err, @"invalid type: map, expected an array of predicates at /apply");
+
+    let err = error(serde_json::json!({ "op": "and", "apply": ["/a"] }));
+    insta::assert_snapshot!(err, @r###"invalid type: string "/a", expected a predicate object at /apply/0"###);
+}
+
+#[test]
+pub fn tells_the_kind_and_location_of_the_error() {
+    let kind_and_location = |predicate: serde_json::Value| {
+        let err = Predicate::from_value(&predicate).unwrap_err();
+        (err.kind().clone(), err.location().to_string())
+    };
+
+    assert_eq!(
+        kind_and_location(serde_json::json!({
+            "op": "or",
+            "apply": [{ "op": "contain", "path": "/a", "value": "b" }]
+        })),
+        (
+            DeserializeErrorKind::UnknownOperation {
+                op: "contain".to_string()
+            },
+            "/apply/0".to_string()
+        )
+    );
+    assert_eq!(
+        kind_and_location(serde_json::json!({ "op": "contains", "path": "/a" })),
+        (
+            DeserializeErrorKind::MissingMember {
+                member: "value".to_string()
+            },
+            String::new()
+        )
+    );
+    assert_eq!(
+        kind_and_location(serde_json::json!({ "op": "matches", "path": "/a", "value": 1 })),
+        (DeserializeErrorKind::InvalidType, "/value".to_string())
+    );
+    assert_eq!(
+        kind_and_location(serde_json::json!({ "op": "in", "path": "/a", "value": { "$path": 1 } })),
+        (DeserializeErrorKind::InvalidType, "/value".to_string())
+    );
+    assert_eq!(
+        kind_and_location(serde_json::json!({ "op": "matches", "path": "/a", "value": "(" })),
+        (DeserializeErrorKind::InvalidValue, "/value".to_string())
+    );
+    assert_eq!(
+        kind_and_location(serde_json::json!({
+            "op": "not",
+            "apply": [{ "op": "defined", "path": "a" }]
+        })),
+        (
+            DeserializeErrorKind::InvalidValue,
+            "/apply/0/path".to_string()
+        )
+    );
+
+    let err = OperatorRegistry::new()
+        .from_value(&serde_json::json!({ "op": "even", "path": "/a" }))
+        .unwrap_err();
+    insta::assert_snapshot!(err.to_string(), @"unknown op `even`");
+    assert_eq!(
+        err.kind(),
+        &DeserializeErrorKind::UnknownOperation {
+            op: "even".to_string()
+        }
+    );
+}
+
+#[test]
+pub fn rejects_a_predicate_of_the_other_order() {
+    let err = FirstOrder::deserialize(serde_json::json!({ "op": "and", "apply": [] }))
+        .unwrap_err()
+        .to_string();
+    insta::assert_snapshot!(err, @"op `and` isn't a first order operation");
+
+    let err = SecondOrder::deserialize(serde_json::json!({ "op": "defined", "path": "/a" }))
+        .unwrap_err()
+        .to_string();
+    insta::assert_snapshot!(err, @"op `defined` isn't a second order operation");
+}
@@ -0,0 +1,150 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::Predicate;
+use regex::Regex;
+
+/// The compact Elasticsearch query of the predicate.
+fn query(predicate: &str) -> String {
+    let predicate: Predicate = predicate.parse().unwrap();
+    let ctx = PredicateContext::default().with_variable("v", 9);
+    predicate.to_elasticsearch(ctx).unwrap().to_string()
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = r#"/objA/stringX == "X" and not(/num1 > 3)"#.parse().unwrap();
+
+    let query = predicate
+        .to_elasticsearch(PredicateContext::default())
+        .unwrap();
+    insta::assert_json_snapshot!(query, @r###"
+    {
+      "bool": {
+        "must": [
+          {
+            "term": {
+              "objA.stringX": {
+                "value": "X"
+              }
+            }
+          },
+          {
+            "bool": {
+              "must": [
+                {
+                  "exists": {
+                    "field": "num1"
+                  }
+                }
+              ],
+              "must_not": [
+                {
+                  "range": {
+                    "num1": {
+                      "gt": 3
+                    }
+                  }
+                }
+              ]
+            }
+          }
+        ]
+      }
+    }
+    "###);
+}
+
+#[test]
+pub fn renders_queries() {
+    insta::assert_snapshot!(query("defined(/a/b)"), @r###"{"exists":{"field":"a.b"}}"###);
+    insta::assert_snapshot!(query("undefined(/a)"), @r###"{"bool":{"must_not":[{"exists":{"field":"a"}}]}}"###);
+    insta::assert_snapshot!(query("not(defined(/a))"), @r###"{"bool":{"must_not":[{"exists":{"field":"a"}}]}}"###);
+    insta::assert_snapshot!(query("/a == 1"), @r###"{"term":{"a":{"value":1}}}"###);
+    insta::assert_snapshot!(query(r#"/a equals- "X""#), @r###"{"term":{"a":{"case_insensitive":true,"value":"X"}}}"###);
+    insta::assert_snapshot!(query("/a equals- true"), @r###"{"term":{"a":{"value":true}}}"###);
+    insta::assert_snapshot!(query(r#"/a in ["x", 2]"#), @r###"{"terms":{"a":["x",2]}}"###);
+    insta::assert_snapshot!(query(r#"/a in- ["x", 2]"#), @r###"{"bool":{"should":[{"term":{"a":{"case_insensitive":true,"value":"x"}}},{"term":{"a":{"value":2}}}]}}"###);
+    insta::assert_snapshot!(query(r#"/a in- ["x"]"#), @r###"{"term":{"a":{"case_insensitive":true,"value":"x"}}}"###);
+    insta::assert_snapshot!(query("/a in []"), @r###"{"terms":{"a":[]}}"###);
+    insta::assert_snapshot!(query(r#"/a intersects ["x", "y"]"#), @r###"{"terms":{"a":["x","y"]}}"###);
+    insta::assert_snapshot!(query("/a > 1 and /a < 5"), @r###"{"bool":{"must":[{"range":{"a":{"gt":1}}},{"range":{"a":{"lt":5}}}]}}"###);
+    insta::assert_snapshot!(query(r#"/a > "2010-10-10T10:10:10Z""#), @r###"{"range":{"a":{"gt":"2010-10-10T10:10:10Z"}}}"###);
+    insta::assert_snapshot!(query(r#"/a starts-with "ab""#), @r###"{"prefix":{"a":{"value":"ab"}}}"###);
+    insta::assert_snapshot!(query(r#"/a ends-with- "*?""#), @r###"{"wildcard":{"a":{"case_insensitive":true,"value":"*\\*\\?"}}}"###);
+    insta::assert_snapshot!(query(r#"/a contains "b\\c""#), @r###"{"wildcard":{"a":{"value":"*b\\\\c*"}}}"###);
+    insta::assert_snapshot!(query("/a contains 1"), @r###"{"match_none":{}}"###);
+    insta::assert_snapshot!(query(r#"/a matches "^a.c$""#), @r###"{"regexp":{"a":{"flags":"NONE","value":"a[^\n]c"}}}"###);
+    insta::assert_snapshot!(query(r#"/a matches- "b|c""#), @r###"{"regexp":{"a":{"case_insensitive":true,"flags":"NONE","value":".*(b|c).*"}}}"###);
+    insta::assert_snapshot!(query("/a: or(/b == 1, /c == 2)"), @r###"{"bool":{"should":[{"term":{"a.b":{"value":1}}},{"term":{"a.c":{"value":2}}}]}}"###);
+    insta::assert_snapshot!(query("not(/a == 1, /b < 2)"), @r###"{"bool":{"must":[{"bool":{"must":[{"exists":{"field":"a"}}],"must_not":[{"term":{"a":{"value":1}}}]}},{"bool":{"must":[{"exists":{"field":"b"}}],"must_not":[{"range":{"b":{"lt":2}}}]}}]}}"###);
+    insta::assert_snapshot!(query("not(not(/a == 1) or /b == 2)"), @r###"{"bool":{"must":[{"term":{"a":{"value":1}}},{"bool":{"must":[{"exists":{"field":"b"}}],"must_not":[{"term":{"b":{"value":2}}}]}}]}}"###);
+    insta::assert_snapshot!(query("and()"), @r###"{"match_all":{}}"###);
+    insta::assert_snapshot!(query("not(or())"), @r###"{"match_none":{}}"###);
+    insta::assert_snapshot!(query("/a == $v"), @r###"{"term":{"a":{"value":9}}}"###);
+    insta::assert_snapshot!(query("not(/a == $missing)"), @r###"{"match_none":{}}"###);
+}
+
+#[test]
+pub fn translates_regexes() {
+    let patterns = [
+        "abc",
+        "^abc",
+        r"c\$$",
+        "^a.c$",
+        "a|bc",
+        "(a|b)c+$",
+        r#"^[^a-c"]{2,}"#,
+        r"[.]\.",
+        "x?y*",
+        "@~<#>&",
+    ];
+    let haystacks = [
+        "", "abc", "xabcx", "c$", "abc$", "a\nc", "bcc", "acc", "aacc", "\"\"", "de", "..", "x.",
+        "y", "@~<#>&",
+    ];
+
+    for pattern in patterns {
+        let query = query(&format!(
+            "/a matches {}",
+            serde_json::to_string(pattern).unwrap()
+        ));
+        let query: serde_json::Value = serde_json::from_str(&query).unwrap();
+        let regexp = query["regexp"]["a"]["value"].as_str().unwrap();
+        // Lucene regular expressions are anchored, and their dot matches any
+        // character.
+        let lucene = Regex::new(&format!("(?s)^(?:{regexp})$")).unwrap();
+        let regex = Regex::new(pattern).unwrap();
+
+        for haystack in haystacks {
+            assert_eq!(
+                lucene.is_match(haystack),
+                regex.is_match(haystack),
+                "{pattern} as {regexp} on {haystack:?}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn reports_unsupported_conversions() {
+    let unsupported = |predicate: &str| {
+        let predicate: Predicate = predicate.parse().unwrap();
+        let err = predicate
+            .to_elasticsearch(PredicateContext::default())
+            .unwrap_err();
+        err.to_string()
+    };
+
+    insta::assert_snapshot!(unsupported(r#"/a == 1 or any /a/* == 1"#), @"`test` at /apply/1 can't be converted into an Elasticsearch query: quantifiers aren't supported");
+    insta::assert_snapshot!(unsupported(r#"/a: not(/b == 1, /c > /d)"#), @r###"`more` at /apply/1 can't be converted into an Elasticsearch query: a "value" member referencing another location isn't supported"###);
+    insta::assert_snapshot!(unsupported(r#"/a > "10:10:10Z""#), @"`more` can't be converted into an Elasticsearch query: Elasticsearch has no type for times");
+    insta::assert_snapshot!(unsupported("/a == null"), @"`test` can't be converted into an Elasticsearch query: Elasticsearch doesn't index null values");
+    insta::assert_snapshot!(unsupported("/a in [1, [2]]"), @"`in` can't be converted into an Elasticsearch query: Elasticsearch can't compare an array or an object as a whole");
+    insta::assert_snapshot!(unsupported(r#"type(/a) == "string""#), @"`type` can't be converted into an Elasticsearch query: Elasticsearch can't check the type of a value");
+    insta::assert_snapshot!(unsupported("/a contained [1, 2]"), @"`contained` can't be converted into an Elasticsearch query: the operation isn't supported");
+    insta::assert_snapshot!(unsupported(r#"/a matches "a\\d""#), @"`matches` can't be converted into an Elasticsearch query: only escaped punctuation is supported in a regex");
+    insta::assert_snapshot!(unsupported(r#"/a matches "(?i)a""#), @"`matches` can't be converted into an Elasticsearch query: flags and non-capturing groups aren't supported");
+    insta::assert_snapshot!(unsupported(r#"/a matches "a+?""#), @"`matches` can't be converted into an Elasticsearch query: lazy quantifiers aren't supported");
+    insta::assert_snapshot!(unsupported(r#"/a matches "^a|b""#), @"`matches` can't be converted into an Elasticsearch query: anchors are only supported at the start and at the end of a regex");
+    insta::assert_snapshot!(unsupported(r#"/a.b == 1"#), @"`test` can't be converted into an Elasticsearch query: a field whose name is empty or has a dot can't be referenced");
+    insta::assert_snapshot!(unsupported("@ == 1"), @"`test` can't be converted into an Elasticsearch query: a field whose name is empty or has a dot can't be referenced");
+}
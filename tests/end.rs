@@ -35,8 +35,7 @@ pub fn returns_true_for_end_string_shallow() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -51,8 +50,7 @@ pub fn returns_false_for_non_ending_string_shallow() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -67,8 +65,7 @@ pub fn returns_true_for_end_string_deep() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -83,8 +80,7 @@ pub fn returns_false_for_non_ending_string_deep() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -99,8 +95,7 @@ pub fn returns_false_for_mismatching() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -116,8 +111,7 @@ pub fn honors_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -132,7 +126,7 @@ pub fn returns_false_for_undefined_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
 }
 
 #[test]
@@ -148,5 +142,5 @@ pub fn returns_false_for_undefined_value_with_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
 }
@@ -0,0 +1,172 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::{Outcome, Predicate, PredicateImpl};
+
+fn example(predicate: &str) -> Option<serde_json::Value> {
+    let predicate: Predicate = predicate.parse().unwrap();
+    predicate.example()
+}
+
+fn counterexample(predicate: &str) -> Option<serde_json::Value> {
+    let predicate: Predicate = predicate.parse().unwrap();
+    predicate.counterexample()
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate =
+        r#"/user: and(/name starts-with "Jo", /age > 17, /tags/1 == "admin")"#
+            .parse()
+            .unwrap();
+
+    insta::assert_json_snapshot!(predicate.example(), @r###"
+    {
+      "user": {
+        "age": 18,
+        "name": "Jo",
+        "tags": [
+          null,
+          "admin"
+        ]
+      }
+    }
+    "###);
+    insta::assert_json_snapshot!(predicate.counterexample(), @r###"
+    {
+      "user": {
+        "name": null
+      }
+    }
+    "###);
+}
+
+#[test]
+pub fn derives_values_from_operations() {
+    insta::assert_json_snapshot!(example("/a > 5 and /a < 10"), @r###"
+    {
+      "a": 6
+    }
+    "###);
+    insta::assert_json_snapshot!(example("/a > 5.5 and /a < 6"), @r###"
+    {
+      "a": 5.75
+    }
+    "###);
+    insta::assert_json_snapshot!(example(r#"/a starts-with "x" and /a ends-with "z" and /a contains "y""#), @r###"
+    {
+      "a": "xyz"
+    }
+    "###);
+    insta::assert_json_snapshot!(example(r#"/a in [1, 2] and /a > 1"#), @r###"
+    {
+      "a": 2
+    }
+    "###);
+    insta::assert_json_snapshot!(example(r#"/a matches "^ab\\.c$""#), @r###"
+    {
+      "a": "ab.c"
+    }
+    "###);
+    insta::assert_json_snapshot!(example(r#"/a < "2024-01-01" and type(/b) == "date-time""#), @r###"
+    {
+      "a": "2023-12-31",
+      "b": "1970-01-01T00:00:00Z"
+    }
+    "###);
+    insta::assert_json_snapshot!(example(r#"/a intersects [3, 4]"#), @r###"
+    {
+      "a": [
+        3
+      ]
+    }
+    "###);
+    insta::assert_json_snapshot!(example("/a > /b and /b == 3"), @r###"
+    {
+      "a": 4,
+      "b": 3
+    }
+    "###);
+}
+
+#[test]
+pub fn pushes_not_down() {
+    insta::assert_json_snapshot!(example("not(/a == 1 or defined(/b))"), @r###"
+    {
+      "a": null
+    }
+    "###);
+    insta::assert_json_snapshot!(example("/a == 1 or /b == 2"), @r###"
+    {
+      "a": 1
+    }
+    "###);
+    insta::assert_json_snapshot!(counterexample("/a == 1 or /b == 2"), @r###"
+    {
+      "a": null,
+      "b": null
+    }
+    "###);
+}
+
+#[test]
+pub fn satisfies_quantifiers() {
+    insta::assert_json_snapshot!(example("any /items/*/qty > 3"), @r###"
+    {
+      "items": [
+        {
+          "qty": 4
+        }
+      ]
+    }
+    "###);
+    insta::assert_json_snapshot!(example(r#"none /items/*/status == "x""#), @r###"
+    {
+      "items": [
+        {
+          "status": null
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+pub fn gives_up_on_impossible_predicates() {
+    assert_eq!(example("/a < 5 and /a > 10"), None);
+    assert_eq!(example("defined(/a) and undefined(/a)"), None);
+    assert_eq!(counterexample("defined(/a) or undefined(/a)"), None);
+    assert_eq!(counterexample("or()"), None);
+}
+
+#[test]
+pub fn evaluates_as_expected() {
+    let predicates = [
+        r#"/objA: and(/num2 == 2, not(/stringX == "X" or undefined(/stringXYZ)))"#,
+        r#"not(/objA/num2 > 3 and (/objA/stringX == "Y" or /objA/stringX == "X"))"#,
+        r#"/objA: or(/stringX == "Y", /stringX == "Z", /boolT == true)"#,
+        r#"/a/b/c contains- "Hello" and type(/a/d) == "array""#,
+        r#"/a contained ["x", "y"] and /b in- ["A", "b"]"#,
+        r#"all /a/*/b < 3 and /a/0/c == null"#,
+        r#"/a == /b and /b == 3"#,
+    ];
+
+    for predicate in predicates {
+        let predicate: Predicate = predicate.parse().unwrap();
+
+        let example = predicate.example().unwrap();
+        assert!(
+            matches!(
+                predicate.evaluate(&example, PredicateContext::default()),
+                Outcome::True
+            ),
+            "{predicate} with {example}"
+        );
+        let counterexample = predicate.counterexample().unwrap();
+        assert!(
+            matches!(
+                predicate.evaluate(&counterexample, PredicateContext::default()),
+                Outcome::False
+            ),
+            "{predicate} with {counterexample}"
+        );
+    }
+}
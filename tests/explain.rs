@@ -0,0 +1,73 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::{Predicate, PredicateImpl};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+#[test]
+pub fn explains_the_failing_leaf_of_nested_predicates() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "and",
+        "path": "/objA",
+        "apply": [
+          {
+            "op": "or",
+            "apply": [
+              { "op": "test", "path": "/stringX", "value": "Y" },
+              { "op": "contains", "path": "/stringXYZ", "value": "YZ" }
+            ]
+          },
+          { "op": "more", "path": "/num2", "value": 5 }
+        ]
+    }))
+    .unwrap();
+
+    let explanation = predicate.explain(&ENTRY, PredicateContext::default());
+
+    assert!(explanation.outcome.is_false());
+    assert_eq!(
+        predicate
+            .evaluate(&ENTRY, PredicateContext::default())
+            .is_false(),
+        explanation.outcome.is_false()
+    );
+
+    let failures = explanation.failures();
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].op, "test");
+    assert_eq!(failures[1].op, "more");
+    assert_eq!(failures[1].path.as_ref().unwrap().to_string(), "/objA/num2");
+    assert_eq!(failures[1].value, Some(serde_json::json!(2)));
+
+    insta::assert_display_snapshot!(explanation, @r###"
+    and /objA: false
+      or /objA: true
+        test /objA/stringX = "X": false
+        contains /objA/stringXYZ = "XYZ": true
+      more /objA/num2 = 2: false
+    "###);
+}
+
+#[test]
+pub fn explains_errors() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "or",
+        "apply": [
+          { "op": "defined", "path": "/objZZZ" },
+          { "op": "contains", "path": "/objZZZ/stringX", "value": "X" }
+        ]
+    }))
+    .unwrap();
+
+    let explanation = predicate.explain(&ENTRY, PredicateContext::default());
+
+    assert!(explanation.outcome.is_error());
+    assert_eq!(explanation.children.len(), 2);
+    assert_eq!(explanation.children[1].value, None);
+    insta::assert_display_snapshot!(explanation, @r###"
+    or: error (The pointer pointed to a nonexistent key, pointed key: objZZZ)
+      defined /objZZZ: false
+      contains /objZZZ/stringX: error (The pointer pointed to a nonexistent key, pointed key: objZZZ)
+    "###);
+}
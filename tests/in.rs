@@ -35,8 +35,7 @@ pub fn returns_true_for_string_value_contained_in_supplied_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -51,8 +50,7 @@ pub fn returns_false_for_string_mismatched_only_by_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -68,8 +66,7 @@ pub fn returns_true_for_string_mismatched_only_by_case_with_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -88,8 +85,7 @@ pub fn returns_true_for_shallow_object_in_supplied_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -108,8 +104,7 @@ pub fn returns_true_for_deep_object_in_supplied_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -128,8 +123,7 @@ pub fn returns_true_for_number_value_contained_in_supplied_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -144,8 +138,7 @@ pub fn returns_false_if_value_is_not_an_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -165,8 +158,7 @@ pub fn returns_true_for_string_match_inside_object_with_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -185,8 +177,7 @@ pub fn returns_false_for_string_inside_object_mismatched_only_by_case_without_ig
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -201,14 +192,24 @@ pub fn returns_err_for_undefined_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
-    insta::assert_debug_snapshot!(result.unwrap_err(), @r###"
+    assert!(result.is_error());
+    insta::assert_debug_snapshot!(result.err().unwrap(), @r###"
     PredicateError {
         kind: JsonPointerIssue {
             issue: NoSuchKey(
                 "objZZZ",
             ),
         },
+        op: "in",
+        path: JSONPath(
+            JsonPointer {
+                ref_toks: [
+                    "objZZZ",
+                    "objZZZZZZZZ",
+                ],
+                _phantom: PhantomData<alloc::string::String>,
+            },
+        ),
     }
     "###);
 }
@@ -226,14 +227,24 @@ pub fn returns_err_for_undefined_value_with_ignored_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
-    insta::assert_debug_snapshot!(result.unwrap_err(), @r###"
+    assert!(result.is_error());
+    insta::assert_debug_snapshot!(result.err().unwrap(), @r###"
     PredicateError {
         kind: JsonPointerIssue {
             issue: NoSuchKey(
                 "objZZZ",
             ),
         },
+        op: "in-",
+        path: JSONPath(
+            JsonPointer {
+                ref_toks: [
+                    "objZZZ",
+                    "objZZZZZZZZ",
+                ],
+                _phantom: PhantomData<alloc::string::String>,
+            },
+        ),
     }
     "###);
 }
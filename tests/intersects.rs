@@ -0,0 +1,157 @@
+use json_predicate::builder::IntersectsBuilder;
+use json_predicate::context::PredicateContext;
+use json_predicate::json_path::JSONPath;
+use json_predicate::{FirstOrder, Predicate, PredicateImpl};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .ignore_case(false)
+            .value(vec!["a".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    insta::assert_json_snapshot!(predicate);
+}
+
+#[test]
+pub fn returns_true_when_an_element_is_in_supplied_array() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec!["z".into(), "b".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_when_no_element_is_in_supplied_array() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec!["y".into(), "z".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_shallow_object_in_supplied_array() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/arrayB").unwrap())
+            .value(vec![serde_json::json!({ "foo": "b" })])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_for_elements_mismatched_only_by_case() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec!["B".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_elements_mismatched_only_by_case_with_ignore_case() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec!["B".into()])
+            .ignore_case(true)
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_for_empty_supplied_array() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/arrayA").unwrap())
+            .value(vec![])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_false_for_non_array_value() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/stringA").unwrap())
+            .value(vec!["A".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_deserialized_ignore_case_predicate() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "intersects-",
+        "path": "/arrayB",
+        "value": [{ "FOO": "B" }]
+    }))
+    .unwrap();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_err_for_undefined_value() {
+    let predicate: Predicate = FirstOrder::from(
+        IntersectsBuilder::default()
+            .path(JSONPath::new("/objZZZ/objZZZZZZZZ").unwrap())
+            .value(vec!["a".into()])
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_error());
+}
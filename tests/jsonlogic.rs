@@ -0,0 +1,215 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::{Predicate, PredicateImpl};
+use serde_json::{json, Value};
+
+mod utils;
+use utils::{matching, ENTRY};
+
+/// The documents to compare with, but the one where `/n` is `null`, which
+/// JsonLogic reads like a missing value.
+fn documents() -> Vec<Value> {
+    utils::documents()
+        .into_iter()
+        .filter(|document| document.get("n") != Some(&Value::Null))
+        .collect()
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = r#"/objA/stringX == "X" and not(/num1 > 3)"#.parse().unwrap();
+
+    let rule = predicate.to_jsonlogic(PredicateContext::default()).unwrap();
+    insta::assert_json_snapshot!(rule, @r###"
+    {
+      "and": [
+        {
+          "===": [
+            {
+              "var": "objA.stringX"
+            },
+            "X"
+          ]
+        },
+        {
+          "!==": [
+            {
+              "var": "num1"
+            },
+            null
+          ]
+        },
+        {
+          "!": [
+            {
+              ">": [
+                {
+                  "var": "num1"
+                },
+                3
+              ]
+            }
+          ]
+        }
+      ]
+    }
+    "###);
+
+    let converted = Predicate::from_jsonlogic(&rule).unwrap();
+    insta::assert_snapshot!(converted.to_string(), @r###"/objA/stringX == "X" and not (undefined(/num1) or /num1 == null) and not (defined(/num1) and /num1 > 3)"###);
+    assert!(predicate.test(&ENTRY, PredicateContext::default()));
+    assert!(converted.test(&ENTRY, PredicateContext::default()));
+}
+
+#[test]
+pub fn round_trips() {
+    let predicates = [
+        "defined(/a)",
+        "undefined(/b/d)",
+        "not(defined(/b/c))",
+        "/n == 1",
+        "not(/n == 1)",
+        "/a/0 == 10",
+        r#"/b/c == "ABC""#,
+        "/f == true",
+        "/n equals- 1",
+        r#"/b/c in ["abc", "ABC"]"#,
+        r#"not(/b/c in ["abc"])"#,
+        "/n > 0 and /n < 2",
+        "not(/n < 1)",
+        "/b/d > 2",
+        "/n < 5 or /b/d > 2",
+        r#"/b/c contains "b""#,
+        r#"not(/b/c contains "B")"#,
+        r#"/a/1 contains "x""#,
+        "/b/c contains 1",
+        "not(/b/c contains 1)",
+        r#"/a intersects ["x", 3]"#,
+        r#"not(/a intersects ["y"])"#,
+        "/a intersects []",
+        "/b: or(/c == \"abc\", /d == 2)",
+        "not(/n == 1, /b/d > 2)",
+        "not(not(/a/0 == 10) or /b/c == \"ABC\")",
+        "and()",
+        "not(or())",
+        "/n == $v",
+        "not(/n == $missing)",
+        "/n > $v",
+    ];
+    let documents = documents();
+    let ctx = PredicateContext::default().with_variable("v", 1);
+
+    for predicate in predicates {
+        let predicate: Predicate = predicate.parse().unwrap();
+        let rule = predicate.to_jsonlogic(ctx.clone()).unwrap();
+        let converted = Predicate::from_jsonlogic(&rule).unwrap();
+        assert_eq!(
+            matching(&converted, PredicateContext::default(), &documents),
+            matching(&predicate, ctx.clone(), &documents),
+            "{predicate} as {rule}"
+        );
+    }
+}
+
+#[test]
+pub fn guards_the_orderings_against_missing_values() {
+    // JsonLogic reads a missing value as `null`, which is less than 5.
+    let predicate: Predicate = "/n < 5".parse().unwrap();
+    assert_eq!(
+        predicate.to_jsonlogic(PredicateContext::default()).unwrap(),
+        json!({"and": [{"!==": [{"var": "n"}, null]}, {"<": [{"var": "n"}, 5]}]})
+    );
+
+    let predicate: Predicate = "/n > -1 and /b/d > 2".parse().unwrap();
+    assert_eq!(
+        predicate.to_jsonlogic(PredicateContext::default()).unwrap(),
+        json!({"and": [
+            {"!==": [{"var": "n"}, null]},
+            {">": [{"var": "n"}, -1]},
+            {"!==": [{"var": "b.d"}, null]},
+            {">": [{"var": "b.d"}, 2]},
+        ]})
+    );
+}
+
+#[test]
+pub fn converts_rules() {
+    let converted = |rule: Value| Predicate::from_jsonlogic(&rule).unwrap().to_string();
+
+    insta::assert_snapshot!(converted(json!(true)), @"and()");
+    insta::assert_snapshot!(converted(json!({"==": [{"var": "a.b"}, 1]})), @"/a/b == 1");
+    insta::assert_snapshot!(converted(json!({"===": ["x", {"var": ["a"]}]})), @r###"/a == "x""###);
+    insta::assert_snapshot!(converted(json!({"==": [{"var": "a"}, null]})), @"undefined(/a) or /a == null");
+    insta::assert_snapshot!(converted(json!({"!=": [{"var": "a"}, null]})), @"not (undefined(/a) or /a == null)");
+    insta::assert_snapshot!(converted(json!({"in": [{"var": "a"}, [1, null]]})), @"undefined(/a) or /a in [1,null]");
+    insta::assert_snapshot!(converted(json!({"in": ["b", {"var": "a"}]})), @r###"/a contains "b" or /a intersects ["b"]"###);
+    insta::assert_snapshot!(converted(json!({"<": [1, {"var": "a.0"}, 5]})), @"/a/0 > 1 and /a/0 < 5");
+    insta::assert_snapshot!(converted(json!({"!": {">": [{"var": ""}, 1]}})), @r###"not (defined("") and "" > 1)"###);
+    insta::assert_snapshot!(converted(json!({"or": [{"and": []}, false]})), @"not and() or not and()");
+}
+
+#[test]
+pub fn follows_jsonlogic_semantics() {
+    let rules = [
+        (json!({"==": [{"var": "b.d"}, null]}), vec![0, 1, 4, 7]),
+        (
+            json!({"!": {"==": [{"var": "n"}, 1]}}),
+            vec![0, 1, 3, 4, 6, 7],
+        ),
+        (
+            json!({"!": {">": [{"var": "b.d"}, 2]}}),
+            vec![0, 1, 2, 3, 4, 5, 7],
+        ),
+        (json!({"in": ["b", {"var": "b.c"}]}), vec![2, 3, 6, 7]),
+        (json!({"in": ["x", {"var": "a"}]}), vec![5, 7]),
+        (json!({"<": [0, {"var": "n"}, 2]}), vec![1, 2, 5]),
+        (
+            json!({"!": [{"or": [{"==": [{"var": "f"}, true]}, true]}]}),
+            vec![],
+        ),
+        (json!({"and": []}), vec![]),
+    ];
+    let documents = documents();
+
+    for (rule, expected) in rules {
+        let predicate = Predicate::from_jsonlogic(&rule).unwrap();
+        assert_eq!(
+            matching(&predicate, PredicateContext::default(), &documents),
+            expected,
+            "{rule}"
+        );
+    }
+}
+
+#[test]
+pub fn reports_unsupported_conversions() {
+    let unsupported = |predicate: &str| {
+        let predicate: Predicate = predicate.parse().unwrap();
+        let err = predicate
+            .to_jsonlogic(PredicateContext::default())
+            .unwrap_err();
+        err.to_string()
+    };
+
+    insta::assert_snapshot!(unsupported(r#"/a == 1 or any /a/* == 1"#), @"`test` at /apply/1 can't be converted into a JsonLogic rule: quantifiers aren't supported");
+    insta::assert_snapshot!(unsupported(r#"/a: not(/b == 1, /c > /d)"#), @r###"`more` at /apply/1 can't be converted into a JsonLogic rule: a "value" member referencing another location isn't supported"###);
+    insta::assert_snapshot!(unsupported(r#"/a > "2010-10-10""#), @"`more` can't be converted into a JsonLogic rule: JsonLogic compares dates and times as strings");
+    insta::assert_snapshot!(unsupported(r#"/a == null"#), @"`test` can't be converted into a JsonLogic rule: JsonLogic reads a missing value as null");
+    insta::assert_snapshot!(unsupported(r#"/a in [1, [2]]"#), @"`in` can't be converted into a JsonLogic rule: JsonLogic compares arrays and objects by reference");
+    insta::assert_snapshot!(unsupported(r#"/a contains- "b""#), @"`contains-` can't be converted into a JsonLogic rule: JsonLogic can't compare strings case-insensitively");
+    insta::assert_snapshot!(unsupported(r#"type(/a) == "string""#), @"`type` can't be converted into a JsonLogic rule: JsonLogic can't check the type of a value");
+    insta::assert_snapshot!(unsupported(r#"/a starts-with "b""#), @"`start` can't be converted into a JsonLogic rule: the operation isn't supported");
+    insta::assert_snapshot!(unsupported(r#"/a.b == 1"#), @"`test` can't be converted into a JsonLogic rule: a key which is empty or has a dot can't be referenced");
+
+    let invalid = |rule: Value| Predicate::from_jsonlogic(&rule).unwrap_err().to_string();
+
+    insta::assert_snapshot!(invalid(json!(1)), @"The JsonLogic rule can't be converted into a predicate: a rule must be a boolean or an object with a single operation");
+    insta::assert_snapshot!(invalid(json!({"and": [true], "or": [false]})), @"The JsonLogic rule can't be converted into a predicate: a rule must be a boolean or an object with a single operation");
+    insta::assert_snapshot!(invalid(json!({"and": [true, {"<=": [{"var": "a"}, 1]}]})), @"The JsonLogic rule at /and/1/<= can't be converted into a predicate: the `<=` operation isn't supported");
+    insta::assert_snapshot!(invalid(json!({"!": [true, false]})), @"The JsonLogic rule at /! can't be converted into a predicate: `!` takes one operand");
+    insta::assert_snapshot!(invalid(json!({"or": [{"var": "a"}]})), @"The JsonLogic rule at /or/0/var can't be converted into a predicate: a `var` must be compared with a literal value");
+    insta::assert_snapshot!(invalid(json!({"==": [{"var": "a"}, {"var": "b"}]})), @"The JsonLogic rule at /== can't be converted into a predicate: `==` must compare a `var` with a literal value");
+    insta::assert_snapshot!(invalid(json!({"==": [{"var": "a"}, [1]]})), @"The JsonLogic rule at /== can't be converted into a predicate: JsonLogic compares arrays and objects by reference");
+    insta::assert_snapshot!(invalid(json!({"==": [{"var": ["a", 0]}, 1]})), @"The JsonLogic rule at /== can't be converted into a predicate: a default value of `var` isn't supported");
+    insta::assert_snapshot!(invalid(json!({"<": [{"var": "a"}, "b"]})), @"The JsonLogic rule at /< can't be converted into a predicate: only numbers can be compared");
+    insta::assert_snapshot!(invalid(json!({"in": [{"var": "a"}, "abc"]})), @"The JsonLogic rule at /in can't be converted into a predicate: `in` must compare a `var` with a literal value");
+}
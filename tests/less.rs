@@ -3,7 +3,7 @@
 use json_predicate::builder::LessBuilder;
 use json_predicate::context::PredicateContext;
 use json_predicate::json_path::JSONPath;
-use json_predicate::{FirstOrder, Predicate, PredicateImpl};
+use json_predicate::{FirstOrder, Predicate, PredicateImpl, Temporal};
 
 mod utils;
 use serde::Deserialize;
@@ -32,7 +32,7 @@ pub fn returns_err_for_non_numeric_comparaisons() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("invalid value: string \"XYZ\", expected a number or a date, time or date-time string at /value", line: 0, column: 0)"###);
 
     let predicate = Predicate::deserialize(serde_json::json!({
         "op": "less",
@@ -41,7 +41,7 @@ pub fn returns_err_for_non_numeric_comparaisons() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("invalid value: string \"ABC\", expected a number or a date, time or date-time string at /value", line: 0, column: 0)"###);
 
     let predicate = Predicate::deserialize(serde_json::json!({
         "op": "less",
@@ -50,7 +50,7 @@ pub fn returns_err_for_non_numeric_comparaisons() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("invalid type: sequence, expected a number or a date, time or date-time string at /value", line: 0, column: 0)"###);
 
     let predicate = Predicate::deserialize(serde_json::json!({
         "op": "less",
@@ -59,7 +59,7 @@ pub fn returns_err_for_non_numeric_comparaisons() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("invalid type: map, expected a number or a date, time or date-time string at /value", line: 0, column: 0)"###);
 }
 
 #[test]
@@ -74,8 +74,7 @@ pub fn returns_true_for_greater_predicate_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -90,8 +89,7 @@ pub fn returns_false_for_lesser_predicate_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -106,8 +104,7 @@ pub fn returns_false_for_equal_numeric_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -122,5 +119,125 @@ pub fn returns_err_for_undefined_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
+}
+
+#[test]
+pub fn returns_true_for_later_date_time_predicate_value() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/dateTime").unwrap())
+            .value(Temporal::new("2024-01-01T00:00:00Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_for_earlier_date_time_predicate_value() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/dateTimeFuture").unwrap())
+            .value(Temporal::new("2024-01-01T00:00:00Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_later_date_time_with_offsets_normalized() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/dateTimeOffset").unwrap())
+            .value(Temporal::new("2010-10-10T05:00:00Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_for_later_date_predicate_value() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/date").unwrap())
+            .value(Temporal::new("2010-10-11").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_for_later_time_predicate_value() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/timeOffset").unwrap())
+            .value(Temporal::new("10:10:10Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_for_equal_time_with_offsets_normalized() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/timeOffset").unwrap())
+            .value(Temporal::new("04:40:10Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_false_for_date_compared_to_date_time() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/date").unwrap())
+            .value(Temporal::new("2024-01-01T00:00:00Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_false_for_number_compared_to_date_time() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/num2").unwrap())
+            .value(Temporal::new("2024-01-01T00:00:00Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
 }
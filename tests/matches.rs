@@ -38,8 +38,7 @@ pub fn returns_false_for_any_non_string_target_path() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 
     let predicate: Predicate = FirstOrder::from(
         MatchesBuilder::default()
@@ -51,8 +50,7 @@ pub fn returns_false_for_any_non_string_target_path() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 
     let predicate: Predicate = FirstOrder::from(
         MatchesBuilder::default()
@@ -64,8 +62,7 @@ pub fn returns_false_for_any_non_string_target_path() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 
     let predicate: Predicate = FirstOrder::from(
         MatchesBuilder::default()
@@ -77,8 +74,7 @@ pub fn returns_false_for_any_non_string_target_path() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -90,7 +86,7 @@ pub fn returns_false_for_string_that_would_make_an_invalid_regex() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("regex parse error:\n    \\\n    ^\nerror: incomplete escape sequence, reached end of pattern prematurely at /value", line: 0, column: 0)"###);
 }
 
 #[test]
@@ -132,8 +128,7 @@ pub fn returns_true_for_match_when_providing_string_that_will_become_regexp() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -149,8 +144,7 @@ pub fn returns_true_for_different_case_string_with_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -165,8 +159,7 @@ pub fn returns_false_for_different_case_string_without_ignore_case_true() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -185,8 +178,7 @@ pub fn returns_true_when_providing_matching_regexp_directly() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -201,8 +193,7 @@ pub fn returns_false_when_providing_matching_regex_with_mismatch_case() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -217,8 +208,7 @@ pub fn returns_true_when_providing_matching_i_regexp_without_mismatch_case() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -233,8 +223,7 @@ pub fn returns_true_when_providing_matching_i_regexp_with_mismatch_case() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -249,7 +238,7 @@ pub fn returns_err_for_undefined_value() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
 }
 
 #[test]
@@ -264,5 +253,5 @@ pub fn returns_err_for_undefined_value_with_ignore_case() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
 }
@@ -0,0 +1,191 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::{Predicate, PredicateImpl};
+use serde_json::{json, Value};
+
+mod utils;
+use utils::{documents, matching, ENTRY};
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = r#"/objA/stringX == "X" and not(/num1 > 3)"#.parse().unwrap();
+
+    let filter = predicate.to_mongo(PredicateContext::default()).unwrap();
+    insta::assert_json_snapshot!(filter, @r###"
+    {
+      "$and": [
+        {
+          "objA.stringX": {
+            "$eq": "X"
+          }
+        },
+        {
+          "$nor": [
+            {
+              "num1": {
+                "$gt": 3
+              }
+            }
+          ],
+          "num1": {
+            "$exists": true
+          }
+        }
+      ]
+    }
+    "###);
+
+    let converted = Predicate::from_mongo(&filter).unwrap();
+    insta::assert_snapshot!(converted.to_string(), @r###"/objA/stringX == "X" and (not (defined(/num1) and /num1 > 3) and defined(/num1))"###);
+    assert!(predicate.test(&ENTRY, PredicateContext::default()));
+    assert!(converted.test(&ENTRY, PredicateContext::default()));
+}
+
+#[test]
+pub fn round_trips() {
+    let predicates = [
+        "defined(/a)",
+        "undefined(/a/0)",
+        "not(defined(/b/c))",
+        r#"type(/a) == "array""#,
+        r#"type(/n) == "number""#,
+        r#"type(/n) == "null""#,
+        r#"type(/b/d/1/e) == "boolean""#,
+        r#"type(/t) == "undefined""#,
+        r#"not(type(/t) == "undefined")"#,
+        r#"not(type(/b) == "string")"#,
+        r#"type(/a/1) == "x""#,
+        "/n == null",
+        "not(/n == null)",
+        "/n == 1",
+        "/a/0 == 10",
+        r#"/a/1 equals- "x""#,
+        r#"/b/c == "ABC""#,
+        r#"/b/c equals- "abc""#,
+        r#"not(/b/c equals- "abc")"#,
+        r#"/b/d == [1, {"e": true}]"#,
+        "/a == []",
+        r#"/a/1 in- ["x", 3]"#,
+        "/a/0 in [10, null]",
+        "not(/a/0 in [10, null])",
+        "not(/a/0 in [])",
+        "/a/0 > 9 and /a/0 < 11",
+        "not(/n < 1)",
+        r#"/b starts-with "abc.""#,
+        r#"/b ends-with "*?[""#,
+        r#"/b/c ends-with "abc""#,
+        r#"/b/c contains- "b""#,
+        "/b/c contains 1",
+        "not(/b/c contains 1)",
+        r#"/b/c matches "^a""#,
+        r#"not(/b/c matches- "^a")"#,
+        "/b: or(/c == \"abc\", /0 == \"zero\")",
+        "not(/n == 1, /a == [])",
+        "not(not(/a/0 == 10) or /b/c == \"ABC\")",
+        "and()",
+        "not(or())",
+        "/a/0 == $v",
+        "not(/a/0 == $missing)",
+        "/a/0 > $v",
+    ];
+    let documents = documents();
+    let ctx = PredicateContext::default().with_variable("v", 9);
+
+    for predicate in predicates {
+        let predicate: Predicate = predicate.parse().unwrap();
+        let filter = predicate.to_mongo(ctx.clone()).unwrap();
+        let converted = Predicate::from_mongo(&filter).unwrap();
+        assert_eq!(
+            matching(&converted, PredicateContext::default(), &documents),
+            matching(&predicate, ctx.clone(), &documents),
+            "{predicate} as {filter}"
+        );
+    }
+}
+
+#[test]
+pub fn converts_filters() {
+    let converted = |filter: Value| Predicate::from_mongo(&filter).unwrap().to_string();
+
+    insta::assert_snapshot!(converted(json!({})), @"and()");
+    insta::assert_snapshot!(converted(json!({"a.b": 1, "c": {"d": 2}})), @r###"/a/b == 1 and /c == {"d":2}"###);
+    insta::assert_snapshot!(converted(json!({"a": null})), @"undefined(/a) or /a == null");
+    insta::assert_snapshot!(converted(json!({"a": {"$in": [1, null]}})), @"undefined(/a) or /a in [1,null]");
+    insta::assert_snapshot!(converted(json!({"a": {"$gt": 1, "$lt": 5}})), @"/a > 1 and /a < 5");
+    insta::assert_snapshot!(converted(json!({"a": {"$regex": "^a.c$", "$options": "mi"}})), @r###"/a matches- "(?m)^a.c$""###);
+    insta::assert_snapshot!(converted(json!({"a": {"$type": ["string", 8]}})), @r###"type(/a) == "string" or type(/a) == "boolean""###);
+    insta::assert_snapshot!(converted(json!({"$or": [{"a": {"$exists": 0}}, {"b": {"$eq": "x"}}]})), @r###"undefined(/a) or /b == "x""###);
+    insta::assert_snapshot!(converted(json!({"$nor": [{"a": 1}, {"b": {"$exists": true}}]})), @"not(defined(/a) and /a == 1, defined(/b))");
+}
+
+#[test]
+pub fn follows_mongodb_semantics() {
+    let filters = [
+        (json!({"n": null}), vec![0, 1, 4, 5]),
+        (json!({"n": {"$in": [1, null]}}), vec![0, 1, 3, 4, 5, 6]),
+        (json!({"$nor": [{"n": 1}]}), vec![0, 1, 2, 4, 5, 7, 8]),
+        (
+            json!({"$nor": [{"b.c": {"$regex": "^a"}}]}),
+            vec![0, 1, 2, 5, 6, 8],
+        ),
+        (
+            json!({"$nor": [{"n": {"$type": "number"}}]}),
+            vec![0, 1, 4, 5],
+        ),
+        (
+            json!({"$nor": [{"$nor": [{"n": {"$lt": 2}}]}]}),
+            vec![2, 3, 6, 8],
+        ),
+        (
+            json!({"b.c": {"$regex": "c$", "$options": "m"}}),
+            vec![3, 4, 7],
+        ),
+    ];
+    let documents = documents();
+
+    for (filter, expected) in filters {
+        let predicate = Predicate::from_mongo(&filter).unwrap();
+        assert_eq!(
+            matching(&predicate, PredicateContext::default(), &documents),
+            expected,
+            "{filter}"
+        );
+    }
+}
+
+#[test]
+pub fn reports_unsupported_conversions() {
+    let unsupported = |predicate: &str| {
+        let predicate: Predicate = predicate.parse().unwrap();
+        let err = predicate.to_mongo(PredicateContext::default()).unwrap_err();
+        err.to_string()
+    };
+
+    insta::assert_snapshot!(unsupported(r#"/a == 1 or any /a/* == 1"#), @"`test` at /apply/1 can't be converted into a MongoDB filter: quantifiers aren't supported");
+    insta::assert_snapshot!(unsupported(r#"/a: not(/b == 1, /c > /d)"#), @r###"`more` at /apply/1 can't be converted into a MongoDB filter: a "value" member referencing another location isn't supported"###);
+    insta::assert_snapshot!(unsupported(r#"/a > "2010-10-10""#), @"`more` can't be converted into a MongoDB filter: dates and times are compared as strings by MongoDB");
+    insta::assert_snapshot!(unsupported(r#"type(/a) == "date""#), @"`type` can't be converted into a MongoDB filter: the type can't be checked by MongoDB");
+    insta::assert_snapshot!(unsupported(r#"/a contained [1, 2]"#), @"`contained` can't be converted into a MongoDB filter: the operation isn't supported");
+    insta::assert_snapshot!(unsupported(r#"/a.b == 1"#), @"`test` can't be converted into a MongoDB filter: a field whose name is empty, has a dot or starts with `$` can't be referenced");
+    insta::assert_snapshot!(unsupported(r#"@ == 1"#), @"`test` can't be converted into a MongoDB filter: a field whose name is empty, has a dot or starts with `$` can't be referenced");
+
+    let invalid = |filter: Value| Predicate::from_mongo(&filter).unwrap_err().to_string();
+
+    insta::assert_snapshot!(invalid(json!([])), @"The MongoDB filter can't be converted into a predicate: a filter must be an object");
+    insta::assert_snapshot!(invalid(json!({"$and": [{"a": 1}, {"b": {"$ne": 1}}]})), @"The MongoDB filter at /$and/1/b/$ne can't be converted into a predicate: the operator isn't supported");
+    insta::assert_snapshot!(invalid(json!({"$where": "this.a"})), @"The MongoDB filter at /$where can't be converted into a predicate: the operator isn't supported");
+    insta::assert_snapshot!(invalid(json!({"$or": []})), @"The MongoDB filter at /$or can't be converted into a predicate: `$or` must be a nonempty array of filters");
+    insta::assert_snapshot!(invalid(json!({"a..b": 1})), @"The MongoDB filter at /a..b can't be converted into a predicate: a field path can't have an empty field name");
+    insta::assert_snapshot!(invalid(json!({"a": {"$gt": 1, "b": 2}})), @"The MongoDB filter at /a can't be converted into a predicate: fields can't be mixed with operators");
+    insta::assert_snapshot!(invalid(json!({"a": {"$lt": "b"}})), @"The MongoDB filter at /a/$lt can't be converted into a predicate: only numbers can be compared");
+    insta::assert_snapshot!(invalid(json!({"a": {"$type": "int"}})), @"The MongoDB filter at /a/$type can't be converted into a predicate: JSON numbers can't be told apart as `int`, use `number`");
+    insta::assert_snapshot!(invalid(json!({"a": {"$type": 7}})), @"The MongoDB filter at /a/$type can't be converted into a predicate: the BSON type number 7 has no JSON equivalent");
+    insta::assert_snapshot!(invalid(json!({"a": {"$type": "objectId"}})), @"The MongoDB filter at /a/$type can't be converted into a predicate: the `objectId` type has no JSON equivalent");
+    insta::assert_snapshot!(invalid(json!({"a": {"$options": "i"}})), @"The MongoDB filter at /a/$options can't be converted into a predicate: `$options` must come with `$regex`");
+    insta::assert_snapshot!(invalid(json!({"a": {"$regex": "a", "$options": "u"}})), @"The MongoDB filter at /a/$regex can't be converted into a predicate: the `u` option isn't supported");
+    insta::assert_snapshot!(invalid(json!({"a": {"$regex": "(?<=a)b"}})), @r###"
+    The MongoDB filter at /a/$regex can't be converted into a predicate: the regex can't be compiled: regex parse error:
+        (?<=a)b
+        ^^^^
+    error: look-around, including look-ahead and look-behind, is not supported
+    "###);
+}
@@ -3,7 +3,7 @@
 use json_predicate::builder::MoreBuilder;
 use json_predicate::context::PredicateContext;
 use json_predicate::json_path::JSONPath;
-use json_predicate::{FirstOrder, Predicate, PredicateImpl};
+use json_predicate::{FirstOrder, Predicate, PredicateImpl, Temporal};
 
 mod utils;
 use serde::Deserialize;
@@ -32,7 +32,7 @@ pub fn returns_err_for_non_numeric_comparaisons() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("invalid value: string \"XYZ\", expected a number or a date, time or date-time string at /value", line: 0, column: 0)"###);
 
     let predicate = Predicate::deserialize(serde_json::json!({
         "op": "more",
@@ -41,7 +41,7 @@ pub fn returns_err_for_non_numeric_comparaisons() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("invalid value: string \"ABC\", expected a number or a date, time or date-time string at /value", line: 0, column: 0)"###);
 
     let predicate = Predicate::deserialize(serde_json::json!({
         "op": "more",
@@ -50,7 +50,7 @@ pub fn returns_err_for_non_numeric_comparaisons() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("invalid type: sequence, expected a number or a date, time or date-time string at /value", line: 0, column: 0)"###);
 
     let predicate = Predicate::deserialize(serde_json::json!({
         "op": "more",
@@ -59,7 +59,7 @@ pub fn returns_err_for_non_numeric_comparaisons() {
     }));
 
     assert!(predicate.is_err());
-    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("data did not match any variant of untagged enum Predicate", line: 0, column: 0)"###);
+    insta::assert_debug_snapshot!(predicate.unwrap_err(), @r###"Error("invalid type: map, expected a number or a date, time or date-time string at /value", line: 0, column: 0)"###);
 }
 
 #[test]
@@ -74,8 +74,7 @@ pub fn returns_false_for_greater_predicate_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -90,8 +89,7 @@ pub fn returns_true_for_lesser_predicate_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -106,8 +104,7 @@ pub fn returns_false_for_equal_numeric_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -122,5 +119,116 @@ pub fn returns_err_for_undefined_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
+}
+
+#[test]
+pub fn returns_true_for_earlier_date_time_predicate_value() {
+    let predicate: Predicate = FirstOrder::from(
+        MoreBuilder::default()
+            .path(JSONPath::new("/objA/dateTimeFuture").unwrap())
+            .value(Temporal::new("2024-01-01T00:00:00Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_for_later_date_time_predicate_value() {
+    let predicate: Predicate = FirstOrder::from(
+        MoreBuilder::default()
+            .path(JSONPath::new("/objA/dateTime").unwrap())
+            .value(Temporal::new("2024-01-01T00:00:00Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_false_for_earlier_date_time_with_offsets_normalized() {
+    let predicate: Predicate = FirstOrder::from(
+        MoreBuilder::default()
+            .path(JSONPath::new("/objA/dateTimeOffset").unwrap())
+            .value(Temporal::new("2010-10-10T10:00:00Z").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_earlier_date_predicate_value() {
+    let predicate: Predicate = FirstOrder::from(
+        MoreBuilder::default()
+            .path(JSONPath::new("/objA/date").unwrap())
+            .value(Temporal::new("2010-10-09").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_for_earlier_time_predicate_value() {
+    let predicate: Predicate = FirstOrder::from(
+        MoreBuilder::default()
+            .path(JSONPath::new("/objA/timeZ").unwrap())
+            .value(Temporal::new("10:10:10+05:30").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_for_non_temporal_string() {
+    let predicate: Predicate = FirstOrder::from(
+        MoreBuilder::default()
+            .path(JSONPath::new("/stringABC").unwrap())
+            .value(Temporal::new("2024-01-01").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_for_deserialized_date_time_predicate() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "more",
+        "path": "/createdAt",
+        "value": "2024-01-01T00:00:00Z"
+    }))
+    .unwrap();
+
+    let data = serde_json::json!({ "createdAt": "2024-03-01T12:00:00+01:00" });
+    let result = predicate.evaluate(&data, PredicateContext::default());
+    assert!(result.is_true());
+    insta::assert_json_snapshot!(predicate, @r###"
+    {
+      "op": "more",
+      "path": "/createdAt",
+      "value": "2024-01-01T00:00:00Z"
+    }
+    "###);
 }
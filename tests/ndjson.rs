@@ -0,0 +1,119 @@
+use std::io::{self, Read};
+
+use json_predicate::context::PredicateContext;
+use json_predicate::ndjson::{NdjsonError, NdjsonFilter, OnInvalid, Summary};
+use json_predicate::{CompiledPredicate, Predicate};
+
+fn filter(predicate: &str) -> NdjsonFilter {
+    NdjsonFilter::new(predicate.parse::<Predicate>().unwrap())
+}
+
+fn run(filter: &NdjsonFilter, input: &str) -> (Result<Summary, NdjsonError>, String) {
+    let mut output = Vec::new();
+    let summary = filter.run(input.as_bytes(), &mut output);
+    (summary, String::from_utf8(output).unwrap())
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let input = concat!(
+        "{\"num\": 1, \"name\": \"a\"}\n",
+        "\n",
+        "{\"num\": 2, \"name\": \"b\"}\r\n",
+        "{\"name\": \"c\"}\n",
+        "{\"num\": 3, \"name\": \"d\"}",
+    );
+    let (summary, output) = run(&filter("/num > 1"), input);
+
+    insta::assert_debug_snapshot!(summary.unwrap(), @r###"
+    Summary {
+        records: 4,
+        matched: 2,
+        errors: 1,
+        skipped: 0,
+    }
+    "###);
+    assert_eq!(
+        output,
+        "{\"num\": 2, \"name\": \"b\"}\n{\"num\": 3, \"name\": \"d\"}\n"
+    );
+}
+
+#[test]
+pub fn keeps_the_order_in_parallel() {
+    let records: Vec<String> = (0..10_000)
+        .map(|num| format!(r#"{{"num": {num}, "even": {}}}"#, num % 2 == 0))
+        .collect();
+    let input: String = records.iter().map(|record| format!("{record}\n")).collect();
+    let expected: String = records
+        .iter()
+        .step_by(2)
+        .map(|record| format!("{record}\n"))
+        .collect();
+
+    for (threads, chunk_size) in [(1, 1024), (4, 1024), (4, 7), (3, 1), (16, 10_000)] {
+        let filter = filter("/even == true")
+            .with_threads(threads)
+            .with_chunk_size(chunk_size);
+        let (summary, output) = run(&filter, &input);
+
+        let summary = summary.unwrap();
+        assert_eq!(summary.records, 10_000, "{threads} threads of {chunk_size}");
+        assert_eq!(summary.matched, 5_000, "{threads} threads of {chunk_size}");
+        assert_eq!(output, expected, "{threads} threads of {chunk_size}");
+    }
+}
+
+#[test]
+pub fn handles_invalid_records() {
+    let input = "{\"num\": 2}\n{\"num\": \n{\"num\": 3}\n";
+
+    for threads in [1, 2] {
+        let aborting = filter("/num > 1").with_threads(threads).with_chunk_size(1);
+        let (err, output) = run(&aborting, input);
+        let err = err.unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        insta::assert_snapshot!(err.to_string(), @"Line 2 isn't a valid JSON record: EOF while parsing a value at line 1 column 8");
+        assert_eq!(output, "{\"num\": 2}\n");
+
+        let skipping = aborting.with_on_invalid(OnInvalid::Skip);
+        let (summary, output) = run(&skipping, input);
+        insta::assert_debug_snapshot!(summary.unwrap(), @r###"
+        Summary {
+            records: 2,
+            matched: 2,
+            errors: 0,
+            skipped: 1,
+        }
+        "###);
+        assert_eq!(output, "{\"num\": 2}\n{\"num\": 3}\n");
+    }
+}
+
+#[test]
+pub fn reports_read_errors() {
+    struct Failing<'a>(&'a [u8]);
+
+    impl Read for Failing<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.read(buf)? {
+                0 => Err(io::Error::other("disconnected")),
+                read => Ok(read),
+            }
+        }
+    }
+
+    let predicate: Predicate = "/num > 1".parse().unwrap();
+    let filter = NdjsonFilter::new(CompiledPredicate::new(
+        &predicate,
+        PredicateContext::default(),
+    ));
+    let mut output = Vec::new();
+    let err = filter
+        .run(Failing(b"{\"num\": 2}\n{\"num\": 3}\n"), &mut output)
+        .unwrap_err();
+
+    assert_eq!(err.line(), Some(3));
+    insta::assert_snapshot!(err.to_string(), @"Couldn't read line 3: disconnected");
+    assert_eq!(output, b"{\"num\": 2}\n{\"num\": 3}\n");
+}
@@ -36,8 +36,7 @@ pub fn return_true_for_not_case_f_f_with_shallow_endpoint() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -60,8 +59,7 @@ pub fn return_true_for_not_case_f_f_with_deep_endpoint() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -85,8 +83,7 @@ pub fn return_false_for_not_case_t_f_and_compound_paths() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -114,8 +111,7 @@ pub fn return_false_for_or_case_t_f_t() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -139,8 +135,59 @@ pub fn return_true_for_not_case_f_f() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn return_false_for_not_case_f_t_values() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "not",
+        "path": "/objA",
+        "apply": [
+          {
+            "op": "contains",
+            "path": "/stringX",
+            "value": "ABC"
+          },
+          {
+            "op": "contains",
+            "path": "/stringXYZ",
+            "value": "XY"
+          }
+        ],
+    }));
+
+    assert!(predicate.is_ok());
+    let result = predicate
+        .unwrap()
+        .evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn return_err_for_not_case_f_err() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "not",
+        "path": "/objA",
+        "apply": [
+          {
+            "op": "contains",
+            "path": "/stringX",
+            "value": "ABC"
+          },
+          {
+            "op": "contains",
+            "path": "/not_real",
+            "value": "ABC"
+          }
+        ],
+    }));
+
+    assert!(predicate.is_ok());
+    let result = predicate
+        .unwrap()
+        .evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_error());
 }
 
 #[test]
@@ -155,6 +202,5 @@ pub fn returns_true_for_not_case_empty_array() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
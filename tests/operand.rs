@@ -0,0 +1,134 @@
+use json_predicate::builder::{MoreBuilder, TestBuilder};
+use json_predicate::context::PredicateContext;
+use json_predicate::json_path::JSONPath;
+use json_predicate::{FirstOrder, Predicate, PredicateErrorKind, PredicateImpl, Quantifier};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+fn predicate(value: serde_json::Value) -> Predicate {
+    Predicate::deserialize(value).unwrap()
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = FirstOrder::from(
+        MoreBuilder::default()
+            .path(JSONPath::new("/endDate").unwrap())
+            .value_path(JSONPath::new("/startDate").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    insta::assert_json_snapshot!(predicate, @r###"
+    {
+      "op": "more",
+      "path": "/endDate",
+      "value": {
+        "$path": "/startDate"
+      }
+    }
+    "###);
+}
+
+#[test]
+pub fn returns_true_when_more_than_another_location() {
+    let predicate = predicate(serde_json::json!({
+        "op": "more",
+        "path": "/objA/dateTimeFuture",
+        "value": { "$path": "/objA/dateTime" }
+    }));
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_when_equal_to_another_location() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/objX/num1").unwrap())
+            .value_path(JSONPath::new("/num1").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_when_in_another_location() {
+    let predicate = predicate(serde_json::json!({
+        "op": "in-",
+        "path": "/stringA",
+        "value": { "$path": "/arrayA" }
+    }));
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_when_resolved_from_the_context() {
+    let predicate = predicate(serde_json::json!({
+        "op": "contains",
+        "path": "/stringXYZ",
+        "value": { "$path": "/stringX" }
+    }));
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::new("/objA".to_string()).unwrap());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_when_quantified() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/arrayA/*").unwrap())
+            .quantifier(Quantifier::Any)
+            .ignore_case(true)
+            .value_path(JSONPath::new("/stringA").unwrap())
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_err_when_the_other_location_is_missing() {
+    let predicate = predicate(serde_json::json!({
+        "op": "test",
+        "path": "/num1",
+        "value": { "$path": "/nope" }
+    }));
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    let err = result.err().unwrap();
+    assert!(matches!(
+        err.kind(),
+        PredicateErrorKind::JsonPointerIssue { .. }
+    ));
+    assert_eq!(err.path().to_string(), "/nope");
+}
+
+#[test]
+pub fn returns_err_when_the_other_location_is_of_the_wrong_type() {
+    let predicate = predicate(serde_json::json!({
+        "op": "less",
+        "path": "/num1",
+        "value": { "$path": "/stringA" }
+    }));
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        &PredicateErrorKind::IncorrectType
+    );
+}
@@ -37,8 +37,7 @@ pub fn return_true_for_or_case_with_shallow_endpoint() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -61,8 +60,7 @@ pub fn return_true_for_or_case_with_deep_endpoint() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -86,8 +84,7 @@ pub fn returns_true_for_or_case_with_t_t_and_compound_paths() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -115,8 +112,7 @@ pub fn returns_true_for_or_case_with_t_f_t() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -140,8 +136,59 @@ pub fn returns_false_for_or_case_with_f_f() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_false_for_or_case_with_f_f_values() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "or",
+        "path": "/objA",
+        "apply": [
+          {
+            "op": "contains",
+            "path": "/stringX",
+            "value": "ABC"
+          },
+          {
+            "op": "type",
+            "path": "/stringXYZ",
+            "value": "number"
+          }
+        ],
+    }));
+
+    assert!(predicate.is_ok());
+    let result = predicate
+        .unwrap()
+        .evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_err_for_or_case_with_f_err() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "or",
+        "path": "/objA",
+        "apply": [
+          {
+            "op": "contains",
+            "path": "/stringX",
+            "value": "ABC"
+          },
+          {
+            "op": "contains",
+            "path": "/not_real",
+            "value": "ABC"
+          }
+        ],
+    }));
+
+    assert!(predicate.is_ok());
+    let result = predicate
+        .unwrap()
+        .evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_error());
 }
 
 #[test]
@@ -156,6 +203,5 @@ pub fn returns_true_for_or_case_empty_array() {
     let result = predicate
         .unwrap()
         .evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
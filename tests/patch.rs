@@ -0,0 +1,143 @@
+use json_predicate::patch::{Patch, PatchError};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+#[test]
+pub fn applies_rfc6902_operations() {
+    let mut document = serde_json::json!({
+        "a": { "b": "c" },
+        "list": [1, 2, 3]
+    });
+
+    let patch = Patch::deserialize(serde_json::json!([
+        { "op": "add", "path": "/a/d", "value": "e" },
+        { "op": "add", "path": "/list/1", "value": 4 },
+        { "op": "add", "path": "/list/-", "value": 5 },
+        { "op": "remove", "path": "/list/0" },
+        { "op": "replace", "path": "/a/b", "value": "f" },
+        { "op": "copy", "from": "/a/b", "path": "/copied" },
+        { "op": "move", "from": "/a/d", "path": "/moved" },
+        { "op": "test", "path": "/moved", "value": "e" }
+    ]))
+    .unwrap();
+
+    let result = patch.apply(&mut document);
+    assert!(result.is_ok());
+    assert_eq!(
+        document,
+        serde_json::json!({
+            "a": { "b": "f" },
+            "list": [4, 2, 3, 5],
+            "copied": "f",
+            "moved": "e"
+        })
+    );
+}
+
+#[test]
+pub fn applies_patch_when_predicates_are_true() {
+    let mut document = ENTRY.clone();
+
+    let patch = Patch::deserialize(serde_json::json!([
+        {
+            "op": "and",
+            "path": "/objA",
+            "apply": [
+                { "op": "defined", "path": "/stringX" },
+                { "op": "type", "path": "/num2", "value": "number" }
+            ]
+        },
+        { "op": "replace", "path": "/objA/stringX", "value": "Y" }
+    ]))
+    .unwrap();
+
+    let result = patch.apply(&mut document);
+    assert!(result.is_ok());
+    assert_eq!(document["objA"]["stringX"], "Y");
+}
+
+#[test]
+pub fn aborts_patch_when_a_predicate_is_false() {
+    let mut document = ENTRY.clone();
+
+    let patch = Patch::deserialize(serde_json::json!([
+        { "op": "replace", "path": "/stringA", "value": "B" },
+        { "op": "contains", "path": "/stringABC", "value": "XYZ" },
+        { "op": "remove", "path": "/stringABC" }
+    ]))
+    .unwrap();
+
+    let result = patch.apply(&mut document);
+    assert!(matches!(
+        result,
+        Err(PatchError::PredicateFailed { index: 1 })
+    ));
+    assert_eq!(document, *ENTRY);
+}
+
+#[test]
+pub fn aborts_patch_when_a_predicate_cannot_be_evaluated() {
+    let mut document = ENTRY.clone();
+
+    let patch = Patch::deserialize(serde_json::json!([
+        { "op": "add", "path": "/stringZ", "value": "Z" },
+        { "op": "contains", "path": "/objZZZ", "value": "Z" }
+    ]))
+    .unwrap();
+
+    let result = patch.apply(&mut document);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().index(), 1);
+    assert_eq!(document, *ENTRY);
+}
+
+#[test]
+pub fn predicates_see_previous_operations() {
+    let mut document = ENTRY.clone();
+
+    let patch = Patch::deserialize(serde_json::json!([
+        { "op": "add", "path": "/objA/stringNew", "value": "NEW" },
+        { "op": "test", "path": "/objA/stringNew", "value": "NEW" }
+    ]))
+    .unwrap();
+
+    let result = patch.apply(&mut document);
+    assert!(result.is_ok());
+}
+
+#[test]
+pub fn returns_err_for_invalid_operations() {
+    let mut document = ENTRY.clone();
+
+    let patch = Patch::deserialize(serde_json::json!([
+        { "op": "remove", "path": "/not_a_key" }
+    ]))
+    .unwrap();
+    let result = patch.apply(&mut document);
+    insta::assert_display_snapshot!(result.unwrap_err(), @"The operation at index 0 references a nonexistent value.");
+
+    let patch = Patch::deserialize(serde_json::json!([
+        { "op": "add", "path": "/arrayA/01", "value": "z" }
+    ]))
+    .unwrap();
+    let result = patch.apply(&mut document);
+    insta::assert_display_snapshot!(result.unwrap_err(), @"The operation at index 0 references an invalid array index.");
+
+    let patch = Patch::deserialize(serde_json::json!([
+        { "op": "move", "from": "/objA", "path": "/objA/objB" }
+    ]))
+    .unwrap();
+    let result = patch.apply(&mut document);
+    insta::assert_display_snapshot!(result.unwrap_err(), @"The operation at index 0 tries to move a value into one of its children.");
+
+    let patch = Patch::deserialize(serde_json::json!([
+        { "op": "move", "from": "/missing", "path": "/missing" }
+    ]))
+    .unwrap();
+    let result = patch.apply(&mut document);
+    insta::assert_display_snapshot!(result.unwrap_err(), @"The operation at index 0 references a nonexistent value.");
+
+    assert_eq!(document, *ENTRY);
+}
@@ -0,0 +1,54 @@
+use json_predicate::arbitrary::document;
+use json_predicate::context::PredicateContext;
+use json_predicate::json_path::JSONPath;
+use json_predicate::{CompiledPredicate, FirstOrder, Predicate, PredicateImpl, SecondOrder};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn predicates_round_trip(predicate in any::<Predicate>()) {
+        let json = serde_json::to_value(&predicate).unwrap();
+        prop_assert_eq!(serde_json::from_value::<Predicate>(json).unwrap(), predicate);
+    }
+
+    #[test]
+    fn first_order_predicates_round_trip(predicate in any::<FirstOrder>()) {
+        let json = serde_json::to_string(&predicate).unwrap();
+        prop_assert_eq!(serde_json::from_str::<FirstOrder>(&json).unwrap(), predicate);
+    }
+
+    #[test]
+    fn second_order_predicates_round_trip(predicate in any::<SecondOrder>()) {
+        let json = serde_json::to_string(&predicate).unwrap();
+        prop_assert_eq!(serde_json::from_str::<SecondOrder>(&json).unwrap(), predicate);
+    }
+
+    #[test]
+    fn paths_round_trip(path in any::<JSONPath>()) {
+        prop_assert_eq!(JSONPath::new(path.to_string()).unwrap(), path.clone());
+        prop_assert_eq!(JSONPath::default() + path.clone(), path);
+    }
+
+    #[test]
+    fn evaluation_never_panics(predicate in any::<Predicate>(), data in document()) {
+        let ctx = PredicateContext::default().with_variable("a", 1);
+
+        let outcome = predicate.test(&data, ctx.clone());
+        let compiled = CompiledPredicate::new(&predicate, ctx.clone()).test(&data);
+        prop_assert_eq!(outcome, compiled);
+        predicate.explain(&data, ctx);
+    }
+
+    #[test]
+    fn mongo_filters_round_trip(predicate in any::<Predicate>(), data in document()) {
+        let ctx = PredicateContext::default().with_variable("a", 1);
+
+        if let Ok(filter) = predicate.to_mongo(ctx.clone()) {
+            let converted = Predicate::from_mongo(&filter).unwrap();
+            prop_assert_eq!(
+                converted.test(&data, PredicateContext::default()),
+                predicate.test(&data, ctx)
+            );
+        }
+    }
+}
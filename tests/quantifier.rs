@@ -0,0 +1,231 @@
+use json_predicate::builder::{DefinedBuilder, TestBuilder, TypeBuilder};
+use json_predicate::context::PredicateContext;
+use json_predicate::json_path::JSONPath;
+use json_predicate::{FirstOrder, Predicate, PredicateErrorKind, PredicateImpl, Quantifier};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/arrayA/*").unwrap())
+            .quantifier(Quantifier::Count {
+                min: 1,
+                max: Some(2),
+            })
+            .value("b")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    insta::assert_json_snapshot!(predicate, @r###"
+    {
+      "op": "test",
+      "path": "/arrayA/*",
+      "quantifier": {
+        "min": 1,
+        "max": 2
+      },
+      "value": "b"
+    }
+    "###);
+}
+
+#[test]
+pub fn deserializes_the_quantifier_member() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "test",
+        "path": "/arrayA/*",
+        "value": "b",
+        "quantifier": "any"
+    }))
+    .unwrap();
+
+    let Predicate::FirstOrder(first_order) = &predicate else {
+        panic!("expected a first order predicate");
+    };
+    assert_eq!(first_order.quantifier(), Some(&Quantifier::Any));
+}
+
+#[test]
+pub fn returns_true_when_any_element_matches() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/arrayA/*").unwrap())
+            .quantifier(Quantifier::Any)
+            .value("b")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_when_not_all_elements_match() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/arrayB/*").unwrap())
+            .quantifier(Quantifier::All)
+            .value("string")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_when_all_elements_match() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/arrayA/*").unwrap())
+            .quantifier(Quantifier::All)
+            .value("string")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_when_no_element_matches_with_none() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/arrayA/*").unwrap())
+            .quantifier(Quantifier::None)
+            .value("z")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_when_the_count_is_in_range() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/arrayB/*").unwrap())
+            .quantifier(Quantifier::Count {
+                min: 1,
+                max: Some(1),
+            })
+            .value("string")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_when_the_count_is_out_of_range() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/arrayA/*").unwrap())
+            .quantifier(Quantifier::Count { min: 4, max: None })
+            .value("string")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_when_matching_with_recursive_descent() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/**/num1").unwrap())
+            .quantifier(Quantifier::All)
+            .value(1)
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+
+    let predicate: Predicate = FirstOrder::from(
+        DefinedBuilder::default()
+            .path(JSONPath::new("/**/num1").unwrap())
+            .quantifier(Quantifier::Count {
+                min: 3,
+                max: Some(3),
+            })
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_when_nothing_matches_with_any() {
+    let predicate: Predicate = FirstOrder::from(
+        DefinedBuilder::default()
+            .path(JSONPath::new("/objZZZ/*").unwrap())
+            .quantifier(Quantifier::Any)
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_when_matching_from_the_context() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/*/stringM").unwrap())
+            .quantifier(Quantifier::Any)
+            .value("M")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::new("/objA".to_string()).unwrap());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_err_for_a_wildcard_without_quantifier() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/arrayA/*").unwrap())
+            .value("b")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        PredicateErrorKind::JsonPointerIssue { .. }
+    ));
+}
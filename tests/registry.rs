@@ -0,0 +1,186 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::json_path::JSONPath;
+use json_predicate::{
+    CompiledPredicate, DeserializeErrorKind, OperatorRegistry, Outcome, Predicate, PredicateImpl,
+};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+/// Whether the referenced element is an even integer.
+#[derive(Deserialize)]
+struct Even {
+    path: Option<JSONPath>,
+}
+
+impl PredicateImpl for Even {
+    fn evaluate(&self, data: &serde_json::Value, ctx: PredicateContext) -> Outcome {
+        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
+        let value = path.get(data).ok().and_then(|value| value.as_i64());
+        value.is_some_and(|value| value % 2 == 0).into()
+    }
+}
+
+fn registry() -> OperatorRegistry {
+    let mut registry = OperatorRegistry::new();
+    registry.register::<Even>("even");
+    registry
+}
+
+fn even_in_and() -> Predicate {
+    registry()
+        .deserialize(serde_json::json!({
+            "op": "and",
+            "path": "/objA",
+            "apply": [
+                { "op": "defined", "path": "/num2" },
+                { "op": "even", "path": "/num2" }
+            ]
+        }))
+        .unwrap()
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    insta::assert_json_snapshot!(even_in_and(), @r###"
+    {
+      "op": "and",
+      "path": "/objA",
+      "apply": [
+        {
+          "op": "defined",
+          "path": "/num2"
+        },
+        {
+          "op": "even",
+          "path": "/num2"
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+pub fn returns_true_when_the_registered_operation_is_satisfied() {
+    let result = even_in_and().evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_when_the_registered_operation_isnt_satisfied() {
+    let predicate = registry()
+        .deserialize(serde_json::json!({ "op": "even", "path": "/num1" }))
+        .unwrap();
+
+    assert_eq!(predicate.op(), "even");
+    assert_eq!(predicate.path(), Some(&JSONPath::new("/num1").unwrap()));
+    assert!(predicate
+        .evaluate(&ENTRY, PredicateContext::default())
+        .is_false());
+}
+
+#[test]
+pub fn fails_to_deserialize_without_the_registry() {
+    let predicate = Predicate::deserialize(serde_json::json!({ "op": "even", "path": "/num1" }));
+    insta::assert_snapshot!(predicate.unwrap_err().to_string(), @"unknown op `even`, no OperatorRegistry is in scope to resolve it");
+
+    let predicate =
+        OperatorRegistry::new().deserialize(serde_json::json!({ "op": "even", "path": "/num1" }));
+    insta::assert_snapshot!(predicate.unwrap_err().to_string(), @"unknown op `even`");
+}
+
+/// Whether the referenced element is an integer of the given parity.
+#[derive(Deserialize)]
+struct Parity {
+    path: Option<JSONPath>,
+    value: Kind,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Kind {
+    Even,
+    Odd,
+}
+
+impl PredicateImpl for Parity {
+    fn evaluate(&self, data: &serde_json::Value, ctx: PredicateContext) -> Outcome {
+        let path = ctx.final_path(&self.path).unwrap_or(JSONPath::empty());
+        let value = path.get(data).ok().and_then(|value| value.as_i64());
+        let remainder = match self.value {
+            Kind::Even => 0,
+            Kind::Odd => 1,
+        };
+        value
+            .is_some_and(|value| value.rem_euclid(2) == remainder)
+            .into()
+    }
+}
+
+#[test]
+pub fn keeps_the_kind_of_the_registered_operation_errors() {
+    let mut registry = OperatorRegistry::new();
+    registry.register::<Parity>("parity");
+
+    let predicate = registry
+        .from_value(&serde_json::json!({ "op": "parity", "path": "/num1", "value": "odd" }))
+        .unwrap();
+    assert!(predicate.test(&ENTRY, PredicateContext::default()));
+
+    let err = registry
+        .from_value(&serde_json::json!({ "op": "parity", "path": "/num1" }))
+        .unwrap_err();
+    insta::assert_snapshot!(err.to_string(), @"missing field `value`");
+    assert_eq!(
+        err.kind(),
+        &DeserializeErrorKind::MissingMember {
+            member: "value".to_string()
+        }
+    );
+
+    let err = registry
+        .from_value(&serde_json::json!({ "op": "parity", "path": "/num1", "value": 1 }))
+        .unwrap_err();
+    insta::assert_snapshot!(err.to_string(), @"invalid type: integer `1`, expected a string or a map");
+    assert_eq!(err.kind(), &DeserializeErrorKind::InvalidType);
+}
+
+#[test]
+pub fn compiles_like_it_evaluates() {
+    let predicate = even_in_and();
+    let compiled = CompiledPredicate::new(&predicate, PredicateContext::default());
+
+    assert!(compiled.test(&ENTRY));
+    assert_eq!(
+        compiled.test(&ENTRY),
+        predicate.test(&ENTRY, PredicateContext::default())
+    );
+}
+
+#[test]
+pub fn explains_the_registered_operation() {
+    let explanation = even_in_and().explain(&ENTRY, PredicateContext::default());
+
+    assert_eq!(explanation.children[1].op, "even");
+    assert_eq!(
+        explanation.children[1].path,
+        Some(JSONPath::new("/objA/num2").unwrap())
+    );
+    assert_eq!(explanation.children[1].value, Some(serde_json::json!(2)));
+    assert!(explanation.children[1].outcome.is_true());
+}
+
+#[test]
+pub fn parses_the_registered_operation_back() {
+    let registry = registry();
+    let predicate = even_in_and();
+
+    let text = predicate.to_string();
+    assert_eq!(
+        text,
+        r#"/objA: and(defined(/num2), {"op":"even","path":"/num2"})"#
+    );
+    assert_eq!(registry.parse(&text).unwrap(), predicate);
+    assert!(text.parse::<Predicate>().is_err());
+}
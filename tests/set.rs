@@ -0,0 +1,97 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::{Outcome, Predicate, PredicateSet};
+
+mod utils;
+use utils::ENTRY;
+
+fn set() -> PredicateSet {
+    let mut set = PredicateSet::new(PredicateContext::default());
+    for (name, predicate) in [
+        ("num", "/num1 == 1"),
+        ("string", r#"/stringABC starts-with "AB""#),
+        ("missing", r#"/objZZZ == "B""#),
+        ("object", r#"/objA: and(defined(/stringX), /num2 < 3)"#),
+        ("same", "/num1 == 1"),
+    ] {
+        set.insert(name, &predicate.parse::<Predicate>().unwrap());
+    }
+    set
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    insta::assert_debug_snapshot!(set().matches(&ENTRY), @r###"
+    [
+        "num",
+        "string",
+        "object",
+        "same",
+    ]
+    "###);
+}
+
+#[test]
+pub fn evaluates_every_predicate() {
+    let set = set();
+    let outcomes: Vec<(&str, &str)> = set
+        .evaluate(&ENTRY)
+        .iter()
+        .map(|(name, outcome)| {
+            let outcome = match outcome {
+                Outcome::True => "true",
+                Outcome::False => "false",
+                Outcome::Error(_) => "error",
+            };
+            (*name, outcome)
+        })
+        .collect();
+
+    insta::assert_debug_snapshot!(outcomes, @r###"
+    [
+        (
+            "num",
+            "true",
+        ),
+        (
+            "string",
+            "true",
+        ),
+        (
+            "missing",
+            "error",
+        ),
+        (
+            "object",
+            "true",
+        ),
+        (
+            "same",
+            "true",
+        ),
+    ]
+    "###);
+}
+
+#[test]
+pub fn replaces_a_predicate_with_the_same_name() {
+    let mut set = set();
+    set.insert("num", &"/num1 == 2".parse::<Predicate>().unwrap());
+
+    assert_eq!(set.len(), 5);
+    insta::assert_debug_snapshot!(set.matches(&ENTRY), @r###"
+    [
+        "string",
+        "object",
+        "same",
+    ]
+    "###);
+}
+
+#[test]
+pub fn compiles_from_the_context() {
+    let mut set = PredicateSet::new(PredicateContext::new("/objA".to_string()).unwrap());
+    set.insert("x", &r#"/stringX == "X""#.parse::<Predicate>().unwrap());
+
+    assert_eq!(set.matches(&ENTRY), vec!["x"]);
+    assert!(PredicateSet::default().matches(&ENTRY).is_empty());
+}
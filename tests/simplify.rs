@@ -0,0 +1,107 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::{Outcome, Predicate, PredicateImpl};
+
+mod utils;
+use utils::ENTRY;
+
+fn simplify(predicate: &str) -> String {
+    let predicate: Predicate = predicate.parse().unwrap();
+    predicate.simplify().to_string()
+}
+
+fn outcome(outcome: Outcome) -> &'static str {
+    match outcome {
+        Outcome::True => "true",
+        Outcome::False => "false",
+        Outcome::Error(_) => "error",
+    }
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate =
+        r#"/objA/num2 == 2 and (/objA/stringX == "X" and /objA/stringX == "X")"#
+            .parse()
+            .unwrap();
+
+    insta::assert_json_snapshot!(predicate.simplify(), @r###"
+    {
+      "op": "and",
+      "path": "/objA",
+      "apply": [
+        {
+          "op": "test",
+          "path": "/num2",
+          "value": 2
+        },
+        {
+          "op": "test",
+          "path": "/stringX",
+          "value": "X"
+        }
+      ]
+    }
+    "###);
+}
+
+#[test]
+pub fn flattens_nested_and_or() {
+    insta::assert_snapshot!(simplify("/a == 1 and (/b == 2 and (/c == 3 and /d == 4))"), @"/a == 1 and /b == 2 and /c == 3 and /d == 4");
+    insta::assert_snapshot!(simplify("/a == 1 or (/b == 2 or /c: or(/d == 3))"), @"/a == 1 or /b == 2 or /c/d == 3");
+    insta::assert_snapshot!(simplify("and(/a == 1)"), @"/a == 1");
+}
+
+#[test]
+pub fn removes_duplicates() {
+    insta::assert_snapshot!(simplify("/a == 1 and /b == 2 and /a == 1"), @"/a == 1 and /b == 2");
+}
+
+#[test]
+pub fn hoists_shared_path_prefixes() {
+    insta::assert_snapshot!(simplify(r#"/a/b/c == 1 and /a/b/d > /a/b/e"#), @"/a/b: and(/c == 1, /d > /e)");
+    insta::assert_snapshot!(simplify(r#"/a/b == 1 or /a/c == 2 or /b == 3"#), @"/a/b == 1 or /a/c == 2 or /b == 3");
+    insta::assert_snapshot!(simplify(r#"any /a/*/b == 1 and defined(/a/c)"#), @"/a: and(any /*/b == 1, defined(/c))");
+}
+
+#[test]
+pub fn folds_and_pushes_not_inward() {
+    insta::assert_snapshot!(simplify("not(not(/a == 1))"), @"/a == 1");
+    insta::assert_snapshot!(simplify("not(defined(/a))"), @"undefined(/a)");
+    insta::assert_snapshot!(simplify("not(/a == 1 and /b == 2)"), @"not /a == 1 or not /b == 2");
+    insta::assert_snapshot!(simplify("not(/a == 1 or defined(/b))"), @"not /a == 1 and undefined(/b)");
+    insta::assert_snapshot!(simplify("not(/a == 1) and not(/b == 2)"), @"not(/a == 1, /b == 2)");
+}
+
+#[test]
+pub fn collapses_tests_on_the_same_path_into_in() {
+    insta::assert_snapshot!(simplify("/a == 1 or /b == 2 or /a == 3 or /a in [3, 4]"), @"/a in [1,3,4] or /b == 2");
+    insta::assert_snapshot!(simplify(r#"/a equals- "x" or /a == "y""#), @r###"/a equals- "x" or /a == "y""###);
+    insta::assert_snapshot!(simplify("not(/a == 1, /a == 2)"), @"not /a in [1,2]");
+}
+
+#[test]
+pub fn evaluates_like_the_original_predicate() {
+    let predicates = [
+        r#"/objA: and(/num2 == 2, not(/stringX == "X" or undefined(/stringXYZ)))"#,
+        r#"not(/objA/num2 > 3 and (/objA/stringX == "Y" or /objA/stringX == "X"))"#,
+        r#"/objA: or(/stringX == "Y", /stringX == "Z", /boolT == true)"#,
+        r#"/objA: or(/stringX == "Y", /missing == "Z")"#,
+        r#"/objA: not(/missing == "Y", /stringX == "Z")"#,
+        r#"not(not(/objA/num2 == /objA/num2), /objA/missing == 1)"#,
+        r#"/objA: and(/objB: and(/num3 > /num2, /stringM == "M"), /num2 < 3)"#,
+        r#"or() and not(and())"#,
+        r#"/arrayN/1/foo/num1 == 1 or /arrayN/1/foo/num1 == 2 or /arrayN/0 == "a""#,
+    ];
+
+    for predicate in predicates {
+        let predicate: Predicate = predicate.parse().unwrap();
+        let simplified = predicate.simplify();
+
+        assert_eq!(
+            outcome(predicate.evaluate(&ENTRY, PredicateContext::default())),
+            outcome(simplified.evaluate(&ENTRY, PredicateContext::default())),
+            "{predicate} simplified as {simplified}"
+        );
+        assert_eq!(simplified.simplify(), simplified, "{simplified}");
+    }
+}
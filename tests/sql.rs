@@ -0,0 +1,226 @@
+use json_predicate::context::PredicateContext;
+use json_predicate::sql::{Dialect, SqlParam};
+use json_predicate::{Predicate, PredicateImpl};
+use rusqlite::types::Value as SqliteValue;
+use rusqlite::Connection;
+use serde_json::{json, Value};
+
+mod utils;
+use utils::ENTRY;
+
+fn documents() -> Vec<Value> {
+    vec![
+        ENTRY.clone(),
+        json!({ "a": [10, "x"], "b": { "0": "zero", "c": "ABC" }, "n": null, "": 1 }),
+        json!({ "a": { "0": 10, "1": "X" }, "b": "abc%_*?[", "n": 1.0, "t": "undefined" }),
+        json!({ "a": [], "b": { "c": "abc", "d": [1, { "e": true }] }, "n": 1, "+1": 2 }),
+        json!({ "a": [0, [1, 2]], "b": { "d": [1, { "e": true }], "c": "abc" }, "-1": 3 }),
+        json!({}),
+        json!([1, { "a": 2 }]),
+        json!("ABC"),
+        json!(5),
+    ]
+}
+
+/// The indices of the documents the predicate holds for in SQLite.
+fn sqlite(predicate: &Predicate, ctx: PredicateContext, documents: &[Value]) -> Vec<usize> {
+    let db = Connection::open_in_memory().unwrap();
+    db.execute("CREATE TABLE documents (id INTEGER, doc TEXT)", ())
+        .unwrap();
+    for (id, document) in documents.iter().enumerate() {
+        db.execute(
+            "INSERT INTO documents VALUES (?1, ?2)",
+            (id, document.to_string()),
+        )
+        .unwrap();
+    }
+
+    let sql = predicate.to_sql(Dialect::Sqlite, "doc", ctx).unwrap();
+    let params = sql.params.into_iter().map(|param| match param {
+        SqlParam::Text(text) => SqliteValue::Text(text),
+        SqlParam::Integer(integer) => SqliteValue::Integer(integer),
+        SqlParam::Real(real) => SqliteValue::Real(real),
+    });
+    let query = format!("SELECT id FROM documents WHERE {} ORDER BY id", sql.sql);
+    let mut statement = db.prepare(&query).unwrap();
+    let ids = statement
+        .query_map(rusqlite::params_from_iter(params), |row| row.get(0))
+        .unwrap();
+    ids.map(Result::unwrap).collect()
+}
+
+/// The indices of the documents the predicate holds for in memory.
+fn memory(predicate: &Predicate, ctx: PredicateContext, documents: &[Value]) -> Vec<usize> {
+    (0..documents.len())
+        .filter(|&id| predicate.test(&documents[id], ctx.clone()))
+        .collect()
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = r#"/objA/stringX == "X" and not(/num1 > 3)"#.parse().unwrap();
+
+    let postgres = predicate
+        .to_sql(Dialect::Postgres, "doc", PredicateContext::default())
+        .unwrap();
+    insta::assert_snapshot!(postgres.sql, @"(CASE WHEN (doc -> $1::text -> $2::text) IS NOT NULL THEN ((doc -> $1::text -> $2::text) = $3::text::jsonb) END AND NOT CASE WHEN (doc -> $4::text) IS NOT NULL THEN (jsonb_typeof((doc -> $4::text)) = 'number' AND (doc -> $4::text) > $5::text::jsonb) END)");
+    insta::assert_debug_snapshot!(postgres.params, @r###"
+    [
+        Text(
+            "objA",
+        ),
+        Text(
+            "stringX",
+        ),
+        Text(
+            "\"X\"",
+        ),
+        Text(
+            "num1",
+        ),
+        Text(
+            "3",
+        ),
+    ]
+    "###);
+
+    let lite = predicate
+        .to_sql(Dialect::Sqlite, "doc", PredicateContext::default())
+        .unwrap();
+    insta::assert_snapshot!(lite.sql, @"(CASE WHEN json_type(doc, ?1) IS NOT NULL THEN (json_type(doc, ?1) IS 'text' AND json_extract(doc, ?1) IS ?2) END AND NOT CASE WHEN json_type(doc, ?3) IS NOT NULL THEN (json_type(doc, ?3) IN ('integer', 'real') AND json_extract(doc, ?3) > ?4) END)");
+    insta::assert_debug_snapshot!(lite.params, @r###"
+    [
+        Text(
+            "$.\"objA\".\"stringX\"",
+        ),
+        Text(
+            "X",
+        ),
+        Text(
+            "$.\"num1\"",
+        ),
+        Integer(
+            3,
+        ),
+    ]
+    "###);
+
+    let ctx = PredicateContext::default();
+    assert!(predicate.test(&ENTRY, ctx.clone()));
+    assert_eq!(
+        sqlite(&predicate, ctx, std::slice::from_ref(&ENTRY)),
+        vec![0]
+    );
+}
+
+#[test]
+pub fn agrees_with_evaluation() {
+    let predicates = [
+        "defined(/a)",
+        "undefined(/a)",
+        "defined(/a/0)",
+        "defined(/a/1/0)",
+        "undefined(/b/0)",
+        "defined(/+1) or defined(/-1)",
+        "defined(/a/+1)",
+        "defined(@)",
+        "defined(/)",
+        r#"type(/a) == "array""#,
+        r#"type(/a) == "object""#,
+        r#"type(/n) == "number""#,
+        r#"type(/n) == "null""#,
+        r#"type(/b) == "string""#,
+        r#"type(/b/d/1/e) == "boolean""#,
+        r#"type(/t) == "undefined""#,
+        r#"type(/a) == "10""#,
+        r#"not(type(/x) == "string")"#,
+        "/n == null",
+        "/n == 1",
+        "/n == 1.0",
+        "/a/0 == 10",
+        r#"/a/1 == "X""#,
+        r#"/a/1 equals- "x""#,
+        r#"/b/c == "ABC""#,
+        r#"/b/c equals- "abc""#,
+        r#"/b/d == [1, {"e": true}]"#,
+        r#"/b == {"c": "abc", "d": [1, {"e": true}]}"#,
+        r#"/b == {"c": "abc"}"#,
+        "/a == []",
+        "/a == [0, [1, 2]]",
+        r#"@ == "ABC""#,
+        r#"@ equals- "abc""#,
+        "/num1 in [1, 2]",
+        r#"/a/1 in- ["x", 3]"#,
+        "/a/0 in []",
+        "not(/a/0 in [])",
+        "/num1 < 2",
+        "/n > 0.5",
+        "/a/0 > 9 and /a/0 < 11",
+        "not(/n < 1)",
+        r#"/b starts-with "abc%""#,
+        r#"/b ends-with "*?[""#,
+        r#"/b contains "_""#,
+        r#"/b contains """#,
+        r#"/b/c starts-with "AB""#,
+        r#"/b/c starts-with- "ab""#,
+        r#"/b/c ends-with- "BC""#,
+        r#"/b/c contains- "b""#,
+        r#"/b/c contains 1"#,
+        r#"/stringAbC_123 contains- "c_1""#,
+        "/: and(/a/0 == 10, /n == 1)",
+        "/b: or(/c == \"abc\", /0 == \"zero\")",
+        "not(/n == 1, /a == [])",
+        "not(/a/0 == 10) or /b/c == \"ABC\"",
+        "and()",
+        "or()",
+        "not()",
+        "/a/0 == $v",
+        "/a/0 == $missing",
+        "not(/a/0 == $missing)",
+        "/a/0 < $v",
+        "/a/0 in $list",
+        "/a/0 in $v",
+    ];
+    let documents = documents();
+    let ctx = PredicateContext::default()
+        .with_variable("v", 10)
+        .with_variable("list", json!([10, "x"]));
+
+    for predicate in predicates {
+        let predicate: Predicate = predicate.parse().unwrap();
+        assert_eq!(
+            sqlite(&predicate, ctx.clone(), &documents),
+            memory(&predicate, ctx.clone(), &documents),
+            "{predicate}"
+        );
+    }
+}
+
+#[test]
+pub fn resolves_paths_from_the_context() {
+    let predicate: Predicate = r#"/c == "abc""#.parse().unwrap();
+    let ctx = PredicateContext::new("/b".to_string()).unwrap();
+    let documents = documents();
+
+    assert_eq!(sqlite(&predicate, ctx.clone(), &documents), vec![3, 4]);
+    assert_eq!(memory(&predicate, ctx, &documents), vec![3, 4]);
+}
+
+#[test]
+pub fn reports_unsupported_predicates() {
+    let unsupported = |predicate: &str| {
+        let predicate: Predicate = predicate.parse().unwrap();
+        predicate
+            .to_sql(Dialect::Sqlite, "doc", PredicateContext::default())
+            .unwrap_err()
+            .to_string()
+    };
+
+    insta::assert_snapshot!(unsupported(r#"/a matches "^a""#), @"`matches` can't be translated into SQL: the operation isn't supported");
+    insta::assert_snapshot!(unsupported(r#"/a == 1 or any /a/* == 1"#), @"`test` at /apply/1 can't be translated into SQL: quantifiers aren't supported");
+    insta::assert_snapshot!(unsupported(r#"/a: not(/b == 1, /c > /d)"#), @r###"`more` at /apply/1 can't be translated into SQL: a "value" member referencing another location isn't supported"###);
+    insta::assert_snapshot!(unsupported(r#"/a > "2010-10-10""#), @"`more` can't be translated into SQL: dates and times can't be compared in SQL");
+    insta::assert_snapshot!(unsupported(r#"type(/a) == "date""#), @"`type` can't be translated into SQL: the type can't be checked in SQL");
+    insta::assert_snapshot!(unsupported(r#"/a equals- ["x"]"#), @"`test-` can't be translated into SQL: a case-insensitive comparison with an array or an object isn't supported");
+    insta::assert_snapshot!(unsupported(r#""/a\"b" == 1"#), @"`test` can't be translated into SQL: SQLite can't reference a member whose key has a double quote, a backslash or a control character");
+}
@@ -0,0 +1,310 @@
+use json_predicate::{Predicate, PredicateImpl};
+
+mod utils;
+use json_predicate::context::PredicateContext;
+use serde::Deserialize;
+use serde_json::json;
+use utils::ENTRY;
+
+fn predicate(value: serde_json::Value) -> Predicate {
+    Predicate::deserialize(value).unwrap()
+}
+
+/// Predicates covering every operation and the shapes the syntax has to
+/// preserve.
+fn predicates() -> Vec<Predicate> {
+    vec![
+        predicate(json!({ "op": "contains", "path": "/stringABC", "value": "B" })),
+        predicate(json!({ "op": "contains-", "path": "/stringABC", "value": "b" })),
+        predicate(json!({ "op": "defined", "path": "/num1" })),
+        predicate(json!({ "op": "undefined", "path": "/nope" })),
+        predicate(json!({ "op": "start", "path": "/stringABC", "value": "A" })),
+        predicate(json!({ "op": "start-", "path": "/stringABC", "value": "a" })),
+        predicate(json!({ "op": "end", "path": "/stringABC", "value": "C" })),
+        predicate(json!({ "op": "end-", "path": "/stringABC", "value": "c" })),
+        predicate(json!({ "op": "type", "path": "/num1", "value": "number" })),
+        predicate(json!({ "op": "in", "path": "/num1", "value": [1, "a", null] })),
+        predicate(json!({ "op": "in-", "path": "/stringA", "value": ["a"] })),
+        predicate(
+            json!({ "op": "test", "path": "/objX", "value": { "num1": 1, "objY": { "num2": 2 } } }),
+        ),
+        predicate(json!({ "op": "test-", "path": "/stringA", "value": "a" })),
+        predicate(json!({ "op": "test", "path": "/num1", "value": -1.5e-3 })),
+        predicate(json!({ "op": "matches", "path": "/stringABC", "value": "^A\\w+\"$" })),
+        predicate(json!({ "op": "matches-", "path": "/stringABC", "value": "^a" })),
+        predicate(json!({ "op": "less", "path": "/num1", "value": 2 })),
+        predicate(json!({ "op": "more", "path": "/objA/date", "value": "2010-10-09" })),
+        predicate(json!({ "op": "contained", "path": "/arrayA", "value": ["a", "b", "c"] })),
+        predicate(json!({ "op": "contained-", "path": "/arrayA", "value": ["A", "B", "C"] })),
+        predicate(json!({ "op": "intersects", "path": "/arrayA", "value": [] })),
+        predicate(json!({ "op": "intersects-", "path": "/arrayA", "value": ["B"] })),
+        predicate(json!({ "op": "test", "path": null, "value": 1 })),
+        predicate(json!({ "op": "test", "path": "", "value": 1 })),
+        predicate(json!({ "op": "test", "path": "/a b/c~1d/(e)/f:g/\"h\"", "value": 1 })),
+        predicate(json!({ "op": "test", "path": "/a/*", "value": 1, "quantifier": "any" })),
+        predicate(json!({ "op": "defined", "path": "/**/a", "quantifier": "all" })),
+        predicate(json!({ "op": "type", "path": "/a/*", "value": "string", "quantifier": "none" })),
+        predicate(
+            json!({ "op": "less", "path": "/a/*", "value": 1.5, "quantifier": { "min": 1 } }),
+        ),
+        predicate(
+            json!({ "op": "in-", "path": "/a/*", "value": ["a"], "quantifier": { "min": 0, "max": 2 } }),
+        ),
+        predicate(json!({ "op": "more", "path": "/a", "value": { "$path": "/b" } })),
+        predicate(json!({ "op": "in-", "path": "/a", "value": { "$path": "" } })),
+        predicate(json!({ "op": "test", "path": "/a", "value": { "$path": "/b c" } })),
+        predicate(json!({ "op": "start", "path": "/a", "value": { "$var": "user_1" } })),
+        predicate(json!({ "op": "in", "path": "/a", "value": { "$var": "user-ids" } })),
+        predicate(json!({
+            "op": "or",
+            "apply": [
+                {
+                    "op": "and",
+                    "apply": [
+                        { "op": "defined", "path": "/a" },
+                        { "op": "and", "apply": [
+                            { "op": "defined", "path": "/b" },
+                            { "op": "defined", "path": "/c" }
+                        ] }
+                    ]
+                },
+                { "op": "or", "apply": [
+                    { "op": "defined", "path": "/d" },
+                    { "op": "defined", "path": "/e" }
+                ] },
+                { "op": "not", "apply": [
+                    { "op": "and", "apply": [
+                        { "op": "defined", "path": "/f" },
+                        { "op": "defined", "path": "/g" }
+                    ] }
+                ] },
+                { "op": "not", "apply": [
+                    { "op": "not", "apply": [{ "op": "defined", "path": "/h" }] }
+                ] }
+            ]
+        })),
+        predicate(json!({
+            "op": "and",
+            "path": "/objA",
+            "apply": [
+                { "op": "and", "apply": [{ "op": "defined", "path": "/num2" }] },
+                { "op": "or", "apply": [] },
+                { "op": "not", "apply": [
+                    { "op": "defined", "path": "/a" },
+                    { "op": "defined", "path": "/b" }
+                ] },
+                { "op": "not", "path": "/objB", "apply": [{ "op": "defined", "path": "/a" }] }
+            ]
+        })),
+    ]
+}
+
+#[test]
+pub fn parses_the_text_syntax() {
+    let parsed: Predicate = r#"/a/b/c starts-with "ABC" and type(/x) == "string""#
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        parsed,
+        predicate(json!({
+            "op": "and",
+            "apply": [
+                { "op": "start", "path": "/a/b/c", "value": "ABC" },
+                { "op": "type", "path": "/x", "value": "string" }
+            ]
+        }))
+    );
+}
+
+#[test]
+pub fn parses_precedence_and_function_forms() {
+    let parsed: Predicate =
+        r#"not /a == 1 and /b equals- "B" or /objA: not(defined(/c), undefined(@))"#
+            .parse()
+            .unwrap();
+
+    assert_eq!(
+        parsed,
+        predicate(json!({
+            "op": "or",
+            "apply": [
+                {
+                    "op": "and",
+                    "apply": [
+                        { "op": "not", "apply": [{ "op": "test", "path": "/a", "value": 1 }] },
+                        { "op": "test-", "path": "/b", "value": "B" }
+                    ]
+                },
+                {
+                    "op": "not",
+                    "path": "/objA",
+                    "apply": [
+                        { "op": "defined", "path": "/c" },
+                        { "op": "undefined", "path": null }
+                    ]
+                }
+            ]
+        }))
+    );
+}
+
+#[test]
+pub fn evaluates_a_parsed_predicate() {
+    let parsed: Predicate = r#"
+        /objA: and(
+            /num2 > 1,
+            /stringX in- ["x", "y"],
+            /dateTime < "2020-01-01T00:00:00Z"
+        )
+        and not /arrayA intersects ["z"]
+    "#
+    .parse()
+    .unwrap();
+
+    assert!(parsed.test(&ENTRY, PredicateContext::default()));
+}
+
+#[test]
+pub fn parses_quantifiers() {
+    let parsed: Predicate = r#"any /items/*/status == "shipped" and count(1..2) defined(/**/id)"#
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        parsed,
+        predicate(json!({
+            "op": "and",
+            "apply": [
+                { "op": "test", "path": "/items/*/status", "value": "shipped", "quantifier": "any" },
+                { "op": "defined", "path": "/**/id", "quantifier": { "min": 1, "max": 2 } }
+            ]
+        }))
+    );
+    assert_eq!(
+        parsed.to_string(),
+        r#"any /items/*/status == "shipped" and count(1..2) defined(/**/id)"#
+    );
+
+    let err = "count(2..1) defined(/a)".parse::<Predicate>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (1, 1));
+    let err = "any /a: and()".parse::<Predicate>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (1, 7));
+}
+
+#[test]
+pub fn parses_operands_and_variables() {
+    let parsed: Predicate = r#"/endDate > /startDate and /a == {"$path": "/b c"}"#
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        parsed,
+        predicate(json!({
+            "op": "and",
+            "apply": [
+                { "op": "more", "path": "/endDate", "value": { "$path": "/startDate" } },
+                { "op": "test", "path": "/a", "value": { "$path": "/b c" } }
+            ]
+        }))
+    );
+    assert_eq!(
+        parsed.to_string(),
+        r#"/endDate > /startDate and /a == {"$path":"/b c"}"#
+    );
+
+    let parsed: Predicate = r#"/owner == $userId"#.parse().unwrap();
+    assert_eq!(
+        parsed,
+        predicate(json!({ "op": "test", "path": "/owner", "value": { "$var": "userId" } }))
+    );
+
+    let err = "/a == $".parse::<Predicate>().unwrap_err();
+    assert_eq!(err.message(), "expected a variable name");
+
+    let err = "/a matches /b".parse::<Predicate>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (1, 12));
+    assert_eq!(err.message(), "expected a JSON value, found pointer \"/b\"");
+}
+
+#[test]
+pub fn round_trips_every_predicate() {
+    for predicate in predicates() {
+        let text = predicate.to_string();
+        assert_eq!(text.parse::<Predicate>().unwrap(), predicate, "{text}");
+
+        let pretty = format!("{predicate:#}");
+        assert_eq!(pretty.parse::<Predicate>().unwrap(), predicate, "{pretty}");
+    }
+}
+
+#[test]
+pub fn prints_the_text_syntax() {
+    let predicates = predicates();
+
+    insta::assert_snapshot!(predicates[24].to_string(), @r###""/a b/c~1d/(e)/f:g/\"h\"" == 1"###);
+    insta::assert_snapshot!(predicates[35].to_string(), @"defined(/a) and (defined(/b) and defined(/c)) or (defined(/d) or defined(/e)) or not (defined(/f) and defined(/g)) or not not defined(/h)");
+    insta::assert_snapshot!(format!("{:#}", predicates[35]), @r###"
+    defined(/a)
+    and (
+      defined(/b)
+      and defined(/c)
+    )
+    or (
+      defined(/d)
+      or defined(/e)
+    )
+    or not (
+      defined(/f)
+      and defined(/g)
+    )
+    or not not defined(/h)
+    "###);
+    insta::assert_snapshot!(format!("{:#}", predicates[36]), @r###"
+    /objA: and(
+      and(
+        defined(/num2)
+      ),
+      or(),
+      not(
+        defined(/a),
+        defined(/b)
+      ),
+      /objB: not(
+        defined(/a)
+      )
+    )
+    "###);
+}
+
+#[test]
+pub fn reports_the_position_of_errors() {
+    let err = "/a == 1\nand /b contains".parse::<Predicate>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (2, 16));
+    assert_eq!(
+        err.to_string(),
+        "expected a JSON value, found the end of the input at line 2, column 16"
+    );
+
+    let err = "/a == 1 and\n  /b startswith \"a\""
+        .parse::<Predicate>()
+        .unwrap_err();
+    assert_eq!((err.line(), err.column()), (2, 6));
+    assert_eq!(err.message(), "expected an operator, found \"startswith\"");
+
+    let err = "/a in \"a\"".parse::<Predicate>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (1, 7));
+    assert_eq!(err.message(), "expected an array");
+
+    let err = "(/a == 1".parse::<Predicate>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (1, 9));
+
+    let err = "/a == \"é\" )".parse::<Predicate>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (1, 11));
+
+    let err = "/a ~ 1".parse::<Predicate>().unwrap_err();
+    assert_eq!(err.message(), "unexpected character '~'");
+
+    let err = "/a matches \"(\"".parse::<Predicate>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (1, 12));
+}
@@ -35,8 +35,7 @@ pub fn returns_true_for_matching_string_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -51,8 +50,7 @@ pub fn returns_false_for_stirng_mismatched_only_by_case_without_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -68,8 +66,7 @@ pub fn returns_true_for_string_mismatched_only_by_case_with_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -85,8 +82,7 @@ pub fn returns_true_for_matching_shallow_object() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -101,8 +97,7 @@ pub fn returns_true_for_matching_deep_object() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -117,8 +112,7 @@ pub fn returns_true_for_matching_number_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -133,8 +127,7 @@ pub fn returns_true_for_matching_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -149,8 +142,7 @@ pub fn returns_false_for_superset_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -165,8 +157,7 @@ pub fn returns_false_for_subset_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -181,8 +172,7 @@ pub fn returns_false_for_string_disguised_as_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -197,8 +187,7 @@ pub fn returns_false_for_array_disguised_as_a_string() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -214,8 +203,7 @@ pub fn returns_true_for_string_match_inside_object_honoring_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -230,8 +218,7 @@ pub fn returns_false_for_string_inside_object_mismatched_only_by_case_without_ig
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -246,15 +233,25 @@ pub fn returns_err_for_undefined_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
     insta::assert_debug_snapshot!(result, @r###"
-    Err(
+    Error(
         PredicateError {
             kind: JsonPointerIssue {
                 issue: NoSuchKey(
                     "objZZZ",
                 ),
             },
+            op: "test",
+            path: JSONPath(
+                JsonPointer {
+                    ref_toks: [
+                        "objZZZ",
+                        "objZZZZZZZZ",
+                    ],
+                    _phantom: PhantomData<alloc::string::String>,
+                },
+            ),
         },
     )
     "###);
@@ -273,15 +270,25 @@ pub fn returns_err_for_undefined_value_with_ignore_case() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
     insta::assert_debug_snapshot!(result, @r###"
-    Err(
+    Error(
         PredicateError {
             kind: JsonPointerIssue {
                 issue: NoSuchKey(
                     "objZZZ",
                 ),
             },
+            op: "test-",
+            path: JSONPath(
+                JsonPointer {
+                    ref_toks: [
+                        "objZZZ",
+                        "objZZZZZZZZ",
+                    ],
+                    _phantom: PhantomData<alloc::string::String>,
+                },
+            ),
         },
     )
     "###);
@@ -34,8 +34,7 @@ pub fn returns_true_when_matching_number_to_type_number() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -50,8 +49,7 @@ pub fn returns_false_when_matching_string_to_type_number() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -66,8 +64,7 @@ pub fn returns_true_when_matching_string_to_type_string() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -82,8 +79,7 @@ pub fn returns_false_when_maching_number_to_string() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -108,10 +104,8 @@ pub fn returns_true_when_matching_boolean_to_type_bool() {
 
     let result_t = predicate_t.evaluate(&ENTRY, PredicateContext::default());
     let result_f = predicate_f.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result_t.is_ok());
-    assert!(result_t.unwrap());
-    assert!(result_f.is_ok());
-    assert!(result_f.unwrap());
+    assert!(result_t.is_true());
+    assert!(result_f.is_true());
 }
 
 #[test]
@@ -126,8 +120,7 @@ pub fn returns_false_when_matching_number_to_type_boolean() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -142,8 +135,7 @@ pub fn returns_true_when_matching_object_to_type_object() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -158,8 +150,7 @@ pub fn returns_false_when_matching_number_to_type_object() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -174,8 +165,7 @@ pub fn returns_true_when_matching_array_to_type_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -190,8 +180,7 @@ pub fn returns_false_when_matching_number_to_type_array() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -206,8 +195,7 @@ pub fn returns_true_when_matching_null_to_type_null() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -222,8 +210,7 @@ pub fn returns_false_when_matching_number_to_type_null() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -238,8 +225,7 @@ pub fn returns_true_when_matching_undefined_to_type_undefined() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -254,8 +240,7 @@ pub fn returns_false_when_matching_number_to_type_undefined() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -270,8 +255,7 @@ pub fn returns_true_when_matching_date_to_type_date() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -286,8 +270,7 @@ pub fn returns_false_when_matching_date_time_to_type_date() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -302,8 +285,7 @@ pub fn returns_true_when_matching_time_z_to_type_time() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -318,8 +300,7 @@ pub fn returns_true_when_matching_time_offset_to_type_time() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -334,8 +315,7 @@ pub fn returns_false_when_matching_date_time_to_type_time() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -350,8 +330,7 @@ pub fn returns_true_when_matching_date_time_z_to_type_date_time() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -366,8 +345,7 @@ pub fn returns_true_when_matching_date_time_offset_to_type_date_time() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -382,8 +360,7 @@ pub fn returns_false_when_matching_date_to_type_date_time() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -398,8 +375,7 @@ pub fn returns_true_when_matching_lang_to_type_lang() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -414,8 +390,7 @@ pub fn returns_false_when_matching_num_to_type_lang() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -431,8 +406,7 @@ pub fn returns_true_when_matching_lang_range_to_type_lang_range() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -448,8 +422,7 @@ pub fn returns_true_when_matching_lang_range2_to_type_lang_range() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -464,8 +437,7 @@ pub fn returns_true_when_matching_lang_range_3_to_type_lang_range() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
@@ -480,12 +452,10 @@ pub fn returns_false_when_matching_num_to_type_lang_range() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
-#[ignore = "iri not fixed"]
 pub fn returns_true_when_matching_iri_to_type_iri() {
     let predicate: Predicate = FirstOrder::from(
         TypeBuilder::default()
@@ -497,12 +467,10 @@ pub fn returns_true_when_matching_iri_to_type_iri() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
-#[ignore = "iri not fixed"]
 pub fn returns_false_when_matching_num_to_type_iri() {
     let predicate: Predicate = FirstOrder::from(
         TypeBuilder::default()
@@ -514,16 +482,14 @@ pub fn returns_false_when_matching_num_to_type_iri() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
 }
 
 #[test]
-#[ignore = "absolute-iri not fixed"]
 pub fn returns_true_when_matching_iri_to_type_absolute_iri() {
     let predicate: Predicate = FirstOrder::from(
         TypeBuilder::default()
-            .path(JSONPath::new("/objA/absolureIri").unwrap())
+            .path(JSONPath::new("/objA/absoluteIri").unwrap())
             .value("absolute-iri")
             .build()
             .unwrap(),
@@ -531,12 +497,10 @@ pub fn returns_true_when_matching_iri_to_type_absolute_iri() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(result.unwrap());
+    assert!(result.is_true());
 }
 
 #[test]
-#[ignore = "absolute-iri not fixed"]
 pub fn returns_false_when_matching_num_to_type_absolute_iri() {
     let predicate: Predicate = FirstOrder::from(
         TypeBuilder::default()
@@ -548,8 +512,83 @@ pub fn returns_false_when_matching_num_to_type_absolute_iri() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_ok());
-    assert!(!result.unwrap());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_when_matching_relative_reference_to_type_iri() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/objA/stringX").unwrap())
+            .value("iri")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_true_when_matching_non_ascii_iri_to_type_iri() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/iri").unwrap())
+            .value("iri")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let data = serde_json::json!({ "iri": "https://例え.テスト/パス?q=値#ç" });
+    let result = predicate.evaluate(&data, PredicateContext::default());
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_when_matching_invalid_iri_to_type_iri() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/objA/dateTimeOffset").unwrap())
+            .value("iri")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_false_when_matching_iri_with_fragment_to_type_absolute_iri() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/objA/iri").unwrap())
+            .value("absolute-iri")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_false_when_matching_relative_reference_to_type_absolute_iri() {
+    let predicate: Predicate = FirstOrder::from(
+        TypeBuilder::default()
+            .path(JSONPath::new("/objA/stringX").unwrap())
+            .value("absolute-iri")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let result = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert!(result.is_false());
 }
 
 #[test]
@@ -564,15 +603,25 @@ pub fn returns_err_for_undefined_value() {
     .into();
 
     let result = predicate.evaluate(&ENTRY, PredicateContext::default());
-    assert!(result.is_err());
+    assert!(result.is_error());
     insta::assert_debug_snapshot!(result, @r###"
-    Err(
+    Error(
         PredicateError {
             kind: JsonPointerIssue {
                 issue: NoSuchKey(
                     "objZZZ",
                 ),
             },
+            op: "type",
+            path: JSONPath(
+                JsonPointer {
+                    ref_toks: [
+                        "objZZZ",
+                        "objZZZZZZZZ",
+                    ],
+                    _phantom: PhantomData<alloc::string::String>,
+                },
+            ),
         },
     )
     "###);
@@ -1,5 +1,7 @@
 // Fixture adapted from https://github.com/MalcolmDwyer/json-predicate/blob/master/test/test.js
 
+use json_predicate::context::PredicateContext;
+use json_predicate::{Predicate, PredicateImpl};
 use serde_json::json;
 
 lazy_static::lazy_static! {
@@ -51,3 +53,32 @@ lazy_static::lazy_static! {
         }
     });
 }
+
+/// Documents to compare the conversions of predicates with: missing, `null`
+/// and mistyped values, arrays, and objects with numeric keys.
+#[allow(dead_code)]
+pub fn documents() -> Vec<serde_json::Value> {
+    vec![
+        ENTRY.clone(),
+        json!({ "a": [10, "x"], "b": { "0": "zero", "c": "ABC" }, "n": null }),
+        json!({ "a": { "0": 10, "1": "X" }, "b": "abc.*?[", "n": 1.0, "t": "undefined" }),
+        json!({ "a": [], "b": { "c": "abc", "d": [1, { "e": true }] }, "n": 1 }),
+        json!({ "a": [0, [1, 2]], "b": { "d": [1, { "e": true }], "c": "abc\n" } }),
+        json!({}),
+        json!({ "a": [10, "x"], "b": { "c": "ABC", "d": 2 }, "n": 1 }),
+        json!({ "a": [3, "y"], "b": { "c": "abc", "d": 5 }, "n": 2.5 }),
+        json!({ "a": ["x"], "b": { "c": "xbx" }, "n": 0, "f": true }),
+    ]
+}
+
+/// The indices of the documents the predicate holds for.
+#[allow(dead_code)]
+pub fn matching(
+    predicate: &Predicate,
+    ctx: PredicateContext,
+    documents: &[serde_json::Value],
+) -> Vec<usize> {
+    (0..documents.len())
+        .filter(|&id| predicate.test(&documents[id], ctx.clone()))
+        .collect()
+}
@@ -0,0 +1,119 @@
+use json_predicate::builder::{LessBuilder, TestBuilder};
+use json_predicate::context::PredicateContext;
+use json_predicate::json_path::JSONPath;
+use json_predicate::{CompiledPredicate, FirstOrder, Predicate, PredicateErrorKind, PredicateImpl};
+
+mod utils;
+use serde::Deserialize;
+use utils::ENTRY;
+
+fn owned_by_user() -> Predicate {
+    Predicate::deserialize(serde_json::json!({
+        "op": "and",
+        "path": "/objA",
+        "apply": [
+            { "op": "test", "path": "/stringX", "value": { "$var": "userId" } }
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+pub fn test_base_predicate_snapshot() {
+    let predicate: Predicate = FirstOrder::from(
+        TestBuilder::default()
+            .path(JSONPath::new("/owner").unwrap())
+            .value_var("userId")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    insta::assert_json_snapshot!(predicate, @r###"
+    {
+      "op": "test",
+      "path": "/owner",
+      "value": {
+        "$var": "userId"
+      }
+    }
+    "###);
+}
+
+#[test]
+pub fn returns_true_when_equal_to_the_variable() {
+    let ctx = PredicateContext::default().with_variable("userId", "X");
+
+    let result = owned_by_user().evaluate(&ENTRY, ctx);
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_false_when_not_equal_to_the_variable() {
+    let ctx = PredicateContext::default().with_variable("userId", "Y");
+
+    let result = owned_by_user().evaluate(&ENTRY, ctx);
+    assert!(result.is_false());
+}
+
+#[test]
+pub fn returns_true_when_less_than_the_variable() {
+    let predicate: Predicate = FirstOrder::from(
+        LessBuilder::default()
+            .path(JSONPath::new("/objA/dateTime").unwrap())
+            .value_var("now")
+            .build()
+            .unwrap(),
+    )
+    .into();
+
+    let ctx = PredicateContext::default().with_variable("now", "2020-01-01T00:00:00Z");
+    let result = predicate.evaluate(&ENTRY, ctx);
+    assert!(result.is_true());
+}
+
+#[test]
+pub fn returns_err_when_the_variable_is_undefined() {
+    let result = owned_by_user().evaluate(&ENTRY, PredicateContext::default());
+
+    let err = result.err().unwrap();
+    assert_eq!(
+        err.kind(),
+        &PredicateErrorKind::UndefinedVariable {
+            name: "userId".to_string()
+        }
+    );
+    assert_eq!(err.path().to_string(), "/objA/stringX");
+}
+
+#[test]
+pub fn returns_err_when_the_variable_is_of_the_wrong_type() {
+    let predicate = Predicate::deserialize(serde_json::json!({
+        "op": "in",
+        "path": "/stringA",
+        "value": { "$var": "allowed" }
+    }))
+    .unwrap();
+
+    let ctx = PredicateContext::default().with_variable("allowed", "A");
+    let result = predicate.evaluate(&ENTRY, ctx);
+    assert_eq!(
+        result.err().unwrap().kind(),
+        &PredicateErrorKind::IncorrectType
+    );
+}
+
+#[test]
+pub fn binds_variables_when_compiled() {
+    let predicate = owned_by_user();
+
+    let ctx = PredicateContext::default().with_variable("userId", "X");
+    assert!(CompiledPredicate::new(&predicate, ctx).test(&ENTRY));
+
+    let compiled = CompiledPredicate::from(&predicate);
+    let expected = predicate.evaluate(&ENTRY, PredicateContext::default());
+    assert_eq!(
+        format!("{:?}", compiled.evaluate(&ENTRY)),
+        format!("{expected:?}")
+    );
+}